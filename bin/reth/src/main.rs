@@ -7,14 +7,34 @@ static ALLOC: reth_cli_util::allocator::Allocator = reth_cli_util::allocator::ne
 #[unsafe(export_name = "_rjem_malloc_conf")]
 static MALLOC_CONF: &[u8] = b"prof:true,prof_active:true,lg_prof_sample:19\0";
 
+use alloy_primitives::{Address, B256};
 use clap::Parser;
 use reth::{args::RessArgs, cli::Cli, ress::install_ress_subprotocol};
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
 use reth_node_builder::NodeHandle;
 use reth_node_ethereum::{EthereumAddOns, EthereumNode};
-use alloy_primitives::Address;
-use reth_rkb::RkbExecutorBuilder;
-use tracing::info;
+use reth_rkb::{
+    rpc::RkbApiServer, verify_bridge_create2, RkbConfig, RkbExecutorBuilder, NATIVE_MINTER_ADDRESS,
+};
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+/// Extension arguments of the RKB node.
+#[derive(Debug, Clone, clap::Args)]
+struct RkbArgs {
+    #[command(flatten)]
+    ress: RessArgs,
+
+    /// Path to a TOML file configuring the NativeMinter precompile.
+    ///
+    /// Values set through environment variables take precedence over the file.
+    #[arg(long = "rkb.config", value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Check that the chain spec is compatible with the NativeMinter before launching.
+    #[arg(long = "rkb.preflight")]
+    preflight: bool,
+}
 
 fn main() {
     reth_cli_util::sigsegv_handler::install();
@@ -25,29 +45,66 @@ fn main() {
     }
 
     if let Err(err) =
-        Cli::<EthereumChainSpecParser, RessArgs>::parse().run(async move |builder, ress_args| {
-            // Get authorized bridge address from environment variable
-            // Falls back to Address::ZERO if not set (for testing/development)
-            let authorized_bridge: Address = std::env::var("RKB_AUTHORIZED_BRIDGE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Address::ZERO);
+        Cli::<EthereumChainSpecParser, RkbArgs>::parse().run(async move |builder, rkb_args| {
+            let mut config = match &rkb_args.config {
+                Some(path) => RkbConfig::load(path)?,
+                None => RkbConfig::default(),
+            };
+
+            // Get authorized bridge address from environment variable, overriding the config file
+            // Only the primary bridge is replaced, additional bridges from the file are kept
+            // Falls back to Address::ZERO if neither is set (for testing/development)
+            if let Some(bridge) = env_parse::<Address>("RKB_AUTHORIZED_BRIDGE") {
+                config.set_authorized_bridge(bridge);
+            }
+            let authorized_bridge = config.authorized_bridge();
 
             info!(target: "reth::cli", %authorized_bridge, "Launching RKB node with NativeMinter precompile");
 
+            // Optionally check the bridge against its deterministic CREATE2 deployment address
+            if let (Some(factory), Some(salt), Some(init_code_hash)) = (
+                env_parse::<Address>("RKB_BRIDGE_FACTORY"),
+                env_parse::<B256>("RKB_BRIDGE_SALT"),
+                env_parse::<B256>("RKB_BRIDGE_INIT_CODE_HASH"),
+            ) {
+                if !verify_bridge_create2(factory, salt, init_code_hash, authorized_bridge) {
+                    warn!(
+                        target: "reth::cli",
+                        %authorized_bridge,
+                        expected = %factory.create2(salt, init_code_hash),
+                        "Authorized bridge does not match its CREATE2 deployment address"
+                    );
+                }
+            }
+
+            let executor = RkbExecutorBuilder::from_config(config)?;
+            // Abort rather than run a node whose EVM is missing the NativeMinter, as bridged
+            // deposits would never be minted
+            if !executor.has_native_minter_at_genesis(&*builder.config().chain) {
+                eyre::bail!("NativeMinter is not registered at {NATIVE_MINTER_ADDRESS}");
+            }
+            if rkb_args.preflight {
+                executor.validate_chain_spec(&*builder.config().chain)?;
+                info!(target: "reth::cli", "Chain spec is compatible with the NativeMinter");
+            }
+            let rkb_rpc = executor.rpc(builder.config().chain.genesis())?;
+
             let NodeHandle { node, node_exit_future } = builder
                 .with_types::<EthereumNode>()
-                .with_components(
-                    EthereumNode::components().executor(RkbExecutorBuilder::new(authorized_bridge)),
-                )
+                .with_components(EthereumNode::components().executor(executor))
                 .with_add_ons(EthereumAddOns::default())
+                .extend_rpc_modules(move |ctx| {
+                    // Expose the NativeMinter configuration under the `rkb` namespace
+                    ctx.modules.merge_configured(rkb_rpc.into_rpc())?;
+                    Ok(())
+                })
                 .launch_with_debug_capabilities()
                 .await?;
 
             // Install ress subprotocol.
-            if ress_args.enabled {
+            if rkb_args.ress.enabled {
                 install_ress_subprotocol(
-                    ress_args,
+                    rkb_args.ress,
                     node.provider,
                     node.evm_config,
                     node.network,
@@ -63,3 +120,8 @@ fn main() {
         std::process::exit(1);
     }
 }
+
+/// Parses the environment variable `key`, if it is set and valid.
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|s| s.parse().ok())
+}
@@ -0,0 +1,70 @@
+//! Throughput of `NativeMinterPrecompile` calls on the mint, burn and rejected paths.
+
+#![allow(missing_docs)]
+
+use alloy_primitives::{address, Address, Bytes, U256};
+use alloy_sol_types::{sol, SolCall};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use reth_rkb::{
+    test_utils::{run_precompile, TestCall, TestContext},
+    NativeMinterPrecompile,
+};
+use std::hint::black_box;
+
+sol! {
+    function mint(address recipient, uint256 amount);
+    function burn(address from, uint256 amount);
+}
+
+const BRIDGE: Address = address!("0x1234567890abcdef1234567890abcdef12345678");
+const OTHER: Address = address!("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd");
+const RECIPIENT: Address = address!("0x00000000000000000000000000000000000000aa");
+
+/// Benchmarks a call on a fresh context built by `setup` for every iteration, after checking once
+/// that it takes the intended path: succeeding if `succeeds`, rejected otherwise.
+fn bench_call(
+    c: &mut Criterion,
+    name: &str,
+    setup: fn() -> TestContext,
+    caller: Address,
+    calldata: Bytes,
+    succeeds: bool,
+) {
+    let precompile = NativeMinterPrecompile::new(BRIDGE);
+
+    let result = run_precompile(&precompile, &mut setup(), TestCall::new(caller, calldata.clone()));
+    assert_eq!(result.is_ok(), succeeds, "{name}: {result:?}");
+
+    let mut group = c.benchmark_group("NativeMinter");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function(name, |b| {
+        b.iter_batched_ref(
+            setup,
+            |ctx| {
+                black_box(run_precompile(&precompile, ctx, TestCall::new(caller, calldata.clone())))
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+/// A context where the recipient holds enough to be burned from.
+fn funded() -> TestContext {
+    let mut ctx = TestContext::new();
+    ctx.set_balance(RECIPIENT, U256::from(1_000_000));
+    ctx
+}
+
+fn native_minter(c: &mut Criterion) {
+    let amount = U256::from(1_000);
+    let mint = mintCall { recipient: RECIPIENT, amount }.abi_encode();
+    let burn = burnCall { from: RECIPIENT, amount }.abi_encode();
+
+    bench_call(c, "mint", TestContext::new, BRIDGE, mint.clone().into(), true);
+    bench_call(c, "burn", funded, BRIDGE, burn.into(), true);
+    bench_call(c, "unauthorized", TestContext::new, OTHER, mint.into(), false);
+}
+
+criterion_group!(benches, native_minter);
+criterion_main!(benches);
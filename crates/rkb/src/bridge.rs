@@ -0,0 +1,131 @@
+//! Startup sources of, and checks on, the configured authorized bridge.
+//!
+//! ## Genesis `extraData` layout
+//!
+//! Chains committing to the bridge in their genesis block encode it in the genesis `extraData`,
+//! see [`bridge_from_extra_data`]:
+//!
+//! | Bytes    | Field     | Value                                   |
+//! |----------|-----------|-----------------------------------------|
+//! | `0..3`   | `magic`   | `"rkb"` in ASCII, `0x726b62`            |
+//! | `3`      | `version` | `0x01`                                  |
+//! | `4..24`  | `bridge`  | authorized bridge address, non-zero     |
+//!
+//! The 24 bytes fit within the 32-byte `extraData` limit of post-merge block validation. Any
+//! other length is rejected, so a layout can't be mistaken for a longer or truncated one.
+
+use crate::{RkbConfigError, NATIVE_MINTER_ADDRESS};
+use alloy_primitives::{Address, Bytes, B256};
+
+/// Magic prefix of an RKB genesis `extraData`.
+const EXTRA_DATA_MAGIC: [u8; 3] = *b"rkb";
+
+/// Version of the genesis `extraData` layout.
+const EXTRA_DATA_VERSION: u8 = 1;
+
+/// Length of an RKB genesis `extraData`.
+const EXTRA_DATA_LEN: usize = EXTRA_DATA_MAGIC.len() + 1 + Address::len_bytes();
+
+/// Returns whether `configured` is the address a bridge deployed by `factory` via CREATE2 with
+/// the given `salt` and `init_code_hash` is deployed at.
+///
+/// Operators deploying the bridge deterministically can use this to catch a mistyped bridge
+/// address at startup.
+pub fn verify_bridge_create2(
+    factory: Address,
+    salt: B256,
+    init_code_hash: B256,
+    configured: Address,
+) -> bool {
+    factory.create2(salt, init_code_hash) == configured
+}
+
+/// Parses the authorized bridge from a genesis `extraData` laid out as documented in the
+/// [module docs](self).
+pub fn bridge_from_extra_data(extra_data: &[u8]) -> Result<Address, RkbConfigError> {
+    if extra_data.len() != EXTRA_DATA_LEN {
+        return Err(RkbConfigError::InvalidExtraData("unexpected length"));
+    }
+    if extra_data[..3] != EXTRA_DATA_MAGIC {
+        return Err(RkbConfigError::InvalidExtraData("missing rkb magic"));
+    }
+    if extra_data[3] != EXTRA_DATA_VERSION {
+        return Err(RkbConfigError::InvalidExtraData("unsupported version"));
+    }
+
+    let bridge = Address::from_slice(&extra_data[4..]);
+    if bridge.is_zero() {
+        return Err(RkbConfigError::InvalidExtraData("zero bridge"));
+    }
+    if bridge == NATIVE_MINTER_ADDRESS {
+        return Err(RkbConfigError::BridgeIsNativeMinter);
+    }
+    Ok(bridge)
+}
+
+/// Returns the genesis `extraData` committing to `bridge`, the inverse of
+/// [`bridge_from_extra_data`].
+pub fn bridge_extra_data(bridge: Address) -> Bytes {
+    [&EXTRA_DATA_MAGIC[..], &[EXTRA_DATA_VERSION], bridge.as_slice()].concat().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex, keccak256};
+
+    #[test]
+    fn test_verify_bridge_create2() {
+        // Example 1 of EIP-1014
+        let init_code_hash = keccak256([0x00]);
+        let expected = address!("0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38");
+
+        assert!(verify_bridge_create2(Address::ZERO, B256::ZERO, init_code_hash, expected));
+        assert!(!verify_bridge_create2(
+            Address::ZERO,
+            B256::with_last_byte(1),
+            init_code_hash,
+            expected
+        ));
+        assert!(!verify_bridge_create2(
+            Address::ZERO,
+            B256::ZERO,
+            init_code_hash,
+            address!("0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF39")
+        ));
+    }
+
+    #[test]
+    fn test_bridge_from_extra_data() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let extra_data = hex!("726b6201 1234567890abcdef1234567890abcdef12345678");
+
+        assert_eq!(bridge_from_extra_data(&extra_data).unwrap(), bridge);
+        assert_eq!(bridge_extra_data(bridge), Bytes::from(extra_data));
+    }
+
+    #[test]
+    fn test_bridge_from_malformed_extra_data() {
+        let valid = bridge_extra_data(address!("0x1234567890abcdef1234567890abcdef12345678"));
+        let malformed = |extra_data: &[u8]| match bridge_from_extra_data(extra_data) {
+            Err(RkbConfigError::InvalidExtraData(reason)) => reason,
+            other => panic!("{other:?}"),
+        };
+
+        assert_eq!(malformed(&[]), "unexpected length");
+        assert_eq!(malformed(&valid[..23]), "unexpected length");
+        assert_eq!(malformed(&[valid.as_ref(), &[0]].concat()), "unexpected length");
+        // A clique-style vanity prefix is not an RKB layout
+        assert_eq!(malformed(&[0; 24]), "missing rkb magic");
+
+        let mut extra_data = valid.to_vec();
+        extra_data[3] = 2;
+        assert_eq!(malformed(&extra_data), "unsupported version");
+
+        assert_eq!(malformed(&bridge_extra_data(Address::ZERO)), "zero bridge");
+        assert!(matches!(
+            bridge_from_extra_data(&bridge_extra_data(NATIVE_MINTER_ADDRESS)),
+            Err(RkbConfigError::BridgeIsNativeMinter)
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+//! File-based configuration of the NativeMinter precompile.
+//!
+//! Operators managing many chains can configure the precompile from a TOML file instead of
+//! stacking environment variables and CLI flags:
+//!
+//! ```toml
+//! authorized_bridges = ["0x1234567890abcdef1234567890abcdef12345678"]
+//! minter_address = "0x00000000000000000000000000000000000000b1"
+//! pauser = "0x00000000000000000000000000000000000000b3"
+//! gas_cost = 8000
+//! supply_cap = "1000000000000000000000000"
+//! fee_bps = 25
+//! fee_recipient = "0x00000000000000000000000000000000000000fe"
+//! ```
+//!
+//! Every field is optional.
+
+use crate::{GasModel, MintFee, NativeMinterPrecompile, NATIVE_MINTER_ADDRESS};
+use alloy_primitives::{Address, U256};
+use reth_prune_types::PruneMode;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Configuration of the NativeMinter precompile, loadable from a TOML file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RkbConfig {
+    /// Addresses allowed to call every NativeMinter function.
+    ///
+    /// The first address is the authorized bridge, further addresses are granted every
+    /// [`Role`](crate::Role). Defaults to the zero address if empty.
+    pub authorized_bridges: Vec<Address>,
+    /// Address granted the [`Role::Minter`](crate::Role::Minter) role.
+    pub minter_address: Option<Address>,
+    /// Flat gas cost of every call, including mints and burns.
+    pub gas_cost: Option<u64>,
+    /// Address granted the [`Role::Pauser`](crate::Role::Pauser) role.
+    pub pauser: Option<Address>,
+    /// Cap on the net amount minted through the precompile.
+    pub supply_cap: Option<U256>,
+    /// Fee taken from every mint, in basis points. Requires `fee_recipient`.
+    pub fee_bps: Option<u16>,
+    /// Account credited with the mint fee.
+    pub fee_recipient: Option<Address>,
+    /// Only install the NativeMinter in EVMs for Cancun or later, see
+    /// [`NativeMinterPrecompile::with_cancun_only`]. A consensus change for chains with
+    /// pre-Cancun history.
+    pub cancun_only: bool,
+}
+
+impl RkbConfig {
+    /// Loads the configuration from the TOML file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RkbConfigError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|source| RkbConfigError::Read { path: path.to_path_buf(), source })?;
+        toml::from_str(&contents)
+            .map_err(|source| RkbConfigError::Parse { path: path.to_path_buf(), source })
+    }
+
+    /// Returns the authorized bridge, the first of the configured bridges.
+    pub fn authorized_bridge(&self) -> Address {
+        self.authorized_bridges.first().copied().unwrap_or_default()
+    }
+
+    /// Sets the authorized bridge, replacing the first of the configured bridges.
+    ///
+    /// Further bridges are kept, so overriding the primary bridge (e.g. from the environment)
+    /// doesn't revoke the additional ones.
+    pub fn set_authorized_bridge(&mut self, bridge: Address) {
+        match self.authorized_bridges.first_mut() {
+            Some(primary) => *primary = bridge,
+            None => self.authorized_bridges.push(bridge),
+        }
+    }
+
+    /// Builds the precompile this configuration describes.
+    pub fn native_minter(&self) -> Result<NativeMinterPrecompile, RkbConfigError> {
+        if self.authorized_bridges.contains(&NATIVE_MINTER_ADDRESS) {
+            return Err(RkbConfigError::BridgeIsNativeMinter);
+        }
+
+        let mut precompile = NativeMinterPrecompile::new(self.authorized_bridge())
+            .with_cancun_only(self.cancun_only);
+
+        for bridge in self.authorized_bridges.iter().skip(1) {
+            precompile = precompile.add_bridge(*bridge);
+        }
+        if let Some(minter) = self.minter_address {
+            precompile = precompile.add_minter(minter);
+        }
+        if let Some(pauser) = self.pauser {
+            precompile = precompile.add_pauser(pauser);
+        }
+        if let Some(gas_cost) = self.gas_cost {
+            precompile = precompile
+                .with_gas_model(GasModel::Flat(gas_cost))
+                .with_mint_gas_cost(gas_cost)
+                .with_burn_gas_cost(gas_cost);
+        }
+        if let Some(supply_cap) = self.supply_cap {
+            precompile = precompile.with_supply_cap(supply_cap);
+        }
+
+        match (self.fee_bps, self.fee_recipient) {
+            (Some(bps), _) if bps > MintFee::MAX_BPS => {
+                return Err(RkbConfigError::FeeTooHigh(bps))
+            }
+            (Some(bps), Some(recipient)) => {
+                precompile = precompile.with_mint_fee(MintFee::new(bps, recipient));
+            }
+            (Some(_), None) => return Err(RkbConfigError::MissingFeeRecipient),
+            (None, _) => {}
+        }
+
+        Ok(precompile)
+    }
+}
+
+/// Errors loading an [`RkbConfig`] or validating a NativeMinter configuration.
+#[derive(Debug, thiserror::Error)]
+pub enum RkbConfigError {
+    /// The configuration file could not be read.
+    #[error("failed to read RKB config {path:?}: {source}")]
+    Read {
+        /// Path of the configuration file.
+        path: PathBuf,
+        /// Underlying IO error.
+        source: std::io::Error,
+    },
+    /// The configuration file is not valid TOML or has invalid fields.
+    #[error("failed to parse RKB config {path:?}: {source}")]
+    Parse {
+        /// Path of the configuration file.
+        path: PathBuf,
+        /// Underlying parse error.
+        source: toml::de::Error,
+    },
+    /// The mint fee exceeds 100%.
+    #[error("fee_bps must be at most {max}, got {0}", max = MintFee::MAX_BPS)]
+    FeeTooHigh(u16),
+    /// A mint fee is configured without a recipient.
+    #[error("fee_bps is set without a fee_recipient")]
+    MissingFeeRecipient,
+    /// An authorized bridge is the NativeMinter's own address.
+    #[error("authorized bridge must not be the NativeMinter address {NATIVE_MINTER_ADDRESS}")]
+    BridgeIsNativeMinter,
+    /// The genesis `extraData` doesn't follow the RKB layout.
+    #[error("invalid RKB genesis extraData: {0}")]
+    InvalidExtraData(&'static str),
+    /// A hardfork required by the NativeMinter is not active at genesis.
+    #[error("chain spec must activate {0} at genesis")]
+    HardforkNotActive(&'static str),
+    /// The genesis alloc deploys code at one of the NativeMinter's addresses.
+    #[error("genesis alloc deploys code at NativeMinter address {0}")]
+    CodeAtNativeMinter(Address),
+    /// The node prunes storage history, including the NativeMinter's.
+    #[error("storage history pruning ({0:?}) would prune NativeMinter storage history")]
+    StorageHistoryPruned(PruneMode),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Role;
+    use alloy_primitives::address;
+    use std::collections::BTreeSet;
+
+    const SAMPLE: &str = r#"
+        authorized_bridges = [
+            "0x1234567890abcdef1234567890abcdef12345678",
+            "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+        ]
+        minter_address = "0x00000000000000000000000000000000000000b1"
+        pauser = "0x00000000000000000000000000000000000000b3"
+        gas_cost = 8000
+        supply_cap = "1000000000000000000000000"
+        fee_bps = 25
+        fee_recipient = "0x00000000000000000000000000000000000000fe"
+    "#;
+
+    #[test]
+    fn test_sample_config() {
+        let config: RkbConfig = toml::from_str(SAMPLE).unwrap();
+        let precompile = config.native_minter().unwrap();
+
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let other_bridge = address!("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd");
+        let minter = address!("0x00000000000000000000000000000000000000b1");
+        let pauser = address!("0x00000000000000000000000000000000000000b3");
+
+        assert_eq!(precompile.authorized_bridge(), bridge);
+        assert_eq!(precompile.role_members(Role::Minter), &BTreeSet::from([minter, other_bridge]));
+        assert_eq!(precompile.role_members(Role::Burner), &BTreeSet::from([other_bridge]));
+        assert_eq!(precompile.role_members(Role::Pauser), &BTreeSet::from([pauser, other_bridge]));
+        assert_eq!(precompile.gas_model(), GasModel::Flat(8000));
+        assert_eq!(precompile.mint_gas_cost(), 8000);
+        assert_eq!(precompile.burn_gas_cost(), 8000);
+        assert_eq!(precompile.supply_cap(), Some(U256::from(10).pow(U256::from(24))));
+        assert_eq!(
+            precompile.mint_fee(),
+            Some(MintFee::new(25, address!("0x00000000000000000000000000000000000000fe")))
+        );
+    }
+
+    #[test]
+    fn test_empty_config_matches_default_precompile() {
+        let config: RkbConfig = toml::from_str("").unwrap();
+        let precompile = config.native_minter().unwrap();
+
+        assert_eq!(precompile.authorized_bridge(), Address::ZERO);
+        assert_eq!(precompile.gas_model(), GasModel::default());
+        assert_eq!(precompile.supply_cap(), None);
+        assert_eq!(precompile.mint_fee(), None);
+    }
+
+    #[test]
+    fn test_invalid_fee() {
+        let config = RkbConfig { fee_bps: Some(10_001), ..Default::default() };
+        assert!(matches!(config.native_minter(), Err(RkbConfigError::FeeTooHigh(10_001))));
+
+        let config = RkbConfig { fee_bps: Some(25), ..Default::default() };
+        assert!(matches!(config.native_minter(), Err(RkbConfigError::MissingFeeRecipient)));
+    }
+
+    #[test]
+    fn test_unknown_field_rejected() {
+        assert!(toml::from_str::<RkbConfig>("authorised_bridges = []").is_err());
+    }
+
+    #[test]
+    fn test_bridge_is_native_minter_rejected() {
+        let config =
+            RkbConfig { authorized_bridges: vec![NATIVE_MINTER_ADDRESS], ..Default::default() };
+        assert!(matches!(config.native_minter(), Err(RkbConfigError::BridgeIsNativeMinter)));
+    }
+
+    #[test]
+    fn test_set_authorized_bridge() {
+        let bridge = address!("0x00000000000000000000000000000000000000e1");
+
+        let mut config = RkbConfig::default();
+        config.set_authorized_bridge(bridge);
+        assert_eq!(config.authorized_bridges, vec![bridge]);
+
+        let mut config: RkbConfig = toml::from_str(SAMPLE).unwrap();
+        config.set_authorized_bridge(bridge);
+        assert_eq!(
+            config.authorized_bridges,
+            vec![bridge, address!("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd")]
+        );
+    }
+}
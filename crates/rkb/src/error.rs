@@ -0,0 +1,188 @@
+//! Errors returned by the NativeMinter precompile.
+
+use alloy_primitives::Bytes;
+use alloy_sol_types::{Revert, SolError};
+use revm::precompile::PrecompileError;
+
+/// Reasons a call into the NativeMinter precompile is rejected.
+///
+/// Each variant's [`Display`](core::fmt::Display) output is the revert message surfaced to
+/// callers.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NativeMinterError {
+    /// The call was made via DELEGATECALL.
+    #[error("NativeMinter: DELEGATECALL not allowed")]
+    DelegateCall,
+    /// The call was made via CALLCODE.
+    #[error("NativeMinter: CALLCODE not allowed")]
+    CallCode,
+    /// The call was made via STATICCALL to a state-modifying function.
+    #[error("NativeMinter: STATICCALL not allowed")]
+    StaticCall,
+    /// The caller is the system address or a system contract, which only act in the node's
+    /// system calls.
+    #[error("NativeMinter: system context not allowed")]
+    SystemCall,
+    /// The precompile was re-entered while a call into it was executing.
+    #[error("NativeMinter: reentrant call")]
+    ReentrantCall,
+    /// The caller is neither the authorized bridge nor holds the required role.
+    #[error("NativeMinter: unauthorized caller")]
+    Unauthorized,
+    /// The calldata is too short to contain a function selector.
+    #[error("NativeMinter: invalid calldata length")]
+    InvalidCalldataLength,
+    /// The arguments of the named function could not be decoded.
+    #[error("NativeMinter: invalid {0} args")]
+    InvalidArgs(&'static str),
+    /// The calldata has bytes after the encoded arguments, in strict mode.
+    #[error("NativeMinter: unexpected trailing calldata")]
+    UnexpectedTrailingCalldata,
+    /// The function selector is not part of the interface.
+    #[error("NativeMinter: unknown function")]
+    UnknownFunction,
+    /// The mint fee exceeds the minted amount.
+    #[error("NativeMinter: fee exceeds amount")]
+    FeeExceedsAmount,
+    /// The account to burn from has insufficient balance.
+    #[error("NativeMinter: insufficient balance")]
+    InsufficientBalance,
+    /// The bridge burned from an account other than the transaction origin, if such burns are
+    /// rejected.
+    #[error("NativeMinter: burn not from transaction origin")]
+    BurnNotFromOrigin,
+    /// The account to burn from doesn't exist, if such burns are rejected.
+    #[error("NativeMinter: account does not exist")]
+    AccountNotFound,
+    /// The mint recipient doesn't exist, if mints only credit existing accounts.
+    #[error("NativeMinter: recipient does not exist")]
+    RecipientNotFound,
+    /// The mint would exceed the cap on distinct recipients minted to in the block.
+    #[error("NativeMinter: too many recipients this block")]
+    TooManyRecipients,
+    /// The mint would push the amount minted within the block over the circuit breaker threshold.
+    #[error("NativeMinter: circuit breaker tripped")]
+    CircuitBreakerTripped,
+    /// The recipient was minted to less than the mint cooldown ago.
+    #[error("NativeMinter: mint cooldown not elapsed")]
+    MintCooldown,
+    /// A delegated minter's mint exceeds its remaining mint allowance.
+    #[error("NativeMinter: mint allowance exceeded")]
+    MintAllowanceExceeded,
+    /// `bridgeAt` was called with an index past the last authorized bridge.
+    #[error("NativeMinter: bridge index out of bounds")]
+    BridgeIndexOutOfBounds,
+    /// The Hyperlane message was already processed.
+    #[error("NativeMinter: message already processed")]
+    MessageAlreadyProcessed,
+    /// The nonce of a `burn` call with a nonce was already used for the account burned from.
+    #[error("NativeMinter: burn nonce already used")]
+    BurnNonceUsed,
+    /// The calldata exceeds the configured maximum length.
+    #[error("NativeMinter: calldata too large")]
+    CalldataTooLarge,
+    /// Native value was attached to the call.
+    #[error("NativeMinter: value not accepted")]
+    ValueNotAccepted,
+    /// The mint would overflow the recipient's balance.
+    #[error("NativeMinter: balance overflow")]
+    BalanceOverflow,
+    /// A `mintBatch` call exceeds the configured maximum batch size.
+    #[error("NativeMinter: batch too large")]
+    BatchTooLarge,
+    /// The recipients and amounts of a `mintBatch` call differ in length.
+    #[error("NativeMinter: batch length mismatch")]
+    BatchLengthMismatch,
+    /// Minting and burning are paused.
+    #[error("NativeMinter: paused")]
+    Paused,
+    /// The mint would exceed the amount allowed in the current rate limit window.
+    #[error("NativeMinter: rate limit exceeded")]
+    RateLimitExceeded,
+    /// The burn exceeds the amount the caller is allowed to burn from the account.
+    #[error("NativeMinter: insufficient allowance")]
+    InsufficientAllowance,
+    /// The deadline of a permit has passed.
+    #[error("NativeMinter: permit expired")]
+    PermitExpired,
+    /// The mint exceeds the approval threshold and is not approved by the approver.
+    #[error("NativeMinter: approval required")]
+    ApprovalRequired,
+    /// The permit signature is malformed or not signed by the owner.
+    #[error("NativeMinter: invalid signature")]
+    InvalidSignature,
+    /// The same amount was already minted to the same recipient in this transaction.
+    #[error("NativeMinter: duplicate mint in tx")]
+    DuplicateMint,
+    /// The amount of a single mint or burn exceeds the configured per-call cap.
+    #[error("NativeMinter: amount exceeds per-call cap")]
+    AmountExceedsCallCap,
+    /// The mint recipient is a precompile.
+    #[error("NativeMinter: cannot mint to precompile")]
+    MintToPrecompile,
+    /// The mint would raise the net minted supply above the configured cap.
+    #[error("NativeMinter: supply cap exceeded")]
+    SupplyCapExceeded,
+    /// Minting and burning are not enabled before the configured activation block.
+    #[error("NativeMinter: not yet active")]
+    NotYetActive,
+    /// The authorized bridge is not sourced from state, so it can't be rotated at runtime.
+    #[error("NativeMinter: bridge not sourced from state")]
+    BridgeNotInState,
+    /// The base fee of the current block is below the configured minimum for minting.
+    #[error("NativeMinter: base fee below minimum")]
+    BaseFeeTooLow,
+    /// The emergency mint was not scheduled, or was already executed.
+    #[error("NativeMinter: emergency mint not scheduled")]
+    EmergencyMintNotScheduled,
+    /// The timelock of the emergency mint has not elapsed yet.
+    #[error("NativeMinter: emergency mint timelocked")]
+    EmergencyMintTimelocked,
+}
+
+impl NativeMinterError {
+    /// Returns the revert data for this error, ABI-encoded as Solidity's `Error(string)`.
+    ///
+    /// This is the layout a calling contract expects in a `catch Error(string memory reason)`
+    /// clause.
+    pub fn revert_data(&self) -> Bytes {
+        Revert { reason: self.to_string() }.abi_encode().into()
+    }
+}
+
+impl From<NativeMinterError> for PrecompileError {
+    fn from(err: NativeMinterError) -> Self {
+        Self::other(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revert_data_is_error_string() {
+        let err = NativeMinterError::Unauthorized;
+        let data = err.revert_data();
+
+        assert_eq!(data[..4], [0x08, 0xc3, 0x79, 0xa0]);
+        assert_eq!(Revert::abi_decode(&data).unwrap().reason, "NativeMinter: unauthorized caller");
+    }
+
+    #[test]
+    fn test_revert_data_with_argument() {
+        let err = NativeMinterError::InvalidArgs("mint");
+        let data = err.revert_data();
+
+        assert_eq!(data[..4], Revert::SELECTOR);
+        assert_eq!(Revert::abi_decode(&data).unwrap().reason, "NativeMinter: invalid mint args");
+    }
+
+    #[test]
+    fn test_into_precompile_error() {
+        assert_eq!(
+            PrecompileError::from(NativeMinterError::Paused),
+            PrecompileError::other_static("NativeMinter: paused")
+        );
+    }
+}
@@ -4,8 +4,16 @@
 //! with the NativeMinter precompile at address 0x420, enabling minting/burning of
 //! native tokens for Hyperlane bridge operations.
 
-use crate::{NativeMinterPrecompile, NATIVE_MINTER_ADDRESS};
-use alloy_evm::{eth::EthEvmContext, precompiles::PrecompilesMap, revm::handler::EthPrecompiles, Evm, EvmFactory};
+use crate::{
+    native_minter::NATIVE_MINTER_ID, NativeMinterConfig, NativeMinterPrecompile, RkbConfigError,
+    NATIVE_MINTER_ADDRESS,
+};
+use alloy_evm::{
+    eth::EthEvmContext,
+    precompiles::{DynPrecompile, Precompile, PrecompilesMap},
+    revm::handler::EthPrecompiles,
+    EvmFactory,
+};
 use alloy_primitives::Address;
 use reth_ethereum::evm::{
     primitives::{Database, EvmEnv},
@@ -19,6 +27,7 @@ use reth_ethereum::evm::{
     },
     EthEvm,
 };
+use std::sync::{Arc, Once};
 
 /// RKB EVM Factory - Creates EVMs with NativeMinter precompile.
 ///
@@ -35,6 +44,19 @@ use reth_ethereum::evm::{
 /// - DELEGATECALL is not allowed (must be direct call)
 /// - STATICCALL is not allowed (state modification required)
 ///
+/// # Error types
+///
+/// The `Error` and `HaltReason` of the factory are those of the Ethereum EVM, as `EthEvmConfig`
+/// requires them to execute blocks, so they can't be overridden without losing node
+/// integration. A call the NativeMinter rejects halts with a precompile error. Integrators that
+/// want a richer application error for tracing or monitoring wrap the factory instead, mapping
+/// the errors and halt reasons of the EVMs it creates at their own boundary.
+///
+/// # Cloning
+///
+/// The configuration is shared behind an [`Arc`], so cloning the factory, as reth may do per
+/// thread or per payload job, doesn't copy it.
+///
 /// # Example
 ///
 /// ```ignore
@@ -47,8 +69,12 @@ use reth_ethereum::evm::{
 /// ```
 #[derive(Debug, Clone)]
 pub struct RkbEvmFactory {
-    /// Authorized bridge address that can call NativeMinter.
-    authorized_bridge: Address,
+    /// Configuration of the NativeMinter installed in every EVM, shared with each instance.
+    config: Arc<NativeMinterConfig>,
+    /// Additional custom precompiles installed in every EVM, keyed by address.
+    precompiles: Vec<(Address, DynPrecompile)>,
+    /// Guards the warning that the NativeMinter is skipped before Cancun, shared by clones.
+    pre_cancun_warning: Arc<Once>,
 }
 
 impl RkbEvmFactory {
@@ -64,12 +90,129 @@ impl RkbEvmFactory {
             "Creating RKB EVM Factory with NativeMinter"
         );
 
-        Self { authorized_bridge }
+        Self::with_config(NativeMinterConfig::new(authorized_bridge))
+    }
+
+    /// Creates a new RKB EVM factory installing a NativeMinter with the given configuration in
+    /// every EVM.
+    ///
+    /// The configuration may already be shared, as an `Arc<NativeMinterConfig>`. It is not
+    /// validated, see [`try_with_config`](Self::try_with_config).
+    pub fn with_config(config: impl Into<Arc<NativeMinterConfig>>) -> Self {
+        Self {
+            config: config.into(),
+            precompiles: Vec::new(),
+            pre_cancun_warning: Arc::new(Once::new()),
+        }
+    }
+
+    /// Creates a new RKB EVM factory installing a NativeMinter with the given configuration in
+    /// every EVM, failing if the configuration is invalid.
+    ///
+    /// [`EvmFactory::create_evm`] can't fail, so the configuration is validated once here rather
+    /// than silently installing a misconfigured precompile in every EVM.
+    pub fn try_with_config(config: NativeMinterConfig) -> Result<Self, RkbConfigError> {
+        config.validate()?;
+        Ok(Self::with_config(config))
+    }
+
+    /// Installs additional custom precompiles alongside the NativeMinter in every EVM.
+    ///
+    /// The NativeMinter takes precedence over a custom precompile registered at one of its
+    /// addresses.
+    pub fn with_precompiles(
+        mut self,
+        precompiles: impl IntoIterator<Item = (Address, DynPrecompile)>,
+    ) -> Self {
+        self.precompiles.extend(precompiles);
+        self
     }
 
     /// Returns the authorized bridge address.
-    pub const fn authorized_bridge(&self) -> Address {
-        self.authorized_bridge
+    pub fn authorized_bridge(&self) -> Address {
+        self.config.authorized_bridge()
+    }
+
+    /// Returns the configuration of the NativeMinter installed in every EVM.
+    pub fn config(&self) -> &NativeMinterConfig {
+        &self.config
+    }
+
+    /// Returns the configuration of the NativeMinter installed in every EVM, as shared by the
+    /// clones of this factory.
+    pub const fn shared_config(&self) -> &Arc<NativeMinterConfig> {
+        &self.config
+    }
+
+    /// Returns the address of every precompile installed in an EVM for `spec`: the standard
+    /// Ethereum precompiles, the custom precompiles and the NativeMinter, from Cancun only if
+    /// [`cancun_only`](NativeMinterConfig::cancun_only) is set.
+    ///
+    /// The standard precompiles are those of the latest hardfork known to revm, installed for
+    /// every spec as they always have been, so the listing doesn't depend on `spec` for them.
+    ///
+    /// This lets operators verify the full precompile set of a hardfork without creating an EVM.
+    pub fn precompile_addresses(&self, spec: SpecId) -> Vec<Address> {
+        self.precompiles(spec, 1).addresses().copied().collect()
+    }
+
+    /// Returns whether the NativeMinter is installed at each of its addresses in an EVM for
+    /// `spec`.
+    ///
+    /// An address only counts if the precompile there is the stateful NativeMinter itself, not a
+    /// custom precompile or a placeholder registered at the same address. Nodes check this at
+    /// startup so a NativeMinter that failed to register aborts the launch instead of silently
+    /// leaving bridged deposits unminted.
+    pub fn has_native_minter(&self, spec: SpecId) -> bool {
+        let precompiles = self.precompiles(spec, 1);
+        NativeMinterPrecompile::from_config(Arc::clone(&self.config)).addresses().all(|address| {
+            precompiles
+                .get(&address)
+                .is_some_and(|precompile| precompile.precompile_id().name() == NATIVE_MINTER_ID)
+        })
+    }
+
+    /// Returns the precompiles installed in an EVM for `spec` and `chain_id`.
+    ///
+    /// The NativeMinter relies on transient storage (EIP-1153). If
+    /// [`cancun_only`](NativeMinterConfig::cancun_only) is set, EVMs for specs before Cancun get
+    /// the other precompiles only rather than a NativeMinter whose reentrancy guard and
+    /// per-transaction checks would not hold, with a warning logged once per factory. Otherwise
+    /// it is installed for every spec, as skipping it would change how pre-Cancun blocks execute.
+    fn precompiles(&self, spec: SpecId, chain_id: u64) -> PrecompilesMap {
+        // Start from the standard Ethereum precompiles. These are the same for every spec:
+        // narrowing them to the hardfork would change how existing blocks execute
+        let mut precompiles = PrecompilesMap::from_static(EthPrecompiles::default().precompiles);
+
+        for (address, precompile) in &self.precompiles {
+            precompiles.apply_precompile(address, |_| Some(precompile.clone()));
+        }
+
+        if self.config.cancun_only() && !spec.is_enabled_in(SpecId::CANCUN) {
+            // This runs for every EVM, so only warn for the first one
+            self.pre_cancun_warning.call_once(|| {
+                tracing::warn!(
+                    ?spec,
+                    native_minter = %NATIVE_MINTER_ADDRESS,
+                    "NativeMinter requires Cancun, not installing it"
+                );
+            });
+            return precompiles;
+        }
+
+        // Add the NativeMinter stateful precompile at each of its addresses, rejecting mints to
+        // any other precompile. This precompile has access to EVM internals and can modify
+        // account balances. It shares the factory's configuration, only the chain ID and the
+        // precompile addresses are its own
+        let native_minter = NativeMinterPrecompile::from_config(Arc::clone(&self.config))
+            .with_chain_id(chain_id)
+            .with_precompile_addresses(precompiles.addresses().copied().collect::<Vec<_>>());
+        for address in native_minter.addresses() {
+            let native_minter_dyn = native_minter.clone().into_dyn_precompile();
+            precompiles.apply_precompile(&address, |_| Some(native_minter_dyn));
+        }
+
+        precompiles
     }
 }
 
@@ -93,33 +236,23 @@ impl EvmFactory for RkbEvmFactory {
 
     fn create_evm<DB: Database>(&self, db: DB, input: EvmEnv) -> Self::Evm<DB, NoOpInspector> {
         let spec = input.cfg_env.spec;
+        let chain_id = input.cfg_env.chain_id;
 
         tracing::debug!(
             ?spec,
-            authorized_bridge = %self.authorized_bridge,
+            authorized_bridge = %self.authorized_bridge(),
             native_minter = %NATIVE_MINTER_ADDRESS,
             "Creating RKB EVM with NativeMinter"
         );
 
-        // Create base EVM with standard Ethereum precompiles
         let evm = revm::Context::mainnet()
             .with_db(db)
             .with_cfg(input.cfg_env)
             .with_block(input.block_env)
             .build_mainnet_with_inspector(NoOpInspector {})
-            .with_precompiles(PrecompilesMap::from_static(EthPrecompiles::default().precompiles));
-
-        let mut evm = EthEvm::new(evm, false);
-
-        // Add the NativeMinter stateful precompile
-        // This precompile has access to EVM internals and can modify account balances
-        let native_minter = NativeMinterPrecompile::new(self.authorized_bridge);
-        let native_minter_dyn = native_minter.into_dyn_precompile();
+            .with_precompiles(self.precompiles(spec, chain_id));
 
-        evm.precompiles_mut()
-            .apply_precompile(&NATIVE_MINTER_ADDRESS, |_| Some(native_minter_dyn));
-
-        evm
+        EthEvm::new(evm, false)
     }
 
     fn create_evm_with_inspector<DB: Database, I: Inspector<Self::Context<DB>, EthInterpreter>>(
@@ -128,17 +261,27 @@ impl EvmFactory for RkbEvmFactory {
         input: EvmEnv,
         inspector: I,
     ) -> Self::Evm<DB, I> {
-        EthEvm::new(
-            self.create_evm(db, input).into_inner().with_inspector(inspector),
-            true,
-        )
+        EthEvm::new(self.create_evm(db, input).into_inner().with_inspector(inspector), true)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::address;
+    use crate::{
+        native_minter::{mintCall, totalMintedCall},
+        storage::{TOTAL_BURNED_SLOT, TOTAL_MINTED_SLOT},
+        test_utils::CapturedLogs,
+        MintFee,
+    };
+    use alloy_evm::{precompiles::PrecompileInput, Evm};
+    use alloy_primitives::{address, hex, Bytes, U256};
+    use alloy_sol_types::{SolCall, SolValue};
+    use reth_ethereum::evm::revm::{
+        context_interface::result::ExecutionResult,
+        precompile::{PrecompileId, PrecompileOutput},
+    };
+    use revm::{bytecode::Bytecode, database::InMemoryDB, state::AccountInfo, DatabaseRef};
 
     #[test]
     fn test_factory_creation() {
@@ -147,9 +290,364 @@ mod tests {
         assert_eq!(factory.authorized_bridge(), bridge);
     }
 
+    #[test]
+    fn test_factory_clone_shares_config() {
+        let factory = RkbEvmFactory::new(address!("0x1234567890abcdef1234567890abcdef12345678"));
+        let clone = factory.clone();
+        assert!(Arc::ptr_eq(factory.shared_config(), clone.shared_config()));
+
+        let shared = Arc::clone(factory.shared_config());
+        let factory = RkbEvmFactory::with_config(Arc::clone(&shared));
+        assert!(Arc::ptr_eq(factory.shared_config(), &shared));
+    }
+
     #[test]
     fn test_default_factory() {
         let factory = RkbEvmFactory::default();
         assert_eq!(factory.authorized_bridge(), Address::ZERO);
     }
+
+    /// Sends a transaction from `caller` minting `amount` to `recipient` and commits it.
+    fn mint<E: Evm<DB = InMemoryDB, Tx = TxEnv, HaltReason = HaltReason>>(
+        evm: &mut E,
+        caller: Address,
+        nonce: u64,
+        recipient: Address,
+        amount: U256,
+    ) -> ExecutionResult<HaltReason> {
+        evm.transact_commit(TxEnv {
+            caller,
+            nonce,
+            gas_limit: 100_000,
+            data: mintCall { recipient, amount }.abi_encode().into(),
+            kind: NATIVE_MINTER_ADDRESS.into(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    /// Executes a block of `mints` calls from `bridge` on top of `db`, returning the new state.
+    fn execute_block(
+        factory: &RkbEvmFactory,
+        db: InMemoryDB,
+        bridge: Address,
+        mints: &[(Address, U256)],
+    ) -> InMemoryDB {
+        let mut evm = factory.create_evm(db, EvmEnv::default());
+        for (nonce, &(recipient, amount)) in mints.iter().enumerate() {
+            let result = mint(&mut evm, bridge, nonce as u64, recipient, amount);
+            assert!(result.is_success(), "{result:?}");
+        }
+        evm.into_db()
+    }
+
+    #[test]
+    fn test_factory_config_enforced_at_call_time() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let other = address!("0x00000000000000000000000000000000000000bb");
+        let recipient = address!("0x00000000000000000000000000000000000000aa");
+        let config =
+            NativeMinterPrecompile::new(bridge).with_supply_cap(U256::from(1_000)).into_config();
+        let factory = RkbEvmFactory::with_config(config);
+        assert_eq!(factory.authorized_bridge(), bridge);
+
+        let mut evm = factory.create_evm(InMemoryDB::default(), EvmEnv::default());
+
+        // Only the configured bridge may mint, and only up to the configured cap
+        assert!(!mint(&mut evm, other, 0, recipient, U256::from(1)).is_success());
+        assert!(!mint(&mut evm, bridge, 0, recipient, U256::from(1_001)).is_success());
+        assert!(mint(&mut evm, bridge, 1, recipient, U256::from(1_000)).is_success());
+
+        let db = evm.into_db();
+        assert_eq!(db.basic_ref(recipient).unwrap().unwrap().balance, U256::from(1_000));
+    }
+
+    #[test]
+    fn test_supply_totals_survive_reexecution() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let recipient = address!("0x00000000000000000000000000000000000000aa");
+        let factory = RkbEvmFactory::new(bridge);
+        let mints = [(recipient, U256::from(1_000)), (recipient, U256::from(500))];
+
+        // The precompile's account is allocated at genesis so its storage is never cleared
+        let mut parent = InMemoryDB::default();
+        parent.insert_account_info(
+            NATIVE_MINTER_ADDRESS,
+            AccountInfo { nonce: 1, ..Default::default() },
+        );
+
+        let executed = execute_block(&factory, parent.clone(), bridge, &mints);
+        let total_minted = executed.storage_ref(NATIVE_MINTER_ADDRESS, TOTAL_MINTED_SLOT).unwrap();
+        assert_eq!(total_minted, U256::from(1_500));
+
+        // A reorg discards the block's state and re-executes it on top of the parent state
+        let reexecuted = execute_block(&factory, parent, bridge, &mints);
+        assert_eq!(
+            reexecuted.storage_ref(NATIVE_MINTER_ADDRESS, TOTAL_MINTED_SLOT).unwrap(),
+            total_minted
+        );
+        assert_eq!(
+            reexecuted.storage_ref(NATIVE_MINTER_ADDRESS, TOTAL_BURNED_SLOT).unwrap(),
+            U256::ZERO
+        );
+        assert_eq!(reexecuted.basic_ref(recipient).unwrap().unwrap().balance, U256::from(1_500));
+    }
+
+    #[test]
+    fn test_additional_address_shares_state() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let recipient = address!("0x00000000000000000000000000000000000000aa");
+        let new_address = address!("0x0000000000000000000000000000000000000421");
+        let config =
+            NativeMinterPrecompile::new(bridge).with_additional_address(new_address).into_config();
+        let factory = RkbEvmFactory::with_config(config);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            NATIVE_MINTER_ADDRESS,
+            AccountInfo { nonce: 1, ..Default::default() },
+        );
+        let mut evm = factory.create_evm(db, EvmEnv::default());
+
+        // Mint through the old address, then read the total through the new one
+        assert!(mint(&mut evm, bridge, 0, recipient, U256::from(1_000)).is_success());
+        let result = evm
+            .transact(TxEnv {
+                caller: recipient,
+                gas_limit: 100_000,
+                data: totalMintedCall {}.abi_encode().into(),
+                kind: new_address.into(),
+                ..Default::default()
+            })
+            .unwrap()
+            .result;
+        assert_eq!(U256::abi_decode(result.output().unwrap()).unwrap(), U256::from(1_000));
+
+        // Both addresses write the canonical account's storage
+        let db = evm.into_db();
+        assert_eq!(
+            db.storage_ref(NATIVE_MINTER_ADDRESS, TOTAL_MINTED_SLOT).unwrap(),
+            U256::from(1_000)
+        );
+        assert_eq!(db.storage_ref(new_address, TOTAL_MINTED_SLOT).unwrap(), U256::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_config_fails_at_construction() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let fee_recipient = address!("0x00000000000000000000000000000000000000fe");
+        let additional_address = address!("0x0000000000000000000000000000000000000421");
+
+        assert!(RkbEvmFactory::try_with_config(NativeMinterConfig::new(bridge)).is_ok());
+        assert!(matches!(
+            RkbEvmFactory::try_with_config(NativeMinterConfig::new(NATIVE_MINTER_ADDRESS)),
+            Err(RkbConfigError::BridgeIsNativeMinter)
+        ));
+        assert!(matches!(
+            RkbEvmFactory::try_with_config(
+                NativeMinterPrecompile::new(additional_address)
+                    .with_additional_address(additional_address)
+                    .into_config()
+            ),
+            Err(RkbConfigError::BridgeIsNativeMinter)
+        ));
+        assert!(matches!(
+            RkbEvmFactory::try_with_config(
+                NativeMinterPrecompile::new(bridge)
+                    .with_mint_fee(MintFee::new(10_001, fee_recipient))
+                    .into_config()
+            ),
+            Err(RkbConfigError::FeeTooHigh(10_001))
+        ));
+    }
+
+    /// Contract forwarding its calldata to the NativeMinter, reverting if the call fails.
+    const FORWARDER: &str = "365f5f375f5f365f5f6104205af115601357005b5f5ffd";
+
+    /// Deploys [`FORWARDER`] and delegates each of `accounts` to it with EIP-7702.
+    fn delegate_to_forwarder(db: &mut InMemoryDB, forwarder: Address, accounts: &[Address]) {
+        let code = Bytecode::new_raw(hex::decode(FORWARDER).unwrap().into());
+        db.insert_account_info(
+            forwarder,
+            AccountInfo { code_hash: code.hash_slow(), code: Some(code), ..Default::default() },
+        );
+        for &account in accounts {
+            let code = Bytecode::new_eip7702(forwarder);
+            db.insert_account_info(
+                account,
+                AccountInfo { code_hash: code.hash_slow(), code: Some(code), ..Default::default() },
+            );
+        }
+    }
+
+    #[test]
+    fn test_eip7702_delegated_caller() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let other = address!("0x00000000000000000000000000000000000000bb");
+        let sender = address!("0x00000000000000000000000000000000000000cc");
+        let forwarder = address!("0x00000000000000000000000000000000000000ff");
+        let recipient = address!("0x00000000000000000000000000000000000000aa");
+
+        let mut db = InMemoryDB::default();
+        delegate_to_forwarder(&mut db, forwarder, &[bridge, other]);
+        let mut env = EvmEnv::default();
+        env.cfg_env.spec = SpecId::PRAGUE;
+        let mut evm = RkbEvmFactory::new(bridge).create_evm(db, env);
+
+        // The delegated code calls the precompile with the delegating account as caller
+        let mut mint_via = |nonce, account: Address| {
+            evm.transact_commit(TxEnv {
+                caller: sender,
+                nonce,
+                gas_limit: 100_000,
+                data: mintCall { recipient, amount: U256::from(1_000) }.abi_encode().into(),
+                kind: account.into(),
+                ..Default::default()
+            })
+            .unwrap()
+        };
+        assert!(mint_via(0, bridge).is_success());
+        assert!(!mint_via(1, other).is_success());
+
+        let db = evm.into_db();
+        assert_eq!(db.basic_ref(recipient).unwrap().unwrap().balance, U256::from(1_000));
+    }
+
+    #[test]
+    fn test_precompile_addresses_cancun() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let addresses = RkbEvmFactory::new(bridge).precompile_addresses(SpecId::CANCUN);
+
+        assert!(addresses.contains(&NATIVE_MINTER_ADDRESS));
+        // ecrecover through the KZG point evaluation precompile
+        for index in 1..=0x0a {
+            assert!(addresses.contains(&Address::with_last_byte(index)), "missing {index:#x}");
+        }
+        // The standard precompiles are installed for every spec, as in every EVM
+        let standard = EthPrecompiles::default().precompiles.addresses().count();
+        assert_eq!(addresses.len(), standard + 1);
+    }
+
+    #[test]
+    fn test_mint_to_installed_precompile_rejected() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let recipient = address!("0x00000000000000000000000000000000000000aa");
+        let ecrecover = Address::with_last_byte(1);
+        let mut evm =
+            RkbEvmFactory::new(bridge).create_evm(InMemoryDB::default(), EvmEnv::default());
+
+        assert!(!mint(&mut evm, bridge, 0, ecrecover, U256::from(1_000)).is_success());
+        assert!(!mint(&mut evm, bridge, 1, NATIVE_MINTER_ADDRESS, U256::from(1_000)).is_success());
+        assert!(mint(&mut evm, bridge, 2, recipient, U256::from(1_000)).is_success());
+    }
+
+    /// Application error an integrator maps execution failures into.
+    #[derive(Debug, thiserror::Error)]
+    enum AppError {
+        /// The transaction could not be executed.
+        #[error("transaction failed: {0}")]
+        Evm(String),
+        /// The transaction halted, e.g. because the NativeMinter rejected the call.
+        #[error("transaction halted after {gas_used} gas: {reason:?}")]
+        Halted { reason: HaltReason, gas_used: u64 },
+        /// The transaction reverted.
+        #[error("transaction reverted: {0}")]
+        Reverted(Bytes),
+    }
+
+    /// Wraps [`RkbEvmFactory`], mapping execution failures into [`AppError`] without touching the
+    /// factory's associated types.
+    struct MonitoredFactory(RkbEvmFactory);
+
+    impl MonitoredFactory {
+        fn execute<DB: Database>(&self, db: DB, tx: TxEnv) -> Result<Bytes, AppError> {
+            let mut evm = self.0.create_evm(db, EvmEnv::default());
+            let result = evm.transact(tx).map_err(|err| AppError::Evm(err.to_string()))?.result;
+            match result {
+                ExecutionResult::Success { output, .. } => Ok(output.into_data()),
+                ExecutionResult::Revert { output, .. } => Err(AppError::Reverted(output)),
+                ExecutionResult::Halt { reason, gas_used } => {
+                    Err(AppError::Halted { reason, gas_used })
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_mapping_wrapper() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let recipient = address!("0x00000000000000000000000000000000000000aa");
+        let factory = MonitoredFactory(RkbEvmFactory::new(bridge));
+        let mint_from = |caller| TxEnv {
+            caller,
+            gas_limit: 100_000,
+            data: mintCall { recipient, amount: U256::from(1_000) }.abi_encode().into(),
+            kind: NATIVE_MINTER_ADDRESS.into(),
+            ..Default::default()
+        };
+
+        let output = factory.execute(InMemoryDB::default(), mint_from(bridge)).unwrap();
+        assert_eq!(output, Bytes::new());
+
+        let err = factory.execute(InMemoryDB::default(), mint_from(recipient)).unwrap_err();
+        assert!(matches!(err, AppError::Halted { .. }), "{err:?}");
+        assert!(err.to_string().starts_with("transaction halted after 100000 gas"), "{err}");
+    }
+
+    #[test]
+    fn test_native_minter_requires_cancun() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let factory = RkbEvmFactory::with_config(
+            NativeMinterPrecompile::new(bridge).with_cancun_only(true).into_config(),
+        );
+        let mut addresses = Vec::new();
+        let logs = CapturedLogs::capture(|| {
+            factory.precompile_addresses(SpecId::SHANGHAI);
+            addresses = factory.clone().precompile_addresses(SpecId::SHANGHAI);
+        });
+        assert!(!addresses.contains(&NATIVE_MINTER_ADDRESS));
+        assert!(addresses.contains(&Address::with_last_byte(1)));
+        assert!(logs.contains("WARN"), "{logs}");
+        // Warned once, not for every EVM
+        assert_eq!(logs.matches("NativeMinter requires Cancun, not installing it").count(), 1);
+
+        for spec in [SpecId::CANCUN, SpecId::PRAGUE] {
+            assert!(factory.precompile_addresses(spec).contains(&NATIVE_MINTER_ADDRESS));
+        }
+
+        // Unless restricted to Cancun, the NativeMinter is installed for every spec
+        let factory = RkbEvmFactory::new(bridge);
+        assert!(!factory.config().cancun_only());
+        assert!(factory.precompile_addresses(SpecId::SHANGHAI).contains(&NATIVE_MINTER_ADDRESS));
+    }
+
+    #[test]
+    fn test_has_native_minter() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let config = NativeMinterPrecompile::new(bridge)
+            .with_additional_address(address!("0x0000000000000000000000000000000000000421"))
+            .into_config();
+        for factory in [RkbEvmFactory::new(bridge), RkbEvmFactory::with_config(config)] {
+            assert!(factory.has_native_minter(SpecId::SHANGHAI));
+            assert!(factory.has_native_minter(SpecId::CANCUN));
+            assert!(factory.has_native_minter(SpecId::PRAGUE));
+        }
+
+        // If restricted to Cancun, the NativeMinter is not installed before it
+        let cancun_only = NativeMinterPrecompile::new(bridge).with_cancun_only(true).into_config();
+        let factory = RkbEvmFactory::with_config(cancun_only.clone());
+        assert!(!factory.has_native_minter(SpecId::SHANGHAI));
+        assert!(factory.has_native_minter(SpecId::CANCUN));
+
+        // A placeholder at the NativeMinter's address doesn't count as the NativeMinter
+        let placeholder =
+            DynPrecompile::new(PrecompileId::custom("placeholder"), |_: PrecompileInput<'_>| {
+                Ok(PrecompileOutput::new(0, Bytes::new()))
+            });
+        let factory = RkbEvmFactory::with_config(cancun_only)
+            .with_precompiles(vec![(NATIVE_MINTER_ADDRESS, placeholder)]);
+        assert!(factory.precompile_addresses(SpecId::SHANGHAI).contains(&NATIVE_MINTER_ADDRESS));
+        assert!(!factory.has_native_minter(SpecId::SHANGHAI));
+        assert!(factory.has_native_minter(SpecId::CANCUN));
+    }
 }
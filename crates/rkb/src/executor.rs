@@ -1,13 +1,25 @@
 //! RKB Executor Builder - Builds EVM config with NativeMinter precompile.
 
-use crate::RkbEvmFactory;
+use crate::{
+    bridge_from_extra_data,
+    rpc::{RkbConfigResponse, RkbRpc},
+    MintBurnRecord, NativeMinterConfig, NativeMinterPrecompile, RkbConfig, RkbConfigError,
+    RkbEvmFactory, Role,
+};
+use alloy_evm::precompiles::DynPrecompile;
+use alloy_genesis::Genesis;
 use alloy_primitives::Address;
-use reth_chainspec::{EthereumHardforks, Hardforks};
-use reth_ethereum::evm::EthEvmConfig;
+use reth_chainspec::{EthChainSpec, EthereumHardforks, Hardforks};
+use reth_ethereum::evm::{
+    revm::primitives::hardfork::SpecId, revm_spec_by_timestamp_and_block_number, EthEvmConfig,
+};
 use reth_ethereum_primitives::EthPrimitives;
 use reth_evm::eth::spec::EthExecutorSpec;
 use reth_node_api::NodeTypes;
 use reth_node_builder::{components::ExecutorBuilder, node::FullNodeTypes, BuilderContext};
+use reth_prune_types::PruneModes;
+use tokio::sync::mpsc::Sender;
+use tracing::Level;
 
 /// RKB Executor Builder - builds EVM config with NativeMinter precompile.
 ///
@@ -27,21 +39,196 @@ use reth_node_builder::{components::ExecutorBuilder, node::FullNodeTypes, Builde
 /// ```
 #[derive(Debug, Clone)]
 pub struct RkbExecutorBuilder {
-    /// Authorized bridge address for NativeMinter.
-    authorized_bridge: Address,
+    /// Configuration of the NativeMinter installed in the EVM, shared with the EVM factory.
+    config: NativeMinterConfig,
+    /// Additional custom precompiles installed alongside the NativeMinter, keyed by address.
+    precompiles: Vec<(Address, DynPrecompile)>,
+    /// Whether the authorized bridge is read from the genesis `extraData`.
+    bridge_from_genesis: bool,
 }
 
 impl RkbExecutorBuilder {
     /// Creates a new RKB executor builder with the given authorized bridge address.
     pub const fn new(authorized_bridge: Address) -> Self {
-        Self { authorized_bridge }
+        Self {
+            config: NativeMinterConfig::new(authorized_bridge),
+            precompiles: Vec::new(),
+            bridge_from_genesis: false,
+        }
+    }
+
+    /// Creates a new RKB executor builder from a file-based configuration.
+    pub fn from_config(config: RkbConfig) -> Result<Self, RkbConfigError> {
+        Ok(Self {
+            config: config.native_minter()?.into_config(),
+            precompiles: Vec::new(),
+            bridge_from_genesis: false,
+        })
     }
 
     /// Creates a new RKB executor builder with zero address (for testing only).
     pub const fn testing() -> Self {
+        Self::new(Address::ZERO)
+    }
+
+    /// Creates a new RKB executor builder whose NativeMinter runs in observe-only mode.
+    ///
+    /// The precompile performs all checks and logging, but mints and burns succeed without
+    /// changing any balance. This is a diagnostic mode for shadow nodes debugging consensus
+    /// divergence: a node running it computes different state roots and must never validate.
+    pub fn observe_only(authorized_bridge: Address) -> Self {
         Self {
-            authorized_bridge: Address::ZERO,
+            config: NativeMinterPrecompile::new(authorized_bridge)
+                .with_observe_only()
+                .into_config(),
+            precompiles: Vec::new(),
+            bridge_from_genesis: false,
+        }
+    }
+
+    /// Registers additional custom precompiles, such as a fee-splitting precompile, to be
+    /// installed alongside the NativeMinter.
+    ///
+    /// The NativeMinter takes precedence over a custom precompile registered at one of its
+    /// addresses.
+    pub fn with_precompiles(mut self, precompiles: Vec<(Address, DynPrecompile)>) -> Self {
+        self.precompiles.extend(precompiles);
+        self
+    }
+
+    /// Sends a record of every successful mint and burn to `sender`, see
+    /// [`NativeMinterPrecompile::with_mint_channel`].
+    pub fn with_mint_channel(mut self, sender: Sender<MintBurnRecord>) -> Self {
+        self.config = NativeMinterPrecompile::from_config(self.config)
+            .with_mint_channel(sender)
+            .into_config();
+        self
+    }
+
+    /// Logs calls rejected by the NativeMinter at `level`, see
+    /// [`NativeMinterPrecompile::with_rejection_log_level`].
+    pub fn with_rejection_log_level(mut self, level: Level) -> Self {
+        self.config = NativeMinterPrecompile::from_config(self.config)
+            .with_rejection_log_level(level)
+            .into_config();
+        self
+    }
+
+    /// Reads the authorized bridge from the chain's genesis `extraData` when building the EVM,
+    /// replacing the bridge the builder was created with, so the bridge is part of the genesis
+    /// commitment. The node fails to start if the `extraData` doesn't follow the layout of
+    /// [`bridge_from_extra_data`].
+    pub const fn with_bridge_from_genesis(mut self) -> Self {
+        self.bridge_from_genesis = true;
+        self
+    }
+
+    /// Applies the configuration read from `genesis`, if the bridge is read from it.
+    fn with_genesis(mut self, genesis: &Genesis) -> Result<Self, RkbConfigError> {
+        if self.bridge_from_genesis {
+            let bridge = bridge_from_extra_data(&genesis.extra_data)?;
+            self.config = NativeMinterPrecompile::from_config(self.config)
+                .with_authorized_bridge(bridge)
+                .into_config();
+        }
+        Ok(self)
+    }
+
+    /// Checks that `spec` is compatible with the NativeMinter this builder installs, for
+    /// operators to run before launching.
+    ///
+    /// The chain must activate Cancun at genesis, as the precompile set and the transient storage
+    /// the NativeMinter relies on are only available from Cancun. Its genesis alloc must not
+    /// deploy code at any of the NativeMinter's addresses, as the precompile would shadow it.
+    /// Allocating an account without code there, as
+    /// [`genesis_alloc`](crate::storage::genesis_alloc) does, is fine. If the bridge is read from
+    /// the genesis `extraData`, it must parse.
+    pub fn validate_chain_spec<C>(&self, spec: &C) -> Result<(), RkbConfigError>
+    where
+        C: EthChainSpec + EthereumHardforks,
+    {
+        let genesis = spec.genesis();
+        if !spec.is_cancun_active_at_timestamp(genesis.timestamp) {
+            return Err(RkbConfigError::HardforkNotActive("Cancun"));
+        }
+
+        for address in self.native_minter().addresses() {
+            let code = genesis.alloc.get(&address).and_then(|account| account.code.as_ref());
+            if code.is_some_and(|code| !code.is_empty()) {
+                return Err(RkbConfigError::CodeAtNativeMinter(address));
+            }
+        }
+
+        if self.bridge_from_genesis {
+            bridge_from_extra_data(&genesis.extra_data)?;
         }
+
+        Ok(())
+    }
+
+    /// Checks that the node's pruning configuration keeps the NativeMinter's storage history.
+    ///
+    /// Reth never prunes the current state, so the supply counters and processed-message set the
+    /// NativeMinter's guarantees rely on always survive. Storage history pruning however drops
+    /// their past values, which audits of the supply at past blocks and replays of historical
+    /// calls read. Reth can't exempt an address from it, so the node refuses to start with any
+    /// storage history pruning. Account history, receipts and the other segments don't hold
+    /// NativeMinter state and may be pruned.
+    pub const fn validate_prune_modes(&self, modes: &PruneModes) -> Result<(), RkbConfigError> {
+        if let Some(mode) = modes.storage_history {
+            return Err(RkbConfigError::StorageHistoryPruned(mode));
+        }
+        Ok(())
+    }
+
+    /// Returns the configuration of the NativeMinter installed in the EVM.
+    pub const fn config(&self) -> &NativeMinterConfig {
+        &self.config
+    }
+
+    /// Returns the additional custom precompiles installed alongside the NativeMinter.
+    pub fn precompiles(&self) -> &[(Address, DynPrecompile)] {
+        &self.precompiles
+    }
+
+    /// Returns the NativeMinter installed in the EVM, before its chain ID is set.
+    pub fn native_minter(&self) -> NativeMinterPrecompile {
+        NativeMinterPrecompile::from_config(self.config.clone())
+    }
+
+    /// Returns whether the NativeMinter is installed in EVMs built for `spec`, see
+    /// [`RkbEvmFactory::has_native_minter`]. Always false if the configuration is invalid.
+    pub fn has_native_minter(&self, spec: SpecId) -> bool {
+        self.clone().evm_factory().is_ok_and(|factory| factory.has_native_minter(spec))
+    }
+
+    /// Returns whether the NativeMinter is installed in EVMs executing the genesis block of
+    /// `chain`, at the spec the chain activates there.
+    ///
+    /// Nodes check this at startup rather than a fixed spec, so a NativeMinter restricted to
+    /// Cancun on a chain that doesn't activate it at genesis aborts the launch.
+    pub fn has_native_minter_at_genesis<C>(&self, chain: &C) -> bool
+    where
+        C: EthChainSpec + EthereumHardforks,
+    {
+        let timestamp = chain.genesis().timestamp;
+        self.has_native_minter(revm_spec_by_timestamp_and_block_number(chain, timestamp, 0))
+    }
+
+    /// Returns the `rkb` RPC namespace serving this builder's NativeMinter configuration.
+    ///
+    /// The configuration is applied to `genesis` first, as when building the EVM, so the
+    /// namespace reports the bridge read from the extraData if
+    /// [`with_bridge_from_genesis`](Self::with_bridge_from_genesis) is set.
+    pub fn rpc(&self, genesis: &Genesis) -> Result<RkbRpc, RkbConfigError> {
+        let this = self.clone().with_genesis(genesis)?;
+        Ok(RkbRpc::new(RkbConfigResponse::from(&this.native_minter())))
+    }
+
+    /// Returns the EVM factory installing the NativeMinter and the custom precompiles, failing if
+    /// the NativeMinter configuration is invalid.
+    fn evm_factory(self) -> Result<RkbEvmFactory, RkbConfigError> {
+        Ok(RkbEvmFactory::try_with_config(self.config)?.with_precompiles(self.precompiles))
     }
 }
 
@@ -54,7 +241,7 @@ impl Default for RkbExecutorBuilder {
 impl<Types, Node> ExecutorBuilder<Node> for RkbExecutorBuilder
 where
     Types: NodeTypes<
-        ChainSpec: Hardforks + EthExecutorSpec + EthereumHardforks,
+        ChainSpec: Hardforks + EthExecutorSpec + EthereumHardforks + EthChainSpec,
         Primitives = EthPrimitives,
     >,
     Node: FullNodeTypes<Types = Types>,
@@ -62,14 +249,278 @@ where
     type EVM = EthEvmConfig<Types::ChainSpec, RkbEvmFactory>;
 
     async fn build_evm(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::EVM> {
-        tracing::info!(
-            authorized_bridge = %self.authorized_bridge,
-            "Building RKB EVM with NativeMinter precompile"
-        );
+        let this = self.with_genesis(ctx.chain_spec().genesis())?;
+
+        // Pruning flags take precedence over the TOML config, as when the node launches
+        let toml_prune_config = ctx.reth_config().prune.clone();
+        let prune_modes = match ctx.config().prune_config() {
+            Some(mut prune_config) => {
+                prune_config.merge(toml_prune_config);
+                prune_config.segments
+            }
+            None => toml_prune_config.segments,
+        };
+        this.validate_prune_modes(&prune_modes)?;
 
-        let factory = RkbEvmFactory::new(self.authorized_bridge);
+        let native_minter = this.native_minter();
+        let factory = this.evm_factory()?;
         let evm_config = EthEvmConfig::new_with_evm_factory(ctx.chain_spec(), factory);
 
+        log_config(&native_minter);
+
         Ok(evm_config)
     }
 }
+
+/// Logs a single snapshot of the full NativeMinter configuration, for operators to audit at
+/// startup.
+fn log_config(native_minter: &NativeMinterPrecompile) {
+    tracing::info!(
+        authorized_bridge = %native_minter.authorized_bridge(),
+        bridge_source = ?native_minter.bridge_source(),
+        additional_bridges = ?native_minter.additional_bridges(),
+        minters = ?native_minter.role_members(Role::Minter),
+        burners = ?native_minter.role_members(Role::Burner),
+        pausers = ?native_minter.role_members(Role::Pauser),
+        gas_model = ?native_minter.gas_model(),
+        mint_gas_cost = native_minter.mint_gas_cost(),
+        burn_gas_cost = native_minter.burn_gas_cost(),
+        view_gas_cost = native_minter.view_gas_cost(),
+        decode_gas_per_word = native_minter.decode_gas_per_word(),
+        storage_salt = %native_minter.storage_salt(),
+        governor = ?native_minter.governor(),
+        max_batch_size = native_minter.max_batch_size(),
+        max_calldata_len = native_minter.max_calldata_len(),
+        burn_mode = ?native_minter.burn_mode(),
+        return_bool = native_minter.return_bool(),
+        decimals = native_minter.decimals(),
+        asset_decimals = ?native_minter.all_asset_decimals(),
+        strict_calldata = native_minter.strict_calldata(),
+        error_verbosity = ?native_minter.error_verbosity(),
+        rejection_log_level = %native_minter.rejection_log_level(),
+        burn_allowance_required = native_minter.burn_allowance_required(),
+        require_origin_burn = native_minter.require_origin_burn(),
+        allowance_output = native_minter.allowance_output(),
+        burn_summary_output = native_minter.burn_summary_output(),
+        reject_missing_account_burn = native_minter.reject_missing_account_burn(),
+        require_existing_recipient = native_minter.require_existing_recipient(),
+        max_recipients_per_block = ?native_minter.max_recipients_per_block(),
+        mint_cooldown_blocks = ?native_minter.mint_cooldown_blocks(),
+        supply_cap = ?native_minter.supply_cap(),
+        breaker_threshold = ?native_minter.breaker_threshold(),
+        mint_fee = ?native_minter.mint_fee(),
+        rate_limit = ?native_minter.rate_limit(),
+        max_amount_per_call = %native_minter.max_amount_per_call(),
+        mint_dedup = native_minter.mint_dedup(),
+        saturating_mint = native_minter.saturating_mint(),
+        value_mode = ?native_minter.value_mode(),
+        mint_target = ?native_minter.mint_target(),
+        track_minted_to = native_minter.track_minted_to(),
+        event_scheme = ?native_minter.event_scheme(),
+        aggregate_batch_events = native_minter.aggregate_batch_events(),
+        activation_block = ?native_minter.activation_block(),
+        min_base_fee = ?native_minter.min_base_fee(),
+        emergency_mint = ?native_minter.emergency_mint(),
+        mint_approver = ?native_minter.mint_approver(),
+        delegatecall_proxies = ?native_minter.delegatecall_proxies(),
+        addresses = ?native_minter.addresses().collect::<Vec<_>>(),
+        log_target = native_minter.log_target(),
+        observe_only = native_minter.observe_only(),
+        cancun_only = native_minter.cancun_only(),
+        mint_channel = native_minter.mint_channel().is_some(),
+        "Building RKB EVM with NativeMinter precompile"
+    );
+
+    if native_minter.observe_only() {
+        tracing::warn!("NativeMinter running in observe-only mode, balances will not change");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{native_minter::totalMintedCall, NATIVE_MINTER_ADDRESS};
+    use crate::{test_utils::CapturedLogs, MintFee, RateLimit};
+    use alloy_evm::{precompiles::PrecompileInput, Evm, EvmFactory};
+    use alloy_genesis::GenesisAccount;
+    use alloy_primitives::{address, Bytes, U256};
+    use alloy_sol_types::SolCall;
+    use reth_chainspec::{Chain, ChainSpecBuilder};
+    use reth_ethereum::evm::{primitives::EvmEnv, revm::context::TxEnv};
+    use reth_prune_types::PruneMode;
+    use revm::{
+        database::InMemoryDB,
+        precompile::{PrecompileId, PrecompileOutput},
+    };
+
+    fn captured_config_log(native_minter: &NativeMinterPrecompile) -> String {
+        // Addresses are logged checksummed
+        CapturedLogs::capture(|| log_config(native_minter)).to_lowercase()
+    }
+
+    #[test]
+    fn test_config_log_default() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let logs = captured_config_log(&NativeMinterPrecompile::new(bridge));
+
+        assert!(logs.contains("authorized_bridge=0x1234567890abcdef1234567890abcdef12345678"));
+        assert!(logs.contains("gas_model=flat(6000)"), "{logs}");
+        assert!(logs.contains("mint_gas_cost=6000 burn_gas_cost=6800"), "{logs}");
+        assert!(logs.contains("supply_cap=none"), "{logs}");
+        assert!(logs.contains("observe_only=false"), "{logs}");
+        // The summary is logged at info level, rejections at the default warn level
+        assert!(logs.contains(" info reth_rkb::executor"), "{logs}");
+        assert!(!logs.contains(" warn reth_rkb::executor"), "{logs}");
+        assert!(logs.contains("rejection_log_level=warn"), "{logs}");
+    }
+
+    #[test]
+    fn test_config_log_non_default() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let pauser = address!("0x00000000000000000000000000000000000000aa");
+        let fee_recipient = address!("0x00000000000000000000000000000000000000bb");
+        let native_minter = NativeMinterPrecompile::new(bridge)
+            .add_pauser(pauser)
+            .with_mint_gas_cost(7_000)
+            .with_supply_cap(U256::from(1_000_000))
+            .with_mint_fee(MintFee::new(250, fee_recipient))
+            .with_rate_limit(RateLimit::new(U256::from(5_000), 3_600))
+            .with_activation_block(100)
+            .with_observe_only();
+        let logs = captured_config_log(&native_minter);
+
+        for field in [
+            "pausers={0x00000000000000000000000000000000000000aa}",
+            "mint_gas_cost=7000",
+            "supply_cap=some(1000000)",
+            "mint_fee=some(mintfee { bps: 250, recipient: 0x00000000000000000000000000000000000000bb })",
+            "rate_limit=some(ratelimit { max_amount: 5000, window_secs: 3600 })",
+            "activation_block=some(100)",
+            "observe_only=true",
+        ] {
+            assert!(logs.contains(field), "{field} missing from {logs}");
+        }
+        assert!(logs.contains("observe-only mode"), "{logs}");
+    }
+
+    #[test]
+    fn test_custom_precompiles_alongside_native_minter() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let fee_collector = address!("0x0000000000000000000000000000000000000421");
+        let precompile =
+            DynPrecompile::new(PrecompileId::custom("fee_collector"), |_: PrecompileInput<'_>| {
+                Ok(PrecompileOutput::new(0, Bytes::from_static(b"fee")))
+            });
+        let builder =
+            RkbExecutorBuilder::new(bridge).with_precompiles(vec![(fee_collector, precompile)]);
+        assert_eq!(builder.precompiles().len(), 1);
+
+        let mut evm =
+            builder.evm_factory().unwrap().create_evm(InMemoryDB::default(), EvmEnv::default());
+        let mut call = |to: Address, data: Bytes| {
+            let tx = TxEnv { gas_limit: 100_000, data, kind: to.into(), ..Default::default() };
+            evm.transact(tx).unwrap().result.into_output().unwrap()
+        };
+
+        assert_eq!(call(fee_collector, Bytes::new()), Bytes::from_static(b"fee"));
+        assert_eq!(
+            call(NATIVE_MINTER_ADDRESS, totalMintedCall {}.abi_encode().into()),
+            Bytes::from(U256::ZERO.to_be_bytes::<32>())
+        );
+    }
+
+    #[test]
+    fn test_invalid_config_fails_to_build_factory() {
+        let builder = RkbExecutorBuilder::new(NATIVE_MINTER_ADDRESS);
+        assert!(!builder.has_native_minter(SpecId::CANCUN));
+        assert!(matches!(builder.evm_factory(), Err(RkbConfigError::BridgeIsNativeMinter)));
+    }
+
+    #[test]
+    fn test_bridge_from_genesis_extra_data() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let genesis = |extra_data: Bytes| Genesis { extra_data, ..Default::default() };
+        let committed = genesis(crate::bridge_extra_data(bridge));
+
+        // The extraData is ignored unless the bridge is read from it
+        let builder = RkbExecutorBuilder::testing().with_genesis(&committed).unwrap();
+        assert_eq!(builder.native_minter().authorized_bridge(), Address::ZERO);
+
+        let builder = RkbExecutorBuilder::testing()
+            .with_bridge_from_genesis()
+            .with_genesis(&committed)
+            .unwrap();
+        assert_eq!(builder.native_minter().authorized_bridge(), bridge);
+
+        let result = RkbExecutorBuilder::testing()
+            .with_bridge_from_genesis()
+            .with_genesis(&genesis(Bytes::from_static(b"not rkb")));
+        assert!(matches!(result, Err(RkbConfigError::InvalidExtraData(_))));
+    }
+
+    #[test]
+    fn test_validate_prune_modes() {
+        let builder = RkbExecutorBuilder::testing();
+        builder.validate_prune_modes(&PruneModes::default()).unwrap();
+
+        // Segments without NativeMinter state may be pruned
+        let modes = PruneModes {
+            account_history: Some(PruneMode::Distance(10_064)),
+            receipts: Some(PruneMode::Full),
+            ..Default::default()
+        };
+        builder.validate_prune_modes(&modes).unwrap();
+
+        let modes = PruneModes { storage_history: Some(PruneMode::Distance(10_064)), ..modes };
+        assert!(matches!(
+            builder.validate_prune_modes(&modes),
+            Err(RkbConfigError::StorageHistoryPruned(PruneMode::Distance(10_064)))
+        ));
+    }
+
+    #[test]
+    fn test_validate_chain_spec() {
+        let spec =
+            |genesis: Genesis| ChainSpecBuilder::default().chain(Chain::dev()).genesis(genesis);
+        let builder = RkbExecutorBuilder::testing();
+
+        let compatible = spec(Genesis::default()).cancun_activated().build();
+        builder.validate_chain_spec(&compatible).unwrap();
+
+        // An account without code, as seeded by `genesis_alloc`, is fine
+        let seeded = Genesis::default().extend_accounts([crate::storage::genesis_alloc(address!(
+            "0x1234567890abcdef1234567890abcdef12345678"
+        ))]);
+        builder.validate_chain_spec(&spec(seeded).cancun_activated().build()).unwrap();
+
+        let pre_cancun = spec(Genesis::default()).shanghai_activated().build();
+        assert!(matches!(
+            builder.validate_chain_spec(&pre_cancun),
+            Err(RkbConfigError::HardforkNotActive("Cancun"))
+        ));
+
+        // The NativeMinter is installed at the genesis spec, unless restricted to Cancun
+        assert!(builder.has_native_minter_at_genesis(&compatible));
+        assert!(builder.has_native_minter_at_genesis(&pre_cancun));
+        let cancun_only =
+            RkbExecutorBuilder::from_config(RkbConfig { cancun_only: true, ..Default::default() })
+                .unwrap();
+        assert!(cancun_only.has_native_minter_at_genesis(&compatible));
+        assert!(!cancun_only.has_native_minter_at_genesis(&pre_cancun));
+
+        let collision = Genesis::default().extend_accounts([(
+            NATIVE_MINTER_ADDRESS,
+            GenesisAccount::default().with_code(Some(Bytes::from_static(&[0x60, 0x00]))),
+        )]);
+        assert!(matches!(
+            builder.validate_chain_spec(&spec(collision).cancun_activated().build()),
+            Err(RkbConfigError::CodeAtNativeMinter(NATIVE_MINTER_ADDRESS))
+        ));
+
+        let from_genesis = RkbExecutorBuilder::testing().with_bridge_from_genesis();
+        assert!(matches!(
+            from_genesis.validate_chain_spec(&compatible),
+            Err(RkbConfigError::InvalidExtraData(_))
+        ));
+    }
+}
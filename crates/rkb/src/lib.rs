@@ -6,8 +6,13 @@
 //! ## Components
 //!
 //! - [`NativeMinterPrecompile`]: Precompile at `0x420` for minting/burning native TIA
+//! - [`NativeMinterConfig`]: NativeMinter configuration shared by the EVM factory and precompile
 //! - [`RkbEvmFactory`]: Custom EVM factory with NativeMinter
 //! - [`RkbExecutorBuilder`]: Executor builder for node integration
+//! - [`RkbConfig`]: NativeMinter configuration loadable from a TOML file
+//! - [`RkbBlockSummary`]: Amounts minted and burned in a block, derived from its state changes
+//! - [`rpc`]: `rkb` RPC namespace exposing the live NativeMinter configuration
+//! - [`prelude`]: The items most integrations need, for a single glob import
 //!
 //! ## Usage
 //!
@@ -24,12 +29,32 @@
 
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
-mod native_minter;
+mod bridge;
+mod config;
+mod error;
 mod evm;
 mod executor;
+mod native_minter;
+pub mod prelude;
+pub mod rpc;
+pub mod storage;
+mod summary;
 
-pub use native_minter::{
-    NativeMinterPrecompile, NATIVE_MINTER_ADDRESS, NATIVE_MINTER_GAS_COST,
-};
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
+pub use bridge::{bridge_extra_data, bridge_from_extra_data, verify_bridge_create2};
+pub use config::{RkbConfig, RkbConfigError};
+pub use error::NativeMinterError;
 pub use evm::RkbEvmFactory;
 pub use executor::RkbExecutorBuilder;
+pub use native_minter::{
+    BridgeSource, BurnMode, CallKind, EmergencyMint, Erc20Target, EventScheme, Features, GasModel,
+    MintApprover, MintBurnRecord, MintFee, MintTarget, NativeMinterConfig, NativeMinterPrecompile,
+    PausableOperation, RateLimit, Role, SimulatedEffect, ValueMode, Verbosity, BURN_GAS_COST,
+    BURN_SELECTOR, DEFAULT_LOG_TARGET, DEFAULT_MAX_BATCH_SIZE, DEFAULT_MAX_CALLDATA_LEN,
+    LOGIC_VERSION, MINT_BATCH_SELECTOR, MINT_GAS_COST, MINT_SELECTOR,
+    MINT_WITH_MESSAGE_FROM_SELECTOR, MINT_WITH_MESSAGE_SELECTOR, NATIVE_MINTER_ADDRESS,
+    NATIVE_MINTER_GAS_COST, VIEW_GAS_COST,
+};
+pub use summary::RkbBlockSummary;
@@ -6,10 +6,48 @@
 //!
 //! ## Security
 //!
-//! - Only the authorized bridge contract can call mint/burn functions
-//! - The authorized address is set at chain configuration time
-//! - Cannot be called via DELEGATECALL (must be direct call)
+//! - Only the authorized bridge contract can call mint/burn functions, unless additional
+//!   addresses are granted a [`Role`]
+//! - The authorized address is set at chain configuration time, either in the node config or
+//!   in the precompile's storage at genesis (see [`BridgeSource`])
+//! - Cannot be called via DELEGATECALL or CALLCODE (must be direct call), unless the DELEGATECALL
+//!   is made by an explicitly trusted proxy
 //! - Reverts in STATICCALL context
+//! - Rejects calls from the system address and system contracts, which only act in the system
+//!   calls the node makes around a block's transactions
+//! - Rejects reentrant calls
+//! - `approve` and `permit` are callable by anyone, but only set allowances of the caller or the
+//!   permit's signer
+//! - The bridge may delegate minting to other accounts with `mintAllowance`: a delegated minter
+//!   may only call `mint`, and only up to its remaining allowance
+//! - Emergency mints are only callable by the configured owner, and only execute a delay after
+//!   being scheduled (see [`EmergencyMint`])
+//! - Mints above a threshold may require a signature of an off-chain approver, passed to
+//!   `mintWithApproval` (see [`MintApprover`])
+//!
+//! ## Contract recipients
+//!
+//! Minting only adjusts the recipient's balance: no code of the recipient runs, so its `receive`
+//! or `fallback` function is not triggered, and neither is any other callback. Precompiles can't
+//! make calls through [`EvmInternals`](alloy_evm::EvmInternals), so a bridge that needs callback
+//! semantics must call the recipient itself after the mint returns, reverting the whole
+//! transaction if the callback fails.
+//!
+//! ## Burning from the transaction origin
+//!
+//! To stop the bridge from burning from accounts that merely interacted with it, burns by the
+//! bridge can be restricted to `from == tx.origin`, the account that initiated the transaction
+//! (see [`NativeMinterPrecompile::with_require_origin_burn`]). Alternatively, require a burn
+//! allowance (see [`NativeMinterPrecompile::with_burn_allowance_required`]): the burned account
+//! then has to approve the bridge itself, through `approve` or a signed `permit`.
+//!
+//! ## EIP-7702 delegated accounts
+//!
+//! An EOA delegating to code with EIP-7702 runs that code in its own context, so a call it makes
+//! to the precompile is a regular CALL whose caller is the EOA itself. A delegated bridge EOA is
+//! thus authorized like any other bridge, a delegated non-bridge account is rejected, and the
+//! DELEGATECALL guard is unaffected. An account delegating to the precompile's address doesn't
+//! run it: EIP-7702 treats delegation to a precompile as empty code.
 //!
 //! ## Interface
 //!
@@ -17,22 +55,144 @@
 //! interface INativeMinter {
 //!     function mint(address recipient, uint256 amount) external;
 //!     function burn(address from, uint256 amount) external;
+//!     function burn(address from, uint256 amount, uint256 nonce) external;
+//!     function mintBurnNet(address account, int256 delta) external;
+//!     function mintWithMessage(address recipient, uint256 amount, bytes32 messageId) external;
+//!     function mintWithMessageFrom(
+//!         address recipient,
+//!         uint256 amount,
+//!         bytes32 messageId,
+//!         uint32 origin
+//!     ) external;
+//!     function mintBatch(address[] calldata recipients, uint256[] calldata amounts) external;
+//!     function mintToSelf(uint256 amount) external;
+//!     function mintWithApproval(address recipient, uint256 amount, bytes calldata approverSig)
+//!         external;
+//!     function approvalNonce() external view returns (uint256);
+//!     function approve(address spender, uint256 value) external returns (bool);
+//!     function permit(
+//!         address owner,
+//!         address spender,
+//!         uint256 value,
+//!         uint256 deadline,
+//!         uint8 v,
+//!         bytes32 r,
+//!         bytes32 s
+//!     ) external;
+//!     function allowance(address owner, address spender) external view returns (uint256);
+//!     function nonces(address owner) external view returns (uint256);
+//!     function DOMAIN_SEPARATOR() external view returns (bytes32);
+//!     function wasProcessed(bytes32 messageId) external view returns (bool);
+//!     function totalMinted() external view returns (uint256);
+//!     function totalBurned() external view returns (uint256);
+//!     function totalSupply() external view returns (uint256);
+//!     function mintedTo(address recipient) external view returns (uint256);
+//!     function decimals() external view returns (uint8);
+//!     function features() external view returns (uint256);
+//!     function version() external view returns (string memory);
+//!     function gasCost(bytes4 selector) external view returns (uint256);
+//!     function bridgeCount() external view returns (uint256);
+//!     function bridgeAt(uint256 index) external view returns (address);
+//!     function isPaused() external view returns (bool);
+//!     function isMintPaused() external view returns (bool);
+//!     function isBurnPaused() external view returns (bool);
+//!     function pause() external;
+//!     function unpause() external;
+//!     function pauseMint() external;
+//!     function unpauseMint() external;
+//!     function pauseBurn() external;
+//!     function unpauseBurn() external;
+//!     function scheduleEmergencyMint(address recipient, uint256 amount) external;
+//!     function executeEmergencyMint(address recipient, uint256 amount) external;
+//!     function setGasCost(uint64 gasCost) external;
+//!     function proposeBridge(address newBridge) external;
+//!     function acceptBridge() external;
+//!     function mintAllowance(address minter, uint256 amount) external;
 //! }
 //! ```
 
+use crate::{
+    storage::{
+        self, APPROVAL_NONCE_SLOT, AUTHORIZED_BRIDGE_SLOT, BREAKER_BLOCK_SLOT, BREAKER_MINTED_SLOT,
+        GAS_COST_SLOT, PAUSED_SLOT, PAUSE_ALL, PAUSE_BURN, PAUSE_MINT, PENDING_BRIDGE_SLOT,
+        RATE_LIMIT_MINTED_SLOT, RATE_LIMIT_START_SLOT, RECIPIENTS_BLOCK_SLOT,
+        RECIPIENTS_IN_BLOCK_SLOT, REENTRANCY_GUARD_SLOT, TOTAL_BURNED_SLOT, TOTAL_MINTED_SLOT,
+    },
+    NativeMinterError, RkbConfigError,
+};
+use alloy_eips::{
+    eip2935::HISTORY_STORAGE_ADDRESS,
+    eip4788::{BEACON_ROOTS_ADDRESS, SYSTEM_ADDRESS},
+    eip7002::WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS,
+    eip7251::CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS,
+};
 use alloy_evm::precompiles::{DynPrecompile, PrecompileInput};
-use alloy_primitives::{address, Address, Bytes, U256};
-use alloy_sol_types::{sol, SolCall};
+use alloy_primitives::{address, keccak256, Address, Bytes, Log, Signature, B256, I256, U256};
+use alloy_sol_types::{eip712_domain, sol, Eip712Domain, SolCall, SolEvent, SolStruct, SolValue};
 use revm::precompile::{PrecompileError, PrecompileId, PrecompileOutput, PrecompileResult};
-use tracing::{debug, warn};
+use revm::{context_interface::Block, database::CacheDB, DatabaseRef};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    sync::Arc,
+};
+use tokio::sync::mpsc::Sender;
+use tracing::{debug, warn, warn_span, Level};
+
+/// Logs a rejected call at the [rejection log
+/// level](NativeMinterPrecompile::with_rejection_log_level) of the precompile.
+///
+/// `tracing` requires event levels to be known at compile time, so the event is expanded once per
+/// level.
+macro_rules! reject {
+    ($this:expr, $($arg:tt)+) => {
+        match $this.config.rejection_log_level {
+            Level::ERROR => tracing::error!($($arg)+),
+            Level::WARN => tracing::warn!($($arg)+),
+            Level::INFO => tracing::info!($($arg)+),
+            Level::DEBUG => tracing::debug!($($arg)+),
+            Level::TRACE => tracing::trace!($($arg)+),
+        }
+    };
+}
 
 /// Precompile address: 0x0000000000000000000000000000000000000420
 pub const NATIVE_MINTER_ADDRESS: Address = address!("0x0000000000000000000000000000000000000420");
 
-/// Gas cost for mint/burn operations.
-/// This is similar to other balance-modifying operations (warm account access + modification).
+/// Name of the [`PrecompileId`] the NativeMinter is registered under.
+pub(crate) const NATIVE_MINTER_ID: &str = "native_minter";
+
+/// Gas cost of calls other than mints and burns, the base of the default [`GasModel`].
 pub const NATIVE_MINTER_GAS_COST: u64 = 6000;
 
+/// Default gas cost of a mint.
+/// This is similar to other balance-modifying operations (warm account access + modification).
+pub const MINT_GAS_COST: u64 = 6000;
+
+/// Default gas cost of a burn.
+/// Higher than [`MINT_GAS_COST`], as a burn reads the balance before writing it.
+pub const BURN_GAS_COST: u64 = 6800;
+
+/// Default gas cost of a view function.
+/// Lower than [`MINT_GAS_COST`], as a view only reads storage.
+pub const VIEW_GAS_COST: u64 = 800;
+
+/// Version of the NativeMinter's execution logic, returned by `version()`.
+///
+/// The return value of a call is part of consensus, so this is fixed rather than the crate version
+/// and only bumped along with a change to how calls execute. The node's crate version is served by
+/// `rkb_config` instead.
+pub const LOGIC_VERSION: &str = "1";
+
+/// Default log target of the NativeMinter, see [`NativeMinterPrecompile::with_log_target`].
+pub const DEFAULT_LOG_TARGET: &str = "rkb::native_minter";
+
+/// Default maximum number of recipients in a single `mintBatch` call.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 256;
+
+/// Default maximum length of the calldata of a call, 64 KiB.
+pub const DEFAULT_MAX_CALLDATA_LEN: usize = 64 * 1024;
+
 // Define the Solidity interface using alloy-sol-types
 sol! {
     /// Mint native tokens to a recipient address.
@@ -43,217 +203,7387 @@ sol! {
     /// Only callable by the authorized bridge contract.
     /// The `from` address must have approved or be the caller.
     function burn(address from, uint256 amount);
+
+    /// Burn native tokens from an address, recording `nonce` as used for `from`.
+    /// Only callable by the authorized bridge contract.
+    /// Reverts if the nonce was already used, so a relayer may retry a withdrawal safely.
+    function burn(address from, uint256 amount, uint256 nonce);
+
+    /// Mint `delta` to `account` if positive, or burn its magnitude from `account` if negative,
+    /// netting a mint and a burn into a single balance update.
+    /// Only callable by the authorized bridge contract.
+    function mintBurnNet(address account, int256 delta);
+
+    /// Mint native tokens for a verified Hyperlane message.
+    /// Only callable by the authorized bridge contract.
+    /// Reverts if the message ID was already processed.
+    function mintWithMessage(address recipient, uint256 amount, bytes32 messageId);
+
+    /// Mint native tokens for a verified Hyperlane message from the `origin` domain, emitting a
+    /// `BridgeIn` event.
+    /// Only callable by the authorized bridge contract.
+    /// Reverts if the message ID was already processed.
+    function mintWithMessageFrom(
+        address recipient,
+        uint256 amount,
+        bytes32 messageId,
+        uint32 origin
+    );
+
+    /// Mint native tokens to multiple recipients.
+    /// Only callable by the authorized bridge contract.
+    /// Charges the mint gas cost per recipient.
+    function mintBatch(address[] recipients, uint256[] amounts);
+
+    /// Mint native tokens to the caller.
+    /// Only callable by the authorized bridge contract.
+    function mintToSelf(uint256 amount);
+
+    /// Mint native tokens, approved by `approverSig`: a 65-byte EIP-712 signature of the
+    /// [`MintApprover`] over a [`MintApproval`] of the current `approvalNonce()`.
+    /// Only callable by the authorized bridge contract.
+    /// The signature is only checked for amounts above the approval threshold.
+    function mintWithApproval(address recipient, uint256 amount, bytes approverSig);
+
+    /// Returns the nonce of the next mint approval signed by the approver.
+    function approvalNonce() returns (uint256);
+
+    /// Returns whether the given Hyperlane message ID was processed by `mintWithMessage`.
+    /// Callable by anyone, including via STATICCALL.
+    function wasProcessed(bytes32 messageId) returns (bool);
+
+    /// Returns the total amount minted over the chain's life.
+    function totalMinted() returns (uint256);
+
+    /// Returns the total amount burned over the chain's life.
+    function totalBurned() returns (uint256);
+
+    /// Returns the net amount minted through the NativeMinter: `totalMinted - totalBurned`.
+    function totalSupply() returns (uint256);
+
+    /// Returns the total amount credited to `recipient` by mints over the chain's life.
+    /// Only tracked if enabled in the node config, zero otherwise.
+    function mintedTo(address recipient) returns (uint256);
+
+    /// Returns the decimals of the native token, as configured in the node.
+    function decimals() returns (uint8);
+
+    /// Returns the bitmask of optional capabilities enabled in the node config, see [`Features`].
+    function features() returns (uint256);
+
+    /// Returns the version of the NativeMinter's execution logic, the same on every node of a
+    /// chain. See `LOGIC_VERSION`.
+    function version() returns (string);
+
+    /// Returns the gas charged per operation by the function with the given `selector`,
+    /// excluding any calldata-dependent charge of the [`GasModel`], so callers can size the gas
+    /// they forward.
+    function gasCost(bytes4 selector) returns (uint256);
+
+    /// Returns the number of authorized bridges: the authorized bridge and the additional
+    /// bridges of the node config.
+    function bridgeCount() returns (uint256);
+
+    /// Returns the authorized bridge at `index`, in ascending order of address.
+    /// Reverts if `index` is not below `bridgeCount()`.
+    function bridgeAt(uint256 index) returns (address);
+
+    /// Returns whether minting and burning are paused with `pause`.
+    function isPaused() returns (bool);
+
+    /// Returns whether minting is paused, with `pause` or `pauseMint`.
+    function isMintPaused() returns (bool);
+
+    /// Returns whether burning is paused, with `pause` or `pauseBurn`.
+    function isBurnPaused() returns (bool);
+
+    /// Allow `spender` to burn up to `value` from the caller.
+    /// Callable by anyone.
+    function approve(address spender, uint256 value) returns (bool);
+
+    /// Allow `spender` to burn up to `value` from `owner`, authorized by an EIP-712 signature of
+    /// `owner` over a [`Permit`].
+    /// Callable by anyone.
+    function permit(
+        address owner,
+        address spender,
+        uint256 value,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    );
+
+    /// Returns the amount `spender` may burn from `owner`.
+    function allowance(address owner, address spender) returns (uint256);
+
+    /// Returns the nonce of the next permit signed by `owner`.
+    function nonces(address owner) returns (uint256);
+
+    /// Returns the EIP-712 domain separator permits are signed under.
+    function DOMAIN_SEPARATOR() returns (bytes32);
+
+    /// Emitted for every mint with [`EventScheme::Native`].
+    event Mint(address indexed recipient, uint256 amount);
+
+    /// Emitted for every burn with [`EventScheme::Native`].
+    event Burn(address indexed from, uint256 amount);
+
+    /// Emitted for every mint (from the zero address) and burn (to the zero address) with
+    /// [`EventScheme::Erc20Transfer`].
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    /// Emitted once per `mintBatch` in place of the per-mint events, whatever the
+    /// [`EventScheme`], if batch events are aggregated. `totalAmount` is the sum of the amounts
+    /// and `recipientsHash` is `keccak256(abi.encode(recipients))`.
+    event BatchMint(uint256 count, uint256 totalAmount, bytes32 recipientsHash);
+
+    /// Emitted for every runtime configuration change, whatever the [`EventScheme`]. `key` is the
+    /// storage slot of the setting, see [`storage`], and the values are its slot contents before
+    /// and after the change.
+    event ConfigChanged(bytes32 indexed key, bytes32 oldValue, bytes32 newValue);
+
+    /// Emitted for every `mintWithMessageFrom`, whatever the [`EventScheme`], correlating the
+    /// Hyperlane message with the mint it was credited by. `amount` is the amount of the message,
+    /// before any mint fee.
+    event BridgeIn(
+        bytes32 indexed messageId,
+        address indexed recipient,
+        uint256 amount,
+        uint32 origin
+    );
+
+    /// The EIP-712 message signed by the approver to authorize a `mintWithApproval`.
+    struct MintApproval {
+        address recipient;
+        uint256 amount;
+        uint256 nonce;
+    }
+
+    /// The EIP-712 message signed to authorize a `permit`.
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    /// Pause minting and burning.
+    /// Only callable by the authorized bridge or a pauser.
+    function pause();
+
+    /// Resume minting and burning, including operations paused individually.
+    /// Only callable by the authorized bridge or a pauser.
+    function unpause();
+
+    /// Pause minting only, e.g. to stop inbound transfers while users can still exit.
+    /// Only callable by the authorized bridge or a pauser.
+    function pauseMint();
+
+    /// Resume minting paused with `pauseMint`. Minting stays paused while `pause` is in effect.
+    /// Only callable by the authorized bridge or a pauser.
+    function unpauseMint();
+
+    /// Pause burning only.
+    /// Only callable by the authorized bridge or a pauser.
+    function pauseBurn();
+
+    /// Resume burning paused with `pauseBurn`. Burning stays paused while `pause` is in effect.
+    /// Only callable by the authorized bridge or a pauser.
+    function unpauseBurn();
+
+    /// Schedule an emergency mint of `amount` to `recipient`, executable after the timelock.
+    /// Only callable by the emergency mint owner.
+    function scheduleEmergencyMint(address recipient, uint256 amount);
+
+    /// Execute a scheduled emergency mint whose timelock has elapsed.
+    /// Only callable by the emergency mint owner.
+    function executeEmergencyMint(address recipient, uint256 amount);
+
+    /// Set the gas charged per state-modifying call, replacing the configured costs. Zero restores
+    /// them. Views keep their configured cost.
+    /// Only callable by the governor.
+    function setGasCost(uint64 gasCost);
+
+    /// Propose `newBridge` as the authorized bridge, replacing any pending proposal. Zero cancels
+    /// the pending proposal. Requires the bridge to be sourced from state.
+    /// Only callable by the governor.
+    function proposeBridge(address newBridge);
+
+    /// Accept a pending bridge proposal, making the caller the authorized bridge.
+    /// Only callable by the proposed bridge.
+    function acceptBridge();
+
+    /// Allow `minter` to call `mint` for up to `amount` in total, replacing its remaining
+    /// allowance. Zero revokes the delegation.
+    /// Only callable by the authorized bridge.
+    function mintAllowance(address minter, uint256 amount);
 }
 
-/// NativeMinter precompile for minting/burning native tokens during bridge operations.
-///
-/// # Usage
-///
-/// ```ignore
-/// use reth_rkb::NativeMinterPrecompile;
-/// use alloy_primitives::address;
+/// Selector of `mint(address,uint256)`.
+pub const MINT_SELECTOR: [u8; 4] = <mintCall as SolCall>::SELECTOR;
+
+/// Selector of `burn(address,uint256)`.
+pub const BURN_SELECTOR: [u8; 4] = <burn_0Call as SolCall>::SELECTOR;
+
+/// Selector of `mintWithMessage(address,uint256,bytes32)`.
+pub const MINT_WITH_MESSAGE_SELECTOR: [u8; 4] = <mintWithMessageCall as SolCall>::SELECTOR;
+
+/// Selector of `mintWithMessageFrom(address,uint256,bytes32,uint32)`.
+pub const MINT_WITH_MESSAGE_FROM_SELECTOR: [u8; 4] = <mintWithMessageFromCall as SolCall>::SELECTOR;
+
+/// Selector of `mintBatch(address[],uint256[])`.
+pub const MINT_BATCH_SELECTOR: [u8; 4] = <mintBatchCall as SolCall>::SELECTOR;
+
+/// Where the NativeMinter reads the authorized bridge address from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BridgeSource {
+    /// Use the address the precompile was constructed with.
+    #[default]
+    Config,
+    /// Read the address from [`AUTHORIZED_BRIDGE_SLOT`] in the precompile's own storage.
+    ///
+    /// This makes the bridge part of consensus state, verifiable from the state root. The slot is
+    /// expected to be seeded at genesis, see [`storage::genesis_alloc`].
+    ///
+    /// If the slot is unset (reads as [`Address::ZERO`]) the configured bridge is used instead, so
+    /// an unseeded state never authorizes the zero address.
+    State,
+}
+
+/// How the NativeMinter handles a burn exceeding the balance of the `from` account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BurnMode {
+    /// Revert the burn. Successful burns return empty output.
+    #[default]
+    Revert,
+    /// Burn `min(amount, balance)`, so a withdrawal is never stuck because the balance changed
+    /// while it was in flight. Successful burns return the burned amount as a `uint256`.
+    Clamp,
+}
+
+/// How the NativeMinter handles a direct call with native value attached.
 ///
-/// // Create precompile with authorized bridge address
-/// let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
-/// let precompile = NativeMinterPrecompile::new(bridge);
+/// The EVM transfers the value to the precompile's account before executing it, where nothing
+/// could ever spend it again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValueMode {
+    /// Reject the call, rolling back the transfer.
+    #[default]
+    Reject,
+    /// Execute the call and credit the value back to the caller.
+    Refund,
+}
+
+/// How much of the node's configuration the NativeMinter's logs reveal when rejecting a call.
 ///
-/// // Convert to DynPrecompile for use with PrecompilesMap
-/// let dyn_precompile = precompile.into_dyn_precompile();
-/// ```
-#[derive(Debug, Clone)]
-pub struct NativeMinterPrecompile {
-    /// The authorized bridge contract address that can call mint/burn.
-    authorized_bridge: Address,
+/// Revert messages are fixed strings, see [`NativeMinterError`], and never include configuration
+/// whatever the verbosity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Redact configured addresses, such as the authorized bridge or the governor, from logs.
+    Terse,
+    /// Log the configured addresses a rejected call was checked against, for debugging.
+    #[default]
+    Detailed,
 }
 
-impl NativeMinterPrecompile {
-    /// Creates a new NativeMinter precompile with the given authorized bridge address.
-    pub const fn new(authorized_bridge: Address) -> Self {
-        Self { authorized_bridge }
-    }
+/// How the NativeMinter charges gas for a call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasModel {
+    /// Charge a fixed amount per operation, independently of the calldata.
+    Flat(u64),
+    /// Charge `base` per operation plus `per_byte` for every byte of calldata, so cost scales
+    /// with the size of the call.
+    PerByte {
+        /// Gas charged per operation.
+        base: u64,
+        /// Gas charged per byte of calldata, including the selector.
+        per_byte: u64,
+    },
+}
 
-    /// Returns the authorized bridge address.
-    pub const fn authorized_bridge(&self) -> Address {
-        self.authorized_bridge
+impl Default for GasModel {
+    fn default() -> Self {
+        Self::Flat(NATIVE_MINTER_GAS_COST)
     }
+}
 
-    /// Converts this precompile into a [`DynPrecompile`] for use with [`PrecompilesMap`].
-    pub fn into_dyn_precompile(self) -> DynPrecompile {
-        DynPrecompile::new_stateful(
-            PrecompileId::custom("native_minter"),
-            move |input: PrecompileInput<'_>| self.call(input),
-        )
+impl GasModel {
+    /// Returns the gas charged for a call with `data_len` bytes of calldata performing
+    /// `operations` operations.
+    pub const fn cost(&self, data_len: usize, operations: u64) -> u64 {
+        self.cost_with_base(self.base(), data_len, operations)
     }
 
-    /// Execute the precompile call.
-    fn call(&self, mut input: PrecompileInput<'_>) -> PrecompileResult {
-        // Check gas
-        if input.gas < NATIVE_MINTER_GAS_COST {
-            return Err(PrecompileError::OutOfGas);
-        }
-
-        // Security: Must be a direct call, not DELEGATECALL
-        if !input.is_direct_call() {
-            warn!(
-                target: "rkb::native_minter",
-                caller = %input.caller,
-                target = %input.target_address,
-                bytecode = %input.bytecode_address,
-                "NativeMinter: DELEGATECALL not allowed"
-            );
-            return Err(PrecompileError::other_static("NativeMinter: DELEGATECALL not allowed"));
+    /// Returns the gas charged for a call with `data_len` bytes of calldata performing
+    /// `operations` operations costing `base` each, in place of the model's own base.
+    pub const fn cost_with_base(&self, base: u64, data_len: usize, operations: u64) -> u64 {
+        let per_call = base.saturating_mul(operations);
+        match *self {
+            Self::Flat(_) => per_call,
+            Self::PerByte { per_byte, .. } => {
+                per_call.saturating_add(per_byte.saturating_mul(data_len as u64))
+            }
         }
+    }
 
-        // Security: Cannot call in STATICCALL context
-        if input.is_static_call() {
-            warn!(
-                target: "rkb::native_minter",
-                caller = %input.caller,
-                "NativeMinter: STATICCALL not allowed"
-            );
-            return Err(PrecompileError::other_static("NativeMinter: STATICCALL not allowed"));
+    /// Returns the gas charged per operation, excluding any calldata-dependent component.
+    pub const fn base(&self) -> u64 {
+        match *self {
+            Self::Flat(gas) => gas,
+            Self::PerByte { base, .. } => base,
         }
+    }
+}
 
-        // Security: Only authorized bridge can call
-        if input.caller != self.authorized_bridge {
-            warn!(
-                target: "rkb::native_minter",
-                caller = %input.caller,
-                authorized = %self.authorized_bridge,
-                "NativeMinter: unauthorized caller"
-            );
-            return Err(PrecompileError::other_static("NativeMinter: unauthorized caller"));
-        }
+/// Caps the amount minted per window of block timestamps.
+///
+/// A window starts at the timestamp of the first mint after the previous window ended and lasts
+/// `window_secs` seconds. The window's start and the amount minted in it are kept in the
+/// precompile's storage, so the limit is part of consensus state.
+///
+/// Block timestamps are assumed to be monotonic, which consensus guarantees on a PoA chain: a
+/// block timestamp before the window's start keeps the window open rather than resetting it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum amount minted per window, across all recipients.
+    pub max_amount: U256,
+    /// Length of a window, in seconds.
+    pub window_secs: u64,
+}
 
-        // Need at least 4 bytes for function selector
-        if input.data.len() < 4 {
-            return Err(PrecompileError::other_static("NativeMinter: invalid calldata length"));
-        }
+impl RateLimit {
+    /// Creates a rate limit allowing `max_amount` to be minted every `window_secs` seconds.
+    pub const fn new(max_amount: U256, window_secs: u64) -> Self {
+        Self { max_amount, window_secs }
+    }
+}
 
-        // Parse function selector
-        let selector: [u8; 4] = input.data[..4].try_into().unwrap();
+/// Enables timelocked emergency mints by an owner, for recovery scenarios.
+///
+/// The owner first schedules a mint with `scheduleEmergencyMint`, recording the block timestamp
+/// in the precompile's storage, and can execute it with `executeEmergencyMint` once `delay_secs`
+/// seconds have passed. A compromised owner key therefore can't mint instantly, leaving time to
+/// react. Executed mints are subject to the same checks as any other mint.
+///
+/// The owner is the only account allowed to call either function, the authorized bridge and role
+/// holders are not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmergencyMint {
+    /// Account allowed to schedule and execute emergency mints.
+    pub owner: Address,
+    /// Time between scheduling and executing an emergency mint, in seconds.
+    pub delay_secs: u64,
+}
 
-        match selector {
-            // mint(address,uint256) selector: 0x40c10f19
-            <mintCall as SolCall>::SELECTOR => {
-                let decoded = mintCall::abi_decode(&input.data[4..])
-                    .map_err(|_| PrecompileError::other_static("NativeMinter: invalid mint args"))?;
+impl EmergencyMint {
+    /// Creates an emergency mint configuration for `owner` with a timelock of `delay_secs`.
+    pub const fn new(owner: Address, delay_secs: u64) -> Self {
+        Self { owner, delay_secs }
+    }
+}
 
-                self.execute_mint(&mut input, decoded.recipient, decoded.amount)
-            }
-            // burn(address,uint256) selector: 0x9dc29fac
-            <burnCall as SolCall>::SELECTOR => {
-                let decoded = burnCall::abi_decode(&input.data[4..])
-                    .map_err(|_| PrecompileError::other_static("NativeMinter: invalid burn args"))?;
+/// Second factor for high-value mints: an off-chain approver whose signature is required for
+/// mints above a threshold.
+///
+/// Mints of more than `threshold` revert with "approval required" unless made through
+/// `mintWithApproval` with an EIP-712 signature of the approver over a [`MintApproval`] of the
+/// recipient, amount and current `approvalNonce()`. Each approval is valid once, as the nonce is
+/// incremented when it is used. Smaller mints, and emergency mints, which have their own timelock,
+/// are not gated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintApprover {
+    /// Account whose signature approves high-value mints.
+    pub approver: Address,
+    /// Largest amount minted by a single mint without an approval.
+    pub threshold: U256,
+}
 
-                self.execute_burn(&mut input, decoded.from, decoded.amount)
-            }
-            _ => {
-                warn!(
-                    target: "rkb::native_minter",
-                    selector = ?selector,
-                    "NativeMinter: unknown function selector"
-                );
-                Err(PrecompileError::other_static("NativeMinter: unknown function"))
-            }
-        }
+impl MintApprover {
+    /// Creates a mint approver configuration requiring `approver`'s signature above `threshold`.
+    pub const fn new(approver: Address, threshold: U256) -> Self {
+        Self { approver, threshold }
     }
+}
 
-    /// Execute the mint operation - credit native tokens to recipient.
-    fn execute_mint(
-        &self,
-        input: &mut PrecompileInput<'_>,
+/// How a contract calls into the NativeMinter, see [`NativeMinterPrecompile::simulate`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CallKind {
+    /// A regular CALL.
+    #[default]
+    Call,
+    /// A STATICCALL.
+    StaticCall,
+    /// A DELEGATECALL.
+    DelegateCall,
+    /// A CALLCODE.
+    CallCode,
+}
+
+/// The effect a call into the NativeMinter has, as reported by
+/// [`NativeMinterPrecompile::simulate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedEffect {
+    /// Mint `amount` to `recipient`.
+    Mint {
+        /// Account credited.
         recipient: Address,
+        /// Amount minted.
         amount: U256,
-    ) -> PrecompileResult {
-        debug!(
-            target: "rkb::native_minter",
-            %recipient,
-            %amount,
-            "Minting native tokens"
-        );
+    },
+    /// Mint `amount` to `recipient`, recording `message_id` as processed.
+    MintWithMessage {
+        /// Account credited.
+        recipient: Address,
+        /// Amount minted.
+        amount: U256,
+        /// Hyperlane message ID recorded as processed.
+        message_id: B256,
+        /// Hyperlane domain the message originates from, if passed to `mintWithMessageFrom`.
+        origin: Option<u32>,
+    },
+    /// Mint `amount` to `recipient`, approved by `signature` if above the approval threshold.
+    ///
+    /// The signature is not verified by [`NativeMinterPrecompile::simulate`], as it covers the
+    /// approval nonce.
+    MintWithApproval {
+        /// Account credited.
+        recipient: Address,
+        /// Amount minted.
+        amount: U256,
+        /// Signature of the approver.
+        signature: Bytes,
+    },
+    /// Mint `amounts[i]` to `recipients[i]` for every recipient.
+    MintBatch {
+        /// Accounts credited.
+        recipients: Vec<Address>,
+        /// Amounts minted, by recipient.
+        amounts: Vec<U256>,
+    },
+    /// Burn `amount` from `from`.
+    Burn {
+        /// Account debited.
+        from: Address,
+        /// Amount burned, before any clamping to the balance.
+        amount: U256,
+    },
+    /// Burn `amount` from `from`, recording `nonce` as used for `from`.
+    BurnWithNonce {
+        /// Account debited.
+        from: Address,
+        /// Amount burned, before any clamping to the balance.
+        amount: U256,
+        /// Nonce recorded as used.
+        nonce: U256,
+    },
+    /// Mint to or burn from an account, depending on the sign of `delta`.
+    MintBurnNet {
+        /// Account credited or debited.
+        account: Address,
+        /// Amount minted if positive, or burned if negative.
+        delta: I256,
+    },
+    /// Set the amount `spender` may burn from `owner`.
+    Approve {
+        /// Account burned from.
+        owner: Address,
+        /// Account allowed to burn.
+        spender: Address,
+        /// Allowed amount.
+        value: U256,
+    },
+    /// Set the amount `spender` may burn from `owner`, if `signature` is a valid permit.
+    ///
+    /// The signature is not verified by [`NativeMinterPrecompile::simulate`], as it covers the
+    /// owner's nonce.
+    Permit {
+        /// Account burned from.
+        owner: Address,
+        /// Account allowed to burn.
+        spender: Address,
+        /// Allowed amount.
+        value: U256,
+        /// Timestamp after which the permit is expired.
+        deadline: U256,
+        /// Signature of `owner`.
+        signature: Signature,
+    },
+    /// Pause or resume both minting and burning.
+    SetPaused(bool),
+    /// Pause or resume a single operation.
+    SetOperationPaused {
+        /// Operation to pause or resume.
+        operation: PausableOperation,
+        /// Whether the operation is paused.
+        paused: bool,
+    },
+    /// Set the gas charged per call, or restore the configured costs if zero.
+    SetGasCost(u64),
+    /// Propose a new authorized bridge, or cancel the pending proposal if zero.
+    ProposeBridge(Address),
+    /// Make the caller the authorized bridge, if it is the proposed bridge.
+    AcceptBridge,
+    /// Set the amount `minter` may mint as a delegated minter.
+    SetMintAllowance {
+        /// Delegated minter.
+        minter: Address,
+        /// Amount the minter may mint.
+        amount: U256,
+    },
+    /// Schedule an emergency mint of `amount` to `recipient`.
+    ScheduleEmergencyMint {
+        /// Account to credit.
+        recipient: Address,
+        /// Amount to mint.
+        amount: U256,
+    },
+    /// Mint `amount` to `recipient`, if scheduled and the timelock has elapsed.
+    ExecuteEmergencyMint {
+        /// Account credited.
+        recipient: Address,
+        /// Amount minted.
+        amount: U256,
+    },
+    /// Call a view function, without modifying state.
+    View,
+}
 
-        // Use EvmInternals to increment the recipient's balance
-        input
-            .internals_mut()
-            .balance_incr(recipient, amount)
-            .map_err(|e| PrecompileError::other(format!("NativeMinter: mint failed: {e}")))?;
+/// A fee taken from every mint and credited to a fee recipient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintFee {
+    /// Fee in basis points of the minted amount, at most [`MintFee::MAX_BPS`].
+    pub bps: u16,
+    /// Account credited with the fee.
+    pub recipient: Address,
+}
 
-        // Emit a log for indexing (optional but useful)
-        // We could add a Mint event here, but precompiles emitting logs is tricky
-        // The HypNativeGas contract will emit its own events
+impl MintFee {
+    /// Basis points in a whole, the maximum fee.
+    pub const MAX_BPS: u16 = 10_000;
 
-        Ok(PrecompileOutput::new(NATIVE_MINTER_GAS_COST, Bytes::new()))
+    /// Creates a fee of `bps` basis points credited to `recipient`.
+    pub const fn new(bps: u16, recipient: Address) -> Self {
+        Self { bps, recipient }
     }
 
-    /// Execute the burn operation - debit native tokens from an address.
-    fn execute_burn(
-        &self,
-        input: &mut PrecompileInput<'_>,
-        from: Address,
-        amount: U256,
-    ) -> PrecompileResult {
-        debug!(
-            target: "rkb::native_minter",
-            %from,
-            %amount,
-            "Burning native tokens"
-        );
+    /// Returns the fee taken from a mint of `amount`, rounded down.
+    ///
+    /// The fee never exceeds `amount`: a fee above [`MintFee::MAX_BPS`], which
+    /// [`NativeMinterConfig::validate`] rejects, is clamped to 100%.
+    pub fn of(&self, amount: U256) -> U256 {
+        let bps = self.bps.min(Self::MAX_BPS);
+        let (bps, max) = (U256::from(bps), U256::from(Self::MAX_BPS));
+        // Split the multiplication so it can't overflow
+        amount / max * bps + amount % max * bps / max
+    }
+}
 
-        // Load the account to check balance
-        let account = input
-            .internals_mut()
-            .load_account(from)
-            .map_err(|e| PrecompileError::other(format!("NativeMinter: load account failed: {e}")))?;
+/// Which events the NativeMinter emits for mints and burns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventScheme {
+    /// Emit `Mint(address indexed recipient, uint256 amount)` and
+    /// `Burn(address indexed from, uint256 amount)`.
+    Native,
+    /// Emit ERC-20 `Transfer(address indexed from, address indexed to, uint256 value)`, from the
+    /// zero address for mints and to the zero address for burns, so existing token indexers can
+    /// track the native supply.
+    Erc20Transfer,
+    /// Emit both the native and the ERC-20 events.
+    Both,
+}
 
-        let current_balance = account.data.info.balance;
+impl EventScheme {
+    /// Returns whether the native `Mint` and `Burn` events are emitted.
+    pub const fn emits_native(&self) -> bool {
+        matches!(self, Self::Native | Self::Both)
+    }
 
-        // Check sufficient balance
-        if current_balance < amount {
-            warn!(
-                target: "rkb::native_minter",
-                %from,
-                %amount,
-                %current_balance,
-                "NativeMinter: insufficient balance for burn"
-            );
-            return Err(PrecompileError::other_static("NativeMinter: insufficient balance"));
-        }
+    /// Returns whether ERC-20 `Transfer` events are emitted.
+    pub const fn emits_erc20_transfer(&self) -> bool {
+        matches!(self, Self::Erc20Transfer | Self::Both)
+    }
+}
 
-        // Calculate new balance and set it
-        let new_balance = current_balance - amount;
-        input
-            .internals_mut()
-            .set_balance(from, new_balance)
-            .map_err(|e| PrecompileError::other(format!("NativeMinter: burn failed: {e}")))?;
+/// What the NativeMinter credits on mints and debits on burns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MintTarget {
+    /// The native balance of the account.
+    #[default]
+    Native,
+    /// The account's balance in a wrapped-TIA ERC-20 token.
+    Erc20(Erc20Target),
+}
 
-        Ok(PrecompileOutput::new(NATIVE_MINTER_GAS_COST, Bytes::new()))
-    }
+/// A wrapped-TIA ERC-20 token credited instead of native balance, see [`MintTarget::Erc20`].
+///
+/// Precompiles can't make calls through [`EvmInternals`](alloy_evm::EvmInternals), so the token's
+/// `mint` function is not called. Instead, its storage is modified directly: the balance in its
+/// `balanceOf` mapping and, if configured, its total supply. A `Transfer` event is emitted from
+/// the token's address as the token would. The storage layout must therefore match the deployed
+/// token exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Erc20Target {
+    /// Address of the token contract.
+    pub token: Address,
+    /// Base slot of the token's `balanceOf` mapping.
+    pub balances_slot: U256,
+    /// Slot of the token's total supply, if it is tracked.
+    pub total_supply_slot: Option<U256>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Erc20Target {
+    /// Creates a target crediting the `balanceOf` mapping at `balances_slot` of `token`.
+    pub const fn new(token: Address, balances_slot: U256) -> Self {
+        Self { token, balances_slot, total_supply_slot: None }
+    }
 
-    #[test]
-    fn test_mint_selector() {
-        // mint(address,uint256) = keccak256("mint(address,uint256)")[0:4]
-        let expected: [u8; 4] = [0x40, 0xc1, 0x0f, 0x19];
-        assert_eq!(<mintCall as SolCall>::SELECTOR, expected);
+    /// Creates a target for a token with the storage layout of OpenZeppelin's `ERC20`:
+    /// `_balances` at slot `0` and `_totalSupply` at slot `2`.
+    pub const fn openzeppelin(token: Address) -> Self {
+        Self::new(token, U256::ZERO).with_total_supply_slot(U256::from_limbs([2, 0, 0, 0]))
     }
 
-    #[test]
-    fn test_burn_selector() {
-        // burn(address,uint256) = keccak256("burn(address,uint256)")[0:4]
-        let expected: [u8; 4] = [0x9d, 0xc2, 0x9f, 0xac];
-        assert_eq!(<burnCall as SolCall>::SELECTOR, expected);
+    /// Sets the slot of the token's total supply, updated on every mint and burn.
+    pub const fn with_total_supply_slot(mut self, total_supply_slot: U256) -> Self {
+        self.total_supply_slot = Some(total_supply_slot);
+        self
     }
 
-    #[test]
-    fn test_precompile_address() {
-        assert_eq!(
-            NATIVE_MINTER_ADDRESS,
-            address!("0x0000000000000000000000000000000000000420")
-        );
+    /// Returns the slot holding the balance of `account`.
+    fn balance_slot(&self, account: Address) -> U256 {
+        storage::mapping_slot(account.into_word(), self.balances_slot)
+    }
+}
+
+/// An operation that can be paused individually, see `pauseMint` and `pauseBurn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PausableOperation {
+    /// Minting, including batch, message and emergency mints.
+    Mint,
+    /// Burning.
+    Burn,
+}
+
+impl PausableOperation {
+    /// Returns the flag pausing this operation in [`PAUSED_SLOT`].
+    pub const fn pause_flag(self) -> U256 {
+        match self {
+            Self::Mint => PAUSE_MINT,
+            Self::Burn => PAUSE_BURN,
+        }
+    }
+}
+
+/// A role granting access to a subset of the NativeMinter's functions.
+///
+/// The authorized bridge implicitly holds every role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// May call `mint`, `mintWithMessage`, `mintWithMessageFrom`, `mintBatch`, `mintToSelf` and
+    /// `mintWithApproval`.
+    Minter,
+    /// May call both overloads of `burn`.
+    Burner,
+    /// May call `pause`, `unpause`, `pauseMint`, `unpauseMint`, `pauseBurn` and `unpauseBurn`.
+    Pauser,
+}
+
+impl Role {
+    /// Returns the role required to call the function with the given selector, if any.
+    const fn for_selector(selector: [u8; 4]) -> Option<Self> {
+        match selector {
+            <mintCall as SolCall>::SELECTOR
+            | <mintWithMessageCall as SolCall>::SELECTOR
+            | <mintWithMessageFromCall as SolCall>::SELECTOR
+            | <mintBatchCall as SolCall>::SELECTOR
+            | <mintToSelfCall as SolCall>::SELECTOR
+            | <mintWithApprovalCall as SolCall>::SELECTOR => Some(Self::Minter),
+            <burn_0Call as SolCall>::SELECTOR | <burn_1Call as SolCall>::SELECTOR => {
+                Some(Self::Burner)
+            }
+            <pauseCall as SolCall>::SELECTOR
+            | <unpauseCall as SolCall>::SELECTOR
+            | <pauseMintCall as SolCall>::SELECTOR
+            | <unpauseMintCall as SolCall>::SELECTOR
+            | <pauseBurnCall as SolCall>::SELECTOR
+            | <unpauseBurnCall as SolCall>::SELECTOR => Some(Self::Pauser),
+            _ => None,
+        }
+    }
+}
+
+/// Bitmask of the optional capabilities enabled in a deployment, returned by the `features()`
+/// view so tooling can discover them without trial calls.
+///
+/// Bit assignments are stable: new capabilities only ever take unused bits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Features(u64);
+
+impl Features {
+    /// Mints are capped per window of block timestamps, see [`RateLimit`].
+    pub const RATE_LIMIT: Self = Self(1 << 0);
+    /// Accounts other than the bridge hold the [`Role::Pauser`] role.
+    pub const PAUSABLE: Self = Self(1 << 1);
+    /// A fee is taken from every mint, see [`MintFee`].
+    pub const MINT_FEE: Self = Self(1 << 2);
+    /// The net minted supply is capped.
+    pub const SUPPLY_CAP: Self = Self(1 << 3);
+    /// Single mints and burns are capped.
+    pub const CALL_CAP: Self = Self(1 << 4);
+    /// Burning from another account consumes an allowance.
+    pub const BURN_ALLOWANCE: Self = Self(1 << 5);
+    /// Burns exceeding the balance are clamped, see [`BurnMode::Clamp`].
+    pub const BURN_CLAMP: Self = Self(1 << 6);
+    /// Identical mints within a transaction are rejected.
+    pub const MINT_DEDUP: Self = Self(1 << 7);
+    /// Mints and burns emit events, see [`EventScheme`].
+    pub const EVENTS: Self = Self(1 << 8);
+    /// Emergency mints are enabled, see [`EmergencyMint`].
+    pub const EMERGENCY_MINT: Self = Self(1 << 9);
+    /// The amount minted to each recipient is tracked.
+    pub const MINTED_TO: Self = Self(1 << 10);
+    /// Mints credit an ERC-20 token, see [`MintTarget::Erc20`].
+    pub const ERC20_TARGET: Self = Self(1 << 11);
+    /// A governor may set the gas cost at runtime.
+    pub const GOVERNOR: Self = Self(1 << 12);
+    /// Mints and burns return `true`.
+    pub const RETURN_BOOL: Self = Self(1 << 13);
+    /// The authorized bridge is read from storage, see [`BridgeSource::State`].
+    pub const BRIDGE_FROM_STATE: Self = Self(1 << 14);
+    /// Minting and burning are only enabled from an activation block.
+    pub const ACTIVATION_BLOCK: Self = Self(1 << 15);
+    /// Trusted proxies may DELEGATECALL the precompile.
+    pub const DELEGATECALL_PROXIES: Self = Self(1 << 16);
+    /// Mints and burns don't change any balance.
+    pub const OBSERVE_ONLY: Self = Self(1 << 17);
+    /// Minting requires a minimum block base fee.
+    pub const MIN_BASE_FEE: Self = Self(1 << 18);
+    /// Mints are capped to a number of distinct recipients per block.
+    pub const RECIPIENT_CAP: Self = Self(1 << 19);
+    /// Mints pushing a block's volume over a threshold are rejected.
+    pub const CIRCUIT_BREAKER: Self = Self(1 << 20);
+    /// Mints to the same recipient are spaced by a number of blocks.
+    pub const MINT_COOLDOWN: Self = Self(1 << 21);
+    /// Mints above a threshold require an off-chain approval, see [`MintApprover`].
+    pub const MINT_APPROVAL: Self = Self(1 << 22);
+    /// Mints overflowing the recipient's balance credit up to `U256::MAX` instead of reverting.
+    pub const SATURATING_MINT: Self = Self(1 << 23);
+    /// Burns by the bridge are restricted to the transaction origin.
+    pub const ORIGIN_BURN: Self = Self(1 << 24);
+
+    /// Returns the raw bitmask.
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Returns whether all capabilities of `other` are enabled.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the capabilities enabled in either set.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// A successful mint or burn, sent to the mint channel for off-chain reconciliation, see
+/// [`NativeMinterPrecompile::with_mint_channel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MintBurnRecord {
+    /// `amount` was minted to `recipient`, including any mint fee.
+    Mint {
+        /// Recipient of the mint.
+        recipient: Address,
+        /// Amount minted.
+        amount: U256,
+    },
+    /// `amount` was burned from `from`.
+    Burn {
+        /// Account burned from.
+        from: Address,
+        /// Amount burned.
+        amount: U256,
+    },
+}
+
+/// Configuration of a [`NativeMinterPrecompile`].
+///
+/// This is the single value shared between the EVM factory and the precompiles it installs: the
+/// factory holds it and builds each precompile with [`NativeMinterPrecompile::from_config`], so
+/// what is configured is exactly what is enforced at call time. It is built through the
+/// precompile's builder methods, see [`NativeMinterPrecompile::into_config`].
+#[derive(Debug, Clone)]
+pub struct NativeMinterConfig {
+    /// The authorized bridge contract address that can call mint/burn.
+    authorized_bridge: Address,
+    /// Where the authorized bridge is read from at call time.
+    bridge_source: BridgeSource,
+    /// How burns exceeding the available balance are handled.
+    burn_mode: BurnMode,
+    /// How calls with native value attached are handled.
+    value_mode: ValueMode,
+    /// Addresses granted the [`Role::Minter`] role.
+    minters: BTreeSet<Address>,
+    /// Addresses granted the [`Role::Burner`] role.
+    burners: BTreeSet<Address>,
+    /// Addresses granted the [`Role::Pauser`] role.
+    pausers: BTreeSet<Address>,
+    /// Bridges authorized in addition to the authorized bridge, holding every [`Role`].
+    additional_bridges: BTreeSet<Address>,
+    /// Maximum number of recipients in a single `mintBatch` call.
+    max_batch_size: usize,
+    /// Maximum length of the calldata of a call, checked before decoding it.
+    max_calldata_len: usize,
+    /// How gas is charged for a call.
+    gas_model: GasModel,
+    /// Gas charged per mint, in place of the gas model's base.
+    mint_gas_cost: u64,
+    /// Gas charged per burn, in place of the gas model's base.
+    burn_gas_cost: u64,
+    /// Gas charged per view call, in place of the gas model's base.
+    view_gas_cost: u64,
+    /// Gas charged per 32-byte word of calldata for decoding it, before any operation.
+    decode_gas_per_word: u64,
+    /// Salt mixed into every storage slot, see [`storage::namespaced_slot`].
+    storage_salt: B256,
+    /// Cap on the amount minted per window of block timestamps, if any.
+    rate_limit: Option<RateLimit>,
+    /// Maximum amount minted or burned by a single mint or burn.
+    max_amount_per_call: U256,
+    /// Whether burning from another account consumes an allowance.
+    burn_allowance_required: bool,
+    /// Whether burns by an authorized bridge must burn from the transaction origin.
+    require_origin_burn: bool,
+    /// Whether burning from a nonexistent account reverts with its own error.
+    reject_missing_account_burn: bool,
+    /// Whether mints to a nonexistent account revert.
+    require_existing_recipient: bool,
+    /// Cap on the number of distinct recipients minted to per block, if any.
+    max_recipients_per_block: Option<u32>,
+    /// Number of blocks that must pass between two mints to the same recipient, if any.
+    mint_cooldown_blocks: Option<u64>,
+    /// Whether `approve` and allowance-consuming burns return the resulting allowance.
+    allowance_output: bool,
+    /// Whether burns return the burned account's new balance and the total burned.
+    burn_summary_output: bool,
+    /// Whether mints overflowing the recipient's balance credit up to `U256::MAX`.
+    saturating_mint: bool,
+    /// Whether balance changes are skipped, for diagnostics.
+    observe_only: bool,
+    /// Whether the NativeMinter is only installed in EVMs for Cancun or later.
+    cancun_only: bool,
+    /// Channel every successful mint and burn is sent to, if any.
+    mint_channel: Option<Sender<MintBurnRecord>>,
+    /// Whether identical mints within a transaction are rejected.
+    mint_dedup: bool,
+    /// Cap on the net amount minted through the precompile, if any.
+    supply_cap: Option<U256>,
+    /// Amount minted within a block that trips the circuit breaker, if any.
+    breaker_threshold: Option<U256>,
+    /// Fee taken from every mint, if any.
+    mint_fee: Option<MintFee>,
+    /// Events emitted for mints and burns, if any.
+    event_scheme: Option<EventScheme>,
+    /// Whether `mintBatch` emits a single `BatchMint` event instead of an event per mint.
+    aggregate_batch_events: bool,
+    /// First block at which minting and burning are enabled, if any.
+    activation_block: Option<u64>,
+    /// Minimum block base fee for minting, if any.
+    min_base_fee: Option<u128>,
+    /// Owner and timelock of emergency mints, if enabled.
+    emergency_mint: Option<EmergencyMint>,
+    /// Approver of mints above a threshold, if enabled.
+    mint_approver: Option<MintApprover>,
+    /// Proxies allowed to DELEGATECALL the precompile.
+    allow_delegatecall_from: BTreeSet<Address>,
+    /// Addresses the precompile is installed at in addition to [`NATIVE_MINTER_ADDRESS`].
+    additional_addresses: Vec<Address>,
+    /// Log target identifying this instance.
+    log_target: &'static str,
+    /// Whether the amount minted to each recipient is tracked.
+    track_minted_to: bool,
+    /// What mints credit and burns debit.
+    mint_target: MintTarget,
+    /// Account allowed to set the gas cost at runtime, if any.
+    governor: Option<Address>,
+    /// Whether mints and burns return `true` instead of empty output.
+    return_bool: bool,
+    /// Decimals of the native token reported by `decimals()`.
+    decimals: u8,
+    /// Decimals of assets differing from the native token's, keyed by asset address.
+    asset_decimals: BTreeMap<Address, u8>,
+    /// Whether calldata with bytes after the encoded arguments is rejected.
+    strict_calldata: bool,
+    /// How much of the configuration logs reveal when rejecting a call.
+    error_verbosity: Verbosity,
+    /// Level rejected calls are logged at.
+    rejection_log_level: Level,
+}
+
+impl NativeMinterConfig {
+    /// Creates the default configuration with the given authorized bridge address.
+    pub const fn new(authorized_bridge: Address) -> Self {
+        Self {
+            authorized_bridge,
+            bridge_source: BridgeSource::Config,
+            burn_mode: BurnMode::Revert,
+            value_mode: ValueMode::Reject,
+            minters: BTreeSet::new(),
+            burners: BTreeSet::new(),
+            pausers: BTreeSet::new(),
+            additional_bridges: BTreeSet::new(),
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            max_calldata_len: DEFAULT_MAX_CALLDATA_LEN,
+            gas_model: GasModel::Flat(NATIVE_MINTER_GAS_COST),
+            mint_gas_cost: MINT_GAS_COST,
+            burn_gas_cost: BURN_GAS_COST,
+            view_gas_cost: VIEW_GAS_COST,
+            decode_gas_per_word: 0,
+            storage_salt: B256::ZERO,
+            rate_limit: None,
+            max_amount_per_call: U256::MAX,
+            burn_allowance_required: false,
+            require_origin_burn: false,
+            reject_missing_account_burn: false,
+            require_existing_recipient: false,
+            max_recipients_per_block: None,
+            mint_cooldown_blocks: None,
+            allowance_output: false,
+            burn_summary_output: false,
+            saturating_mint: false,
+            observe_only: false,
+            cancun_only: false,
+            mint_channel: None,
+            mint_dedup: false,
+            supply_cap: None,
+            breaker_threshold: None,
+            mint_fee: None,
+            event_scheme: None,
+            aggregate_batch_events: false,
+            activation_block: None,
+            min_base_fee: None,
+            emergency_mint: None,
+            mint_approver: None,
+            allow_delegatecall_from: BTreeSet::new(),
+            additional_addresses: Vec::new(),
+            log_target: DEFAULT_LOG_TARGET,
+            track_minted_to: false,
+            mint_target: MintTarget::Native,
+            governor: None,
+            return_bool: false,
+            decimals: 18,
+            asset_decimals: BTreeMap::new(),
+            strict_calldata: true,
+            error_verbosity: Verbosity::Detailed,
+            rejection_log_level: Level::WARN,
+        }
+    }
+
+    /// Returns the authorized bridge address.
+    pub const fn authorized_bridge(&self) -> Address {
+        self.authorized_bridge
+    }
+
+    /// Returns whether the NativeMinter is only installed in EVMs for Cancun or later, see
+    /// [`NativeMinterPrecompile::with_cancun_only`].
+    pub const fn cancun_only(&self) -> bool {
+        self.cancun_only
+    }
+
+    /// Checks the configuration for settings that would install a misconfigured precompile.
+    ///
+    /// Rejects an authorized bridge at one of the precompile's own addresses, which could never
+    /// call it, and a mint fee above 100%, which would take the whole amount of every mint.
+    pub fn validate(&self) -> Result<(), RkbConfigError> {
+        if self.authorized_bridge == NATIVE_MINTER_ADDRESS
+            || self.additional_addresses.contains(&self.authorized_bridge)
+        {
+            return Err(RkbConfigError::BridgeIsNativeMinter);
+        }
+        if let Some(fee) = self.mint_fee.filter(|fee| fee.bps > MintFee::MAX_BPS) {
+            return Err(RkbConfigError::FeeTooHigh(fee.bps));
+        }
+        Ok(())
+    }
+}
+
+impl From<NativeMinterPrecompile> for NativeMinterConfig {
+    fn from(precompile: NativeMinterPrecompile) -> Self {
+        precompile.into_config()
+    }
+}
+
+/// NativeMinter precompile for minting/burning native tokens during bridge operations.
+///
+/// # Usage
+///
+/// ```ignore
+/// use reth_rkb::NativeMinterPrecompile;
+/// use alloy_primitives::address;
+///
+/// // Create precompile with authorized bridge address
+/// let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+/// let precompile = NativeMinterPrecompile::new(bridge);
+///
+/// // Convert to DynPrecompile for use with PrecompilesMap
+/// let dyn_precompile = precompile.into_dyn_precompile();
+/// ```
+#[derive(Debug, Clone)]
+pub struct NativeMinterPrecompile {
+    /// Configuration enforced at call time, shared with the factory and every other EVM.
+    config: Arc<NativeMinterConfig>,
+    /// Chain ID permits are signed for, set per EVM.
+    chain_id: u64,
+    /// Addresses of the other precompiles installed alongside, rejected as mint recipients, set
+    /// per EVM.
+    precompile_addresses: BTreeSet<Address>,
+}
+
+impl NativeMinterPrecompile {
+    /// Creates a new NativeMinter precompile with the given authorized bridge address.
+    pub fn new(authorized_bridge: Address) -> Self {
+        Self::from_config(NativeMinterConfig::new(authorized_bridge))
+    }
+
+    /// Creates a new NativeMinter precompile enforcing the given configuration.
+    ///
+    /// The configuration may already be shared, as an `Arc<NativeMinterConfig>`, in which case it
+    /// is only copied if a builder modifies it.
+    pub fn from_config(config: impl Into<Arc<NativeMinterConfig>>) -> Self {
+        Self { config: config.into(), chain_id: 1, precompile_addresses: BTreeSet::new() }
+    }
+
+    /// Returns the configuration enforced at call time.
+    pub fn config(&self) -> &NativeMinterConfig {
+        &self.config
+    }
+
+    /// Returns the configuration enforced at call time, as shared with the factory.
+    pub const fn shared_config(&self) -> &Arc<NativeMinterConfig> {
+        &self.config
+    }
+
+    /// Returns the configuration enforced at call time, consuming the precompile.
+    ///
+    /// The chain ID and precompile addresses are set per EVM and aren't part of it.
+    pub fn into_config(self) -> NativeMinterConfig {
+        Arc::unwrap_or_clone(self.config)
+    }
+
+    /// Returns the configuration for a builder to modify, copying it first if it is shared.
+    fn config_mut(&mut self) -> &mut NativeMinterConfig {
+        Arc::make_mut(&mut self.config)
+    }
+
+    /// Replaces the authorized bridge the precompile was created with.
+    pub fn with_authorized_bridge(mut self, authorized_bridge: Address) -> Self {
+        self.config_mut().authorized_bridge = authorized_bridge;
+        self
+    }
+
+    /// Sets where the authorized bridge is read from at call time.
+    pub fn with_bridge_source(mut self, bridge_source: BridgeSource) -> Self {
+        self.config_mut().bridge_source = bridge_source;
+        self
+    }
+
+    /// Sets how burns exceeding the available balance are handled.
+    pub fn with_burn_mode(mut self, burn_mode: BurnMode) -> Self {
+        self.config_mut().burn_mode = burn_mode;
+        self
+    }
+
+    /// Sets how direct calls with native value attached are handled. Rejected by default.
+    ///
+    /// DELEGATECALLs from trusted proxies are unaffected, as their value stays with the proxy.
+    pub fn with_value_mode(mut self, value_mode: ValueMode) -> Self {
+        self.config_mut().value_mode = value_mode;
+        self
+    }
+
+    /// Sets the maximum number of recipients in a single `mintBatch` call.
+    ///
+    /// This bounds the worst-case execution of a batch, independently of the gas it is given.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.config_mut().max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Sets the maximum length of the calldata of a call, [`DEFAULT_MAX_CALLDATA_LEN`] by default.
+    ///
+    /// Longer calldata is rejected before being decoded, bounding the memory and CPU spent on
+    /// decoding the dynamic arrays of batch calls.
+    pub fn with_max_calldata_len(mut self, max_calldata_len: usize) -> Self {
+        self.config_mut().max_calldata_len = max_calldata_len;
+        self
+    }
+
+    /// Sets how gas is charged for a call.
+    ///
+    /// Mints and burns are charged their own cost in place of the model's base, see
+    /// [`Self::with_mint_gas_cost`] and [`Self::with_burn_gas_cost`].
+    pub fn with_gas_model(mut self, gas_model: GasModel) -> Self {
+        self.config_mut().gas_model = gas_model;
+        self
+    }
+
+    /// Sets the gas charged per mint, [`MINT_GAS_COST`] by default.
+    pub fn with_mint_gas_cost(mut self, mint_gas_cost: u64) -> Self {
+        self.config_mut().mint_gas_cost = mint_gas_cost;
+        self
+    }
+
+    /// Sets the gas charged per burn, [`BURN_GAS_COST`] by default.
+    pub fn with_burn_gas_cost(mut self, burn_gas_cost: u64) -> Self {
+        self.config_mut().burn_gas_cost = burn_gas_cost;
+        self
+    }
+
+    /// Sets the gas charged per call to a view function, [`VIEW_GAS_COST`] by default.
+    ///
+    /// Unlike the costs of state-modifying functions, it is not replaced by the cost set with
+    /// `setGasCost`.
+    pub fn with_view_gas_cost(mut self, view_gas_cost: u64) -> Self {
+        self.config_mut().view_gas_cost = view_gas_cost;
+        self
+    }
+
+    /// Sets the gas charged per 32-byte word of calldata for decoding it, on top of the gas
+    /// model and any cost set by the governor. Zero by default.
+    ///
+    /// Gas is then checked in two phases: a call is first checked against the cost of decoding
+    /// its calldata, then against the cost of executing its operations, which for `mintBatch` is
+    /// only known once decoded. A call given enough gas to decode but not to execute fails at the
+    /// second check, and each failure logs the phase it ran out of gas in.
+    pub fn with_decode_gas_per_word(mut self, decode_gas_per_word: u64) -> Self {
+        self.config_mut().decode_gas_per_word = decode_gas_per_word;
+        self
+    }
+
+    /// Namespaces the precompile's storage by `salt`, storing each logical slot at
+    /// `keccak256(salt . slot)`. Zero by default, which keeps the documented layout.
+    ///
+    /// Changing the salt of a live chain orphans the state stored under the previous salt, so a
+    /// new salt is meant for a new layout or a precompile sharing the account. The bridge seeded
+    /// by [`storage::genesis_alloc`] and [`Self::export_storage`] assume the default salt, while
+    /// [`RkbBlockSummary::from_bundle_state`](crate::RkbBlockSummary::from_bundle_state) takes it. Transient storage is cleared after every transaction and isn't salted.
+    pub fn with_storage_salt(mut self, salt: B256) -> Self {
+        self.config_mut().storage_salt = salt;
+        self
+    }
+
+    /// Caps the amount minted per window of block timestamps.
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.config_mut().rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Caps the amount of any single mint or burn, as a sanity bound independent of the rate
+    /// limit. Each mint of a `mintBatch` is capped separately. Unbounded by default.
+    pub fn with_max_amount_per_call(mut self, max_amount_per_call: U256) -> Self {
+        self.config_mut().max_amount_per_call = max_amount_per_call;
+        self
+    }
+
+    /// Sets whether burning from an account other than the caller consumes an allowance the
+    /// account granted to the caller via `approve` or `permit`.
+    pub fn with_burn_allowance_required(mut self, required: bool) -> Self {
+        self.config_mut().burn_allowance_required = required;
+        self
+    }
+
+    /// Sets whether burns by an authorized bridge must burn from the transaction origin,
+    /// reverting with "burn not from transaction origin" otherwise. Disabled by default.
+    ///
+    /// The user initiating the transaction is then the only account the bridge can burn from.
+    /// Burns by other holders of the [`Role::Burner`] role are unaffected.
+    pub fn with_require_origin_burn(mut self, require: bool) -> Self {
+        self.config_mut().require_origin_burn = require;
+        self
+    }
+
+    /// Sets whether burning from an account that doesn't exist reverts with "account does not
+    /// exist" instead of "insufficient balance".
+    ///
+    /// An account doesn't exist if it is empty as defined by EIP-161 (no balance, nonce or code),
+    /// whether or not it was ever touched, as both are equivalent for the state. By default such
+    /// a burn is handled like any other: it reverts with "insufficient balance" unless it burns
+    /// zero. Only applies to the [`MintTarget::Native`] target.
+    pub fn with_reject_missing_account_burn(mut self, reject: bool) -> Self {
+        self.config_mut().reject_missing_account_burn = reject;
+        self
+    }
+
+    /// Sets whether mints only credit existing accounts, reverting with "recipient does not exist"
+    /// otherwise. Disabled by default.
+    ///
+    /// Crediting a fresh address creates an account, so this prevents state growth from spam
+    /// mints to random addresses. Existence is as for
+    /// [`with_reject_missing_account_burn`](Self::with_reject_missing_account_burn). Only applies
+    /// to the [`MintTarget::Native`] target.
+    pub fn with_require_existing_recipient(mut self, require: bool) -> Self {
+        self.config_mut().require_existing_recipient = require;
+        self
+    }
+
+    /// Sets the maximum number of distinct recipients minted to per block, reverting with "too
+    /// many recipients this block" once exceeded. Unlimited by default.
+    ///
+    /// This bounds the accounts a compromised or misbehaving bridge can create per block through
+    /// many tiny mints. Transient storage is cleared after every transaction, so the count is kept
+    /// in persistent storage along with the block it is for, and starts over at the first mint of
+    /// the next block. Mints to a recipient already counted in the block are not counted again.
+    pub fn with_max_recipients_per_block(mut self, max: u32) -> Self {
+        self.config_mut().max_recipients_per_block = Some(max);
+        self
+    }
+
+    /// Requires `blocks` blocks to pass between two mints to the same recipient, reverting with
+    /// "mint cooldown not elapsed" otherwise. Disabled by default.
+    ///
+    /// Unlike [`with_rate_limit`](Self::with_rate_limit), which caps the amount minted to all
+    /// recipients together, this throttles repeated mints to a single address. A mint in block `n`
+    /// allows the next mint to that recipient from block `n + blocks`, so a recipient can't be
+    /// minted to twice within a block, including within one `mintBatch`.
+    pub fn with_mint_cooldown_blocks(mut self, blocks: u64) -> Self {
+        self.config_mut().mint_cooldown_blocks = Some(blocks);
+        self
+    }
+
+    /// Sets whether `approve` returns the newly set allowance, and a burn consuming an allowance
+    /// returns the remaining allowance, both as a 32-byte `uint256`, saving wallets a follow-up
+    /// read. Disabled by default, `approve` returning `true` as in ERC-20.
+    ///
+    /// Burns that don't consume an allowance keep their usual output.
+    pub fn with_allowance_output(mut self, allowance_output: bool) -> Self {
+        self.config_mut().allowance_output = allowance_output;
+        self
+    }
+
+    /// Sets whether burns return `(uint256 newBalance, uint256 totalBurned)`, the burned
+    /// account's balance after the burn and the running `totalBurned`, saving withdrawal UIs two
+    /// follow-up reads. Disabled by default.
+    ///
+    /// Takes precedence over every other burn output, including
+    /// [`with_allowance_output`](Self::with_allowance_output).
+    pub fn with_burn_summary_output(mut self, burn_summary_output: bool) -> Self {
+        self.config_mut().burn_summary_output = burn_summary_output;
+        self
+    }
+
+    /// Sets whether a mint overflowing the recipient's balance credits it up to `U256::MAX`
+    /// rather than reverting. Disabled by default.
+    ///
+    /// Single-recipient mints then return the amount actually credited to the recipient as a
+    /// 32-byte `uint256`, and only that amount counts towards `totalMinted`. The supply cap, rate
+    /// limit and other limits are still checked against the requested amount.
+    pub fn with_saturating_mint(mut self, saturating_mint: bool) -> Self {
+        self.config_mut().saturating_mint = saturating_mint;
+        self
+    }
+
+    /// Sets the chain ID permits are signed for.
+    pub const fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Runs the precompile in observe-only mode: every check and log is performed, but mints and
+    /// burns succeed without changing any balance.
+    ///
+    /// This is a diagnostic mode for shadow nodes and must never be enabled on a validating node.
+    pub(crate) fn with_observe_only(mut self) -> Self {
+        self.config_mut().observe_only = true;
+        self
+    }
+
+    /// Sets whether the NativeMinter is only installed in EVMs for Cancun or later, as its
+    /// reentrancy guard and per-transaction checks rely on transient storage (EIP-1153).
+    /// Disabled by default: the NativeMinter is installed for every spec.
+    ///
+    /// This is a consensus change for any chain with pre-Cancun history, whose blocks calling the
+    /// NativeMinter would execute differently. Only enable it on chains activating Cancun at
+    /// genesis.
+    pub fn with_cancun_only(mut self, cancun_only: bool) -> Self {
+        self.config_mut().cancun_only = cancun_only;
+        self
+    }
+
+    /// Sends a [`MintBurnRecord`] of every successful mint and burn to `sender`, for
+    /// high-throughput off-chain reconciliation.
+    ///
+    /// Records are sent with a non-blocking `try_send` so block production never waits on the
+    /// consumer: when the channel is full or closed the record is dropped and counted in
+    /// `rkb_dropped_records_total`. A record is sent as soon as the call succeeds, so it may
+    /// belong to a transaction that later reverts or a block that is never committed; consumers
+    /// must reconcile against committed state.
+    pub fn with_mint_channel(mut self, sender: Sender<MintBurnRecord>) -> Self {
+        self.config_mut().mint_channel = Some(sender);
+        self
+    }
+
+    /// Sets whether a mint of the same amount to the same recipient as an earlier mint in the
+    /// same transaction is rejected.
+    ///
+    /// Minted pairs are recorded in transient storage, so this only deduplicates within a
+    /// transaction, not across transactions. It applies to every mint function, including
+    /// repeated entries of a single `mintBatch`.
+    pub fn with_mint_dedup(mut self, mint_dedup: bool) -> Self {
+        self.config_mut().mint_dedup = mint_dedup;
+        self
+    }
+
+    /// Caps the net amount minted through the precompile, `totalMinted - totalBurned`.
+    pub fn with_supply_cap(mut self, supply_cap: U256) -> Self {
+        self.config_mut().supply_cap = Some(supply_cap);
+        self
+    }
+
+    /// Sets a circuit breaker rejecting any mint that would push the amount minted within a block
+    /// over `threshold`. Disabled by default.
+    ///
+    /// The mint tripping the breaker reverts and logs a warning, so the block's total never exceeds
+    /// the threshold. The breaker doesn't pause minting: a revert rolls back every state change of
+    /// the call, so the pause flag couldn't be persisted along with it. Mints fitting under the
+    /// threshold still succeed, and a pauser watching for the warning pauses minting with
+    /// `pauseMint` if needed.
+    ///
+    /// Transient storage is cleared after every transaction, so the block's total is kept in
+    /// persistent storage, as for
+    /// [`with_max_recipients_per_block`](Self::with_max_recipients_per_block). It restarts from
+    /// zero in each block.
+    pub fn with_breaker_threshold(mut self, threshold: U256) -> Self {
+        self.config_mut().breaker_threshold = Some(threshold);
+        self
+    }
+
+    /// Takes a fee from every mint, crediting it to the fee recipient instead of the mint's
+    /// recipient.
+    ///
+    /// The fee is part of the minted amount, so it counts towards the totals, supply cap and rate
+    /// limit.
+    pub fn with_mint_fee(mut self, mint_fee: MintFee) -> Self {
+        self.config_mut().mint_fee = Some(mint_fee);
+        self
+    }
+
+    /// Emits events for mints and burns according to the given scheme.
+    ///
+    /// No events are emitted by default, the bridge contract is expected to emit its own.
+    pub fn with_event_scheme(mut self, event_scheme: EventScheme) -> Self {
+        self.config_mut().event_scheme = Some(event_scheme);
+        self
+    }
+
+    /// Sets whether `mintBatch` emits a single `BatchMint(count, totalAmount, recipientsHash)`
+    /// event instead of the events of the [`EventScheme`] for every mint. Disabled by default.
+    ///
+    /// This bounds the logs of a batch to one entry while keeping it auditable: the recipients
+    /// can be checked against `recipientsHash` from the calldata. Only applies if an event scheme
+    /// is set.
+    pub fn with_aggregate_batch_events(mut self, aggregate: bool) -> Self {
+        self.config_mut().aggregate_batch_events = aggregate;
+        self
+    }
+
+    /// Rejects mints and burns in blocks below `activation_block`.
+    ///
+    /// This keeps minting disabled during the genesis bootstrap window, before the bridge is
+    /// deployed and configured.
+    pub fn with_activation_block(mut self, activation_block: u64) -> Self {
+        self.config_mut().activation_block = Some(activation_block);
+        self
+    }
+
+    /// Rejects mints in blocks whose base fee is below `min_base_fee`.
+    ///
+    /// A collapsed base fee can indicate a stuck chain, in which case a PoA sequencer may prefer
+    /// to halt minting until the network recovers. Burns are unaffected.
+    pub fn with_min_base_fee(mut self, min_base_fee: u128) -> Self {
+        self.config_mut().min_base_fee = Some(min_base_fee);
+        self
+    }
+
+    /// Enables timelocked emergency mints, see [`EmergencyMint`].
+    pub fn with_emergency_mint(mut self, emergency_mint: EmergencyMint) -> Self {
+        self.config_mut().emergency_mint = Some(emergency_mint);
+        self
+    }
+
+    /// Requires an off-chain approval for mints above a threshold, see [`MintApprover`].
+    pub fn with_mint_approver(mut self, mint_approver: MintApprover) -> Self {
+        self.config_mut().mint_approver = Some(mint_approver);
+        self
+    }
+
+    /// Grants the [`Role::Minter`] role to the given address.
+    pub fn add_minter(mut self, minter: Address) -> Self {
+        self.config_mut().minters.insert(minter);
+        self
+    }
+
+    /// Grants the [`Role::Burner`] role to the given address.
+    pub fn add_burner(mut self, burner: Address) -> Self {
+        self.config_mut().burners.insert(burner);
+        self
+    }
+
+    /// Grants the [`Role::Pauser`] role to the given address.
+    pub fn add_pauser(mut self, pauser: Address) -> Self {
+        self.config_mut().pausers.insert(pauser);
+        self
+    }
+
+    /// Authorizes an additional bridge, granting it every [`Role`].
+    ///
+    /// Additional bridges are listed by the `bridgeCount` and `bridgeAt` views, together with the
+    /// authorized bridge.
+    pub fn add_bridge(self, bridge: Address) -> Self {
+        let mut this = self.add_minter(bridge).add_burner(bridge).add_pauser(bridge);
+        this.config_mut().additional_bridges.insert(bridge);
+        this
+    }
+
+    /// Allows the given proxy to DELEGATECALL the precompile.
+    ///
+    /// **Security-sensitive:** a delegated call executes on behalf of the proxy, so it is
+    /// authorized as if the proxy had called the precompile directly, regardless of which code
+    /// the proxy delegated to. Only trust a proxy whose implementation is controlled by the
+    /// bridge operator, and which is itself authorized.
+    ///
+    /// The proxy is identified by the context the precompile executes in (`target_address`): the
+    /// bytecode address of a call into the precompile is always one of its own
+    /// [`addresses`](Self::addresses), so it can't tell proxies apart. CALLCODE remains rejected.
+    pub fn allow_delegatecall_from(mut self, proxy: Address) -> Self {
+        self.config_mut().allow_delegatecall_from.insert(proxy);
+        self
+    }
+
+    /// Returns the proxies allowed to DELEGATECALL the precompile.
+    pub fn delegatecall_proxies(&self) -> &BTreeSet<Address> {
+        &self.config.allow_delegatecall_from
+    }
+
+    /// Also installs the precompile at `address`, for migrating it to a new address while keeping
+    /// the old one active during a transition window.
+    ///
+    /// Every address runs the same precompile on the same state: storage is always kept in the
+    /// account at [`NATIVE_MINTER_ADDRESS`], whichever address is called, and events are emitted
+    /// from it too.
+    pub fn with_additional_address(mut self, address: Address) -> Self {
+        if address != NATIVE_MINTER_ADDRESS && !self.config.additional_addresses.contains(&address)
+        {
+            self.config_mut().additional_addresses.push(address);
+        }
+        self
+    }
+
+    /// Returns the addresses the precompile is installed at, starting with
+    /// [`NATIVE_MINTER_ADDRESS`].
+    pub fn addresses(&self) -> impl Iterator<Item = Address> + '_ {
+        core::iter::once(NATIVE_MINTER_ADDRESS)
+            .chain(self.config.additional_addresses.iter().copied())
+    }
+
+    /// Rejects mints to the given precompile addresses, in addition to the precompile's own
+    /// addresses, which are always rejected.
+    ///
+    /// Minting to a precompile is almost always a bug and could interfere with its behavior.
+    /// [`RkbEvmFactory`](crate::RkbEvmFactory) sets these to every precompile it installs for the
+    /// active hardfork.
+    pub fn with_precompile_addresses(
+        mut self,
+        addresses: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        self.precompile_addresses.extend(addresses);
+        self
+    }
+
+    /// Returns the addresses of the other precompiles rejected as mint recipients.
+    pub const fn precompile_addresses(&self) -> &BTreeSet<Address> {
+        &self.precompile_addresses
+    }
+
+    /// Sets the log target identifying this instance, e.g. `chainA::native_minter`, for nodes
+    /// running several instances.
+    ///
+    /// `tracing` requires event targets to be known at compile time, so events keep the
+    /// [`DEFAULT_LOG_TARGET`] target and are recorded within a `native_minter` span whose
+    /// `log_target` field holds the configured target.
+    pub fn with_log_target(mut self, log_target: &'static str) -> Self {
+        self.config_mut().log_target = log_target;
+        self
+    }
+
+    /// Returns the log target identifying this instance.
+    pub fn log_target(&self) -> &'static str {
+        self.config.log_target
+    }
+
+    /// Sets whether the total amount credited to each recipient is tracked, readable through
+    /// `mintedTo`.
+    ///
+    /// This costs an additional storage write per credited account of every mint, so it is
+    /// disabled by default.
+    pub fn with_minted_to_tracking(mut self, track_minted_to: bool) -> Self {
+        self.config_mut().track_minted_to = track_minted_to;
+        self
+    }
+
+    /// Returns whether the total amount credited to each recipient is tracked.
+    pub fn track_minted_to(&self) -> bool {
+        self.config.track_minted_to
+    }
+
+    /// Sets what mints credit and burns debit, native balance by default.
+    pub fn with_mint_target(mut self, mint_target: MintTarget) -> Self {
+        self.config_mut().mint_target = mint_target;
+        self
+    }
+
+    /// Returns what mints credit and burns debit.
+    pub fn mint_target(&self) -> MintTarget {
+        self.config.mint_target
+    }
+
+    /// Allows `governor` to set the gas charged per call at runtime with `setGasCost`, so it can
+    /// be tuned through governance transactions instead of node restarts.
+    ///
+    /// The cost is stored in [`GAS_COST_SLOT`] and replaces the gas model's base and the mint and
+    /// burn gas costs while nonzero. It is only read while a governor is configured.
+    ///
+    /// With [`BridgeSource::State`], the governor may also rotate the authorized bridge in two
+    /// steps: it proposes the new bridge with `proposeBridge`, which then confirms it controls the
+    /// address by calling `acceptBridge`. This prevents handing authorization to a wrong or
+    /// uncontrolled address.
+    pub fn with_governor(mut self, governor: Address) -> Self {
+        self.config_mut().governor = Some(governor);
+        self
+    }
+
+    /// Returns the account allowed to set the gas cost and propose a new bridge at runtime, if
+    /// any.
+    pub fn governor(&self) -> Option<Address> {
+        self.config.governor
+    }
+
+    /// Sets whether mints and burns return `true` ABI-encoded as a 32-byte `bool` on success,
+    /// for callers treating the precompile as an ERC-20. Disabled by default, mints and burns
+    /// returning empty output.
+    ///
+    /// Burns under [`BurnMode::Clamp`] keep returning the burned amount.
+    pub fn with_return_bool(mut self, return_bool: bool) -> Self {
+        self.config_mut().return_bool = return_bool;
+        self
+    }
+
+    /// Returns whether mints and burns return `true` instead of empty output.
+    pub fn return_bool(&self) -> bool {
+        self.config.return_bool
+    }
+
+    /// Sets the decimals of the native token reported by the `decimals()` view, 18 by default.
+    ///
+    /// Amounts minted and burned are never scaled by it. It is the default of
+    /// [`asset_decimals`](Self::asset_decimals) for integrators converting amounts with
+    /// [`scale_to_asset`](Self::scale_to_asset).
+    pub fn with_decimals(mut self, decimals: u8) -> Self {
+        self.config_mut().decimals = decimals;
+        self
+    }
+
+    /// Returns the decimals of the native token reported by the `decimals()` view.
+    pub fn decimals(&self) -> u8 {
+        self.config.decimals
+    }
+
+    /// Sets the decimals of the asset at `asset`, such as the token of an [`Erc20Target`], for
+    /// [`scale_to_asset`](Self::scale_to_asset).
+    ///
+    /// Assets without configured decimals, including the native token, use the native token's
+    /// [`decimals`](Self::with_decimals).
+    pub fn with_asset_decimals(mut self, asset: Address, decimals: u8) -> Self {
+        self.config_mut().asset_decimals.insert(asset, decimals);
+        self
+    }
+
+    /// Returns the decimals of the asset at `asset`, defaulting to the native token's.
+    pub fn asset_decimals(&self, asset: Address) -> u8 {
+        self.config.asset_decimals.get(&asset).copied().unwrap_or(self.config.decimals)
+    }
+
+    /// Returns the decimals configured per asset.
+    pub fn all_asset_decimals(&self) -> &BTreeMap<Address, u8> {
+        &self.config.asset_decimals
+    }
+
+    /// Converts `amount`, denominated in the native token's decimals, to the decimals of the
+    /// asset at `asset`.
+    ///
+    /// Returns `None` if the converted amount overflows, or if scaling down would drop a
+    /// remainder, as rounding would silently lose funds.
+    pub fn scale_to_asset(&self, asset: Address, amount: U256) -> Option<U256> {
+        scale_decimals(amount, self.config.decimals, self.asset_decimals(asset))
+    }
+
+    /// Sets whether calldata must be exactly the selector followed by the canonical encoding of
+    /// the arguments, rejecting trailing bytes that could mask encoder bugs or smuggle data.
+    /// Enabled by default.
+    pub fn with_strict_calldata(mut self, strict_calldata: bool) -> Self {
+        self.config_mut().strict_calldata = strict_calldata;
+        self
+    }
+
+    /// Sets how much of the configuration logs reveal when rejecting a call.
+    /// [`Verbosity::Detailed`] by default.
+    ///
+    /// Under [`Verbosity::Terse`], the authorized bridge, pending bridge, governor and emergency
+    /// mint owner are logged as `<redacted>`, so logs shipped to a shared sink don't disclose
+    /// which accounts may call the precompile.
+    pub fn with_error_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.config_mut().error_verbosity = verbosity;
+        self
+    }
+
+    /// Sets the level calls rejected by the precompile's checks, such as unauthorized, static
+    /// and delegated calls, are logged at. [`Level::WARN`] by default.
+    ///
+    /// Raising it to [`Level::ERROR`] surfaces rejections to alerting, lowering it to
+    /// [`Level::DEBUG`] silences them during load tests. Out-of-gas reverts, logged to help size
+    /// the gas forwarded rather than to flag misuse, stay at [`Level::WARN`].
+    pub fn with_rejection_log_level(mut self, level: Level) -> Self {
+        self.config_mut().rejection_log_level = level;
+        self
+    }
+
+    /// Returns whether calldata with bytes after the encoded arguments is rejected.
+    pub fn strict_calldata(&self) -> bool {
+        self.config.strict_calldata
+    }
+
+    /// Returns the bridges authorized in addition to the authorized bridge.
+    pub fn additional_bridges(&self) -> &BTreeSet<Address> {
+        &self.config.additional_bridges
+    }
+
+    /// Returns how much of the configuration logs reveal when rejecting a call.
+    pub fn error_verbosity(&self) -> Verbosity {
+        self.config.error_verbosity
+    }
+
+    /// Returns the level rejected calls are logged at.
+    pub fn rejection_log_level(&self) -> Level {
+        self.config.rejection_log_level
+    }
+
+    /// Returns the addresses granted the given role, in addition to the authorized bridge.
+    pub fn role_members(&self, role: Role) -> &BTreeSet<Address> {
+        match role {
+            Role::Minter => &self.config.minters,
+            Role::Burner => &self.config.burners,
+            Role::Pauser => &self.config.pausers,
+        }
+    }
+
+    /// Returns the authorized bridge address.
+    ///
+    /// With [`BridgeSource::State`] this is not the address enforced at call time.
+    pub fn authorized_bridge(&self) -> Address {
+        self.config.authorized_bridge
+    }
+
+    /// Returns where the authorized bridge is read from at call time.
+    pub fn bridge_source(&self) -> BridgeSource {
+        self.config.bridge_source
+    }
+
+    /// Returns how burns exceeding the available balance are handled.
+    pub fn burn_mode(&self) -> BurnMode {
+        self.config.burn_mode
+    }
+
+    /// Returns how direct calls with native value attached are handled.
+    pub fn value_mode(&self) -> ValueMode {
+        self.config.value_mode
+    }
+
+    /// Returns the maximum number of recipients in a single `mintBatch` call.
+    pub fn max_batch_size(&self) -> usize {
+        self.config.max_batch_size
+    }
+
+    /// Returns the maximum length of the calldata of a call.
+    pub fn max_calldata_len(&self) -> usize {
+        self.config.max_calldata_len
+    }
+
+    /// Returns how gas is charged for a call.
+    pub fn gas_model(&self) -> GasModel {
+        self.config.gas_model
+    }
+
+    /// Returns the gas charged per mint.
+    pub fn mint_gas_cost(&self) -> u64 {
+        self.config.mint_gas_cost
+    }
+
+    /// Returns the gas charged per burn.
+    pub fn burn_gas_cost(&self) -> u64 {
+        self.config.burn_gas_cost
+    }
+
+    /// Returns the gas charged per call to a view function.
+    pub fn view_gas_cost(&self) -> u64 {
+        self.config.view_gas_cost
+    }
+
+    /// Returns the gas charged per 32-byte word of calldata for decoding it.
+    pub fn decode_gas_per_word(&self) -> u64 {
+        self.config.decode_gas_per_word
+    }
+
+    /// Returns the salt mixed into every storage slot.
+    pub fn storage_salt(&self) -> B256 {
+        self.config.storage_salt
+    }
+
+    /// Returns the physical slot backing a logical slot, such as one derived by
+    /// [`storage::allowance_slot`], under this precompile's storage salt. See
+    /// [`storage::namespaced_slot`].
+    pub fn storage_slot(&self, slot: U256) -> U256 {
+        storage::namespaced_slot(self.config.storage_salt, slot)
+    }
+
+    /// Returns the cap on the amount minted per window of block timestamps, if any.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.config.rate_limit
+    }
+
+    /// Returns the maximum amount of any single mint or burn.
+    pub fn max_amount_per_call(&self) -> U256 {
+        self.config.max_amount_per_call
+    }
+
+    /// Returns whether burning from another account consumes an allowance.
+    pub fn burn_allowance_required(&self) -> bool {
+        self.config.burn_allowance_required
+    }
+
+    /// Returns whether burns by an authorized bridge must burn from the transaction origin.
+    pub fn require_origin_burn(&self) -> bool {
+        self.config.require_origin_burn
+    }
+
+    /// Returns whether burning from a nonexistent account reverts with its own error.
+    pub fn reject_missing_account_burn(&self) -> bool {
+        self.config.reject_missing_account_burn
+    }
+
+    /// Returns whether mints to a nonexistent account revert.
+    pub fn require_existing_recipient(&self) -> bool {
+        self.config.require_existing_recipient
+    }
+
+    /// Returns the cap on the number of distinct recipients minted to per block, if any.
+    pub fn max_recipients_per_block(&self) -> Option<u32> {
+        self.config.max_recipients_per_block
+    }
+
+    /// Returns the number of blocks that must pass between two mints to the same recipient, if
+    /// any.
+    pub fn mint_cooldown_blocks(&self) -> Option<u64> {
+        self.config.mint_cooldown_blocks
+    }
+
+    /// Returns whether `approve` and allowance-consuming burns return the resulting allowance.
+    pub fn allowance_output(&self) -> bool {
+        self.config.allowance_output
+    }
+
+    /// Returns whether burns return the burned account's new balance and the total burned.
+    pub fn burn_summary_output(&self) -> bool {
+        self.config.burn_summary_output
+    }
+
+    /// Returns whether mints overflowing the recipient's balance credit up to `U256::MAX`.
+    pub fn saturating_mint(&self) -> bool {
+        self.config.saturating_mint
+    }
+
+    /// Returns the chain ID permits are signed for.
+    pub const fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Returns the EIP-712 domain permits are signed under.
+    pub const fn eip712_domain(&self) -> Eip712Domain {
+        eip712_domain! {
+            name: "NativeMinter",
+            version: "1",
+            chain_id: self.chain_id,
+            verifying_contract: NATIVE_MINTER_ADDRESS,
+        }
+    }
+
+    /// Returns whether the precompile runs in observe-only mode.
+    pub fn observe_only(&self) -> bool {
+        self.config.observe_only
+    }
+
+    /// Returns whether the NativeMinter is only installed in EVMs for Cancun or later.
+    pub fn cancun_only(&self) -> bool {
+        self.config.cancun_only
+    }
+
+    /// Returns the channel every successful mint and burn is sent to, if any.
+    pub fn mint_channel(&self) -> Option<&Sender<MintBurnRecord>> {
+        self.config.mint_channel.as_ref()
+    }
+
+    /// Returns whether identical mints within a transaction are rejected.
+    pub fn mint_dedup(&self) -> bool {
+        self.config.mint_dedup
+    }
+
+    /// Returns the cap on the net amount minted through the precompile, if any.
+    pub fn supply_cap(&self) -> Option<U256> {
+        self.config.supply_cap
+    }
+
+    /// Returns the amount minted within a block that trips the circuit breaker, if any.
+    pub fn breaker_threshold(&self) -> Option<U256> {
+        self.config.breaker_threshold
+    }
+
+    /// Returns the fee taken from every mint, if any.
+    pub fn mint_fee(&self) -> Option<MintFee> {
+        self.config.mint_fee
+    }
+
+    /// Returns the events emitted for mints and burns, if any.
+    pub fn event_scheme(&self) -> Option<EventScheme> {
+        self.config.event_scheme
+    }
+
+    /// Returns whether `mintBatch` emits a single `BatchMint` event instead of an event per mint.
+    pub fn aggregate_batch_events(&self) -> bool {
+        self.config.aggregate_batch_events
+    }
+
+    /// Returns the first block at which minting and burning are enabled, if any.
+    pub fn activation_block(&self) -> Option<u64> {
+        self.config.activation_block
+    }
+
+    /// Returns the minimum block base fee for minting, if any.
+    pub fn min_base_fee(&self) -> Option<u128> {
+        self.config.min_base_fee
+    }
+
+    /// Returns the owner and timelock of emergency mints, if enabled.
+    pub fn emergency_mint(&self) -> Option<EmergencyMint> {
+        self.config.emergency_mint
+    }
+
+    /// Returns the approver of mints above a threshold, if enabled.
+    pub fn mint_approver(&self) -> Option<MintApprover> {
+        self.config.mint_approver
+    }
+
+    /// Returns the optional capabilities enabled in this configuration.
+    pub fn features(&self) -> Features {
+        let config = &self.config;
+        [
+            (config.rate_limit.is_some(), Features::RATE_LIMIT),
+            (!config.pausers.is_empty(), Features::PAUSABLE),
+            (config.mint_fee.is_some(), Features::MINT_FEE),
+            (config.supply_cap.is_some(), Features::SUPPLY_CAP),
+            (config.max_amount_per_call != U256::MAX, Features::CALL_CAP),
+            (config.burn_allowance_required, Features::BURN_ALLOWANCE),
+            (config.burn_mode == BurnMode::Clamp, Features::BURN_CLAMP),
+            (config.mint_dedup, Features::MINT_DEDUP),
+            (config.event_scheme.is_some(), Features::EVENTS),
+            (config.emergency_mint.is_some(), Features::EMERGENCY_MINT),
+            (config.track_minted_to, Features::MINTED_TO),
+            (matches!(config.mint_target, MintTarget::Erc20(_)), Features::ERC20_TARGET),
+            (config.governor.is_some(), Features::GOVERNOR),
+            (config.return_bool, Features::RETURN_BOOL),
+            (config.bridge_source == BridgeSource::State, Features::BRIDGE_FROM_STATE),
+            (config.activation_block.is_some(), Features::ACTIVATION_BLOCK),
+            (!config.allow_delegatecall_from.is_empty(), Features::DELEGATECALL_PROXIES),
+            (config.observe_only, Features::OBSERVE_ONLY),
+            (config.min_base_fee.is_some(), Features::MIN_BASE_FEE),
+            (config.max_recipients_per_block.is_some(), Features::RECIPIENT_CAP),
+            (config.breaker_threshold.is_some(), Features::CIRCUIT_BREAKER),
+            (config.mint_cooldown_blocks.is_some(), Features::MINT_COOLDOWN),
+            (config.mint_approver.is_some(), Features::MINT_APPROVAL),
+            (config.saturating_mint, Features::SATURATING_MINT),
+            (config.require_origin_burn, Features::ORIGIN_BURN),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .fold(Features::default(), |features, (_, feature)| features.union(feature))
+    }
+
+    /// Exports the precompile's fixed storage slots from the given state.
+    ///
+    /// Only slots with a non-zero value are included. Mapping entries, such as processed message
+    /// IDs, can't be enumerated without their keys and are not exported.
+    pub fn export_storage<DB: DatabaseRef>(db: &DB) -> Result<BTreeMap<U256, U256>, DB::Error> {
+        let mut exported = BTreeMap::new();
+        for slot in storage::FIXED_SLOTS {
+            let value = db.storage_ref(NATIVE_MINTER_ADDRESS, slot)?;
+            if !value.is_zero() {
+                exported.insert(slot, value);
+            }
+        }
+        Ok(exported)
+    }
+
+    /// Imports storage previously returned by [`Self::export_storage`] into the given state.
+    pub fn import_storage<DB: DatabaseRef>(
+        db: &mut CacheDB<DB>,
+        storage: &BTreeMap<U256, U256>,
+    ) -> Result<(), DB::Error> {
+        for (slot, value) in storage {
+            db.insert_account_storage(NATIVE_MINTER_ADDRESS, *slot, *value)?;
+        }
+        Ok(())
+    }
+
+    /// Converts this precompile into a [`DynPrecompile`] for use with [`PrecompilesMap`].
+    pub fn into_dyn_precompile(self) -> DynPrecompile {
+        DynPrecompile::new_stateful(
+            PrecompileId::custom(NATIVE_MINTER_ID),
+            move |input: PrecompileInput<'_>| self.call(input),
+        )
+    }
+
+    /// Execute the precompile call.
+    ///
+    /// Every [`PrecompileInput`] field is populated by the EVM for top-level and nested calls
+    /// alike, so only the calldata is untrusted: it is never indexed without checking its length,
+    /// and malformed calldata reverts instead of panicking.
+    pub(crate) fn call(&self, input: PrecompileInput<'_>) -> PrecompileResult {
+        // Entered at the level of the least verbose events, so it is recorded with all of them
+        let _span = warn_span!(
+            target: "rkb::native_minter",
+            "native_minter",
+            log_target = %self.config.log_target
+        )
+        .entered();
+
+        let data = input.data;
+        let result = self.execute(input);
+        record_call(data, &result);
+        result
+    }
+
+    /// Executes the call, after entering the span of the precompile.
+    fn execute(&self, mut input: PrecompileInput<'_>) -> PrecompileResult {
+        // Bound the work spent on the calldata before any of it is decoded
+        if input.data.len() > self.config.max_calldata_len {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                caller = %input.caller,
+                len = input.data.len(),
+                max = self.config.max_calldata_len,
+                "NativeMinter: calldata too large"
+            );
+            return Err(NativeMinterError::CalldataTooLarge.into());
+        }
+
+        // Check gas, first for decoding the calldata, then for executing a single operation. A
+        // call given exactly the cost succeeds and consumes all of it.
+        ensure_enough_gas_to_decode(input.gas, self.decode_gas_cost(input.data.len()))?;
+        ensure_enough_gas(input.gas, self.gas_cost(&mut input, 1)?)?;
+
+        // Security: Must be a direct call, not DELEGATECALL or CALLCODE, unless delegated from a
+        // trusted proxy. The delegated call then acts as the proxy.
+        if !input.is_direct_call() {
+            let err = indirect_call_error(&input);
+            if err == NativeMinterError::DelegateCall
+                && self.config.allow_delegatecall_from.contains(&input.target_address)
+            {
+                debug!(
+                    target: "rkb::native_minter",
+                    caller = %input.caller,
+                    proxy = %input.target_address,
+                    "Accepting DELEGATECALL from trusted proxy"
+                );
+                input.caller = input.target_address;
+            } else {
+                reject!(
+                    self,
+                    target: "rkb::native_minter",
+                    caller = %input.caller,
+                    target = %input.target_address,
+                    bytecode = %input.bytecode_address,
+                    "{err}"
+                );
+                return Err(err.into());
+            }
+        }
+
+        // Security: System calls never legitimately reach the precompile, so a call from the
+        // system address or a system contract can only be a misconfiguration
+        if SYSTEM_ADDRESSES.contains(&input.caller) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                caller = %input.caller,
+                "NativeMinter: system context not allowed"
+            );
+            return Err(NativeMinterError::SystemCall.into());
+        }
+
+        // Security: The value of a direct call was transferred to the precompile's account, where
+        // it would be lost
+        if !input.value.is_zero() && input.is_direct_call() {
+            match self.config.value_mode {
+                ValueMode::Reject => {
+                    reject!(
+                        self,
+                        target: "rkb::native_minter",
+                        caller = %input.caller,
+                        value = %input.value,
+                        "NativeMinter: value not accepted"
+                    );
+                    return Err(NativeMinterError::ValueNotAccepted.into());
+                }
+                ValueMode::Refund => self.refund_value(&mut input)?,
+            }
+        }
+
+        // View functions are readable by anyone, including via STATICCALL
+        if let Some(result) = self.view(&mut input) {
+            return result;
+        }
+
+        // Security: Cannot call in STATICCALL context
+        if input.is_static_call() {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                caller = %input.caller,
+                "NativeMinter: STATICCALL not allowed"
+            );
+            return Err(NativeMinterError::StaticCall.into());
+        }
+
+        // Security: Reject reentrant calls. The flag lives in transient storage, so it is scoped to
+        // the transaction and rolled back together with a reverting call frame.
+        if storage::tload(&mut input, REENTRANCY_GUARD_SLOT) != U256::ZERO {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                caller = %input.caller,
+                "NativeMinter: reentrant call"
+            );
+            return Err(NativeMinterError::ReentrantCall.into());
+        }
+
+        storage::tstore(&mut input, REENTRANCY_GUARD_SLOT, U256::from(1));
+        let result = self.dispatch(&mut input);
+        storage::tstore(&mut input, REENTRANCY_GUARD_SLOT, U256::ZERO);
+
+        result
+    }
+
+    /// Execute a view function, if the calldata selects one.
+    fn view(&self, input: &mut PrecompileInput<'_>) -> Option<PrecompileResult> {
+        let selector: [u8; 4] = input.data.get(..4)?.try_into().ok()?;
+        if !VIEW_SELECTORS.contains(&selector) {
+            return None;
+        }
+
+        Some(
+            self.execute_view(input, selector)
+                .and_then(|output| Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, output))),
+        )
+    }
+
+    /// Execute the view function with the given selector, returning its ABI-encoded output.
+    fn execute_view(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        selector: [u8; 4],
+    ) -> Result<Bytes, PrecompileError> {
+        let data = input.data;
+        let args = data.get(4..).unwrap_or_default();
+
+        let output = match selector {
+            <wasProcessedCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<wasProcessedCall>(args, "wasProcessed")?;
+                let processed =
+                    self.sload(input, storage::processed_message_slot(decoded.messageId))?;
+                (!processed.is_zero()).abi_encode()
+            }
+            <totalMintedCall as SolCall>::SELECTOR => {
+                self.decode_args::<totalMintedCall>(args, "totalMinted")?;
+                self.sload(input, TOTAL_MINTED_SLOT)?.abi_encode()
+            }
+            <totalBurnedCall as SolCall>::SELECTOR => {
+                self.decode_args::<totalBurnedCall>(args, "totalBurned")?;
+                self.sload(input, TOTAL_BURNED_SLOT)?.abi_encode()
+            }
+            <totalSupplyCall as SolCall>::SELECTOR => {
+                self.decode_args::<totalSupplyCall>(args, "totalSupply")?;
+                let minted = self.sload(input, TOTAL_MINTED_SLOT)?;
+                let burned = self.sload(input, TOTAL_BURNED_SLOT)?;
+                minted.saturating_sub(burned).abi_encode()
+            }
+            <allowanceCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<allowanceCall>(args, "allowance")?;
+                self.sload(input, storage::allowance_slot(decoded.owner, decoded.spender))?
+                    .abi_encode()
+            }
+            <noncesCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<noncesCall>(args, "nonces")?;
+                self.sload(input, storage::nonce_slot(decoded.owner))?.abi_encode()
+            }
+            <mintedToCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintedToCall>(args, "mintedTo")?;
+                self.sload(input, storage::minted_to_slot(decoded.recipient))?.abi_encode()
+            }
+            <DOMAIN_SEPARATORCall as SolCall>::SELECTOR => {
+                self.decode_args::<DOMAIN_SEPARATORCall>(args, "DOMAIN_SEPARATOR")?;
+                self.eip712_domain().separator().abi_encode()
+            }
+            <decimalsCall as SolCall>::SELECTOR => {
+                self.decode_args::<decimalsCall>(args, "decimals")?;
+                U256::from(self.config.decimals).abi_encode()
+            }
+            <featuresCall as SolCall>::SELECTOR => {
+                self.decode_args::<featuresCall>(args, "features")?;
+                U256::from(self.features().bits()).abi_encode()
+            }
+            <versionCall as SolCall>::SELECTOR => {
+                self.decode_args::<versionCall>(args, "version")?;
+                LOGIC_VERSION.abi_encode()
+            }
+            <isPausedCall as SolCall>::SELECTOR => {
+                self.decode_args::<isPausedCall>(args, "isPaused")?;
+                let flags = self.sload(input, PAUSED_SLOT)?;
+                (!(flags & PAUSE_ALL).is_zero()).abi_encode()
+            }
+            <isMintPausedCall as SolCall>::SELECTOR => {
+                self.decode_args::<isMintPausedCall>(args, "isMintPaused")?;
+                self.is_paused(input, PausableOperation::Mint)?.abi_encode()
+            }
+            <isBurnPausedCall as SolCall>::SELECTOR => {
+                self.decode_args::<isBurnPausedCall>(args, "isBurnPaused")?;
+                self.is_paused(input, PausableOperation::Burn)?.abi_encode()
+            }
+            <approvalNonceCall as SolCall>::SELECTOR => {
+                self.decode_args::<approvalNonceCall>(args, "approvalNonce")?;
+                self.sload(input, APPROVAL_NONCE_SLOT)?.abi_encode()
+            }
+            <gasCostCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<gasCostCall>(args, "gasCost")?;
+                U256::from(self.base_gas_cost(input, Some(decoded.selector.0))?).abi_encode()
+            }
+            <bridgeCountCall as SolCall>::SELECTOR => {
+                self.decode_args::<bridgeCountCall>(args, "bridgeCount")?;
+                U256::from(self.authorized_bridges(input)?.len()).abi_encode()
+            }
+            <bridgeAtCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<bridgeAtCall>(args, "bridgeAt")?;
+                let bridges = self.authorized_bridges(input)?;
+                let index = usize::try_from(decoded.index).unwrap_or(usize::MAX);
+                let bridge =
+                    bridges.iter().nth(index).ok_or(NativeMinterError::BridgeIndexOutOfBounds)?;
+                bridge.abi_encode()
+            }
+            _ => return Err(NativeMinterError::UnknownFunction.into()),
+        };
+
+        Ok(output.into())
+    }
+
+    /// Authorize the caller and dispatch to the selected function.
+    fn dispatch(&self, input: &mut PrecompileInput<'_>) -> PrecompileResult {
+        // Security: Only the authorized bridge or a holder of the selector's role can call, or a
+        // delegated minter calling `mint`
+        let authorized_bridge = self.resolve_authorized_bridge(input)?;
+        let delegated = self.is_delegated_mint(input, authorized_bridge)?;
+        if !delegated {
+            self.authorize(input.caller, authorized_bridge, input.data)?;
+        }
+
+        match self.decode(input.caller, input.data)? {
+            SimulatedEffect::Mint { recipient, amount } => {
+                self.check_approval(input, recipient, amount, None)?;
+                if delegated {
+                    self.spend_mint_allowance(input, amount)?;
+                }
+                self.execute_mint(input, recipient, amount)
+            }
+            SimulatedEffect::MintWithMessage { recipient, amount, message_id, origin } => {
+                self.check_approval(input, recipient, amount, None)?;
+                self.execute_mint_with_message(input, recipient, amount, message_id, origin)
+            }
+            SimulatedEffect::MintWithApproval { recipient, amount, signature } => {
+                self.check_approval(input, recipient, amount, Some(&signature))?;
+                self.execute_mint(input, recipient, amount)
+            }
+            SimulatedEffect::MintBatch { recipients, amounts } => {
+                for (recipient, amount) in recipients.iter().zip(&amounts) {
+                    self.check_approval(input, *recipient, *amount, None)?;
+                }
+                self.execute_mint_batch(input, recipients, amounts)
+            }
+            SimulatedEffect::Burn { from, amount } => self.execute_burn(input, from, amount),
+            SimulatedEffect::BurnWithNonce { from, amount, nonce } => {
+                self.execute_burn_with_nonce(input, from, amount, nonce)
+            }
+            SimulatedEffect::MintBurnNet { account, delta } => {
+                if delta.is_negative() {
+                    self.execute_burn(input, account, delta.unsigned_abs())
+                } else {
+                    self.check_approval(input, account, delta.unsigned_abs(), None)?;
+                    self.execute_mint(input, account, delta.unsigned_abs())
+                }
+            }
+            SimulatedEffect::Approve { owner, spender, value } => {
+                self.execute_approve(input, owner, spender, value)
+            }
+            SimulatedEffect::Permit { owner, spender, value, deadline, signature } => {
+                self.execute_permit(input, owner, spender, value, deadline, signature)
+            }
+            SimulatedEffect::SetPaused(paused) => self.execute_set_paused(input, paused),
+            SimulatedEffect::SetOperationPaused { operation, paused } => {
+                self.execute_set_operation_paused(input, operation, paused)
+            }
+            SimulatedEffect::SetGasCost(gas_cost) => self.execute_set_gas_cost(input, gas_cost),
+            SimulatedEffect::ProposeBridge(new_bridge) => {
+                self.execute_propose_bridge(input, new_bridge)
+            }
+            SimulatedEffect::AcceptBridge => self.execute_accept_bridge(input),
+            SimulatedEffect::SetMintAllowance { minter, amount } => {
+                self.execute_set_mint_allowance(input, minter, amount)
+            }
+            SimulatedEffect::ScheduleEmergencyMint { recipient, amount } => {
+                self.execute_schedule_emergency_mint(input, recipient, amount)
+            }
+            SimulatedEffect::ExecuteEmergencyMint { recipient, amount } => {
+                self.execute_emergency_mint(input, recipient, amount)
+            }
+            SimulatedEffect::View => unreachable!("view functions are executed before dispatch"),
+        }
+    }
+
+    /// Runs the authorization and decoding of a call without executing it, returning the effect
+    /// it would have.
+    ///
+    /// This lets a relayer validate a call before submitting it. Only checks that don't depend on
+    /// state are performed: the caller is authorized against the configured bridge regardless of
+    /// the [`BridgeSource`], and the pause flag, processed messages, balances and rate limit are
+    /// not consulted. Delegated minters are rejected, as their mint allowance lives in state.
+    pub fn simulate(
+        &self,
+        caller: Address,
+        data: &[u8],
+        call_kind: CallKind,
+    ) -> Result<SimulatedEffect, NativeMinterError> {
+        match call_kind {
+            CallKind::DelegateCall => return Err(NativeMinterError::DelegateCall),
+            CallKind::CallCode => return Err(NativeMinterError::CallCode),
+            CallKind::Call | CallKind::StaticCall => {}
+        }
+
+        if data.get(..4).is_some_and(|selector| VIEW_SELECTORS.iter().any(|view| view == selector))
+        {
+            return Ok(SimulatedEffect::View);
+        }
+
+        if call_kind == CallKind::StaticCall {
+            return Err(NativeMinterError::StaticCall);
+        }
+
+        self.authorize(caller, self.config.authorized_bridge, data)?;
+
+        let effect = self.decode(caller, data)?;
+        if let SimulatedEffect::MintBatch { recipients, amounts } = &effect {
+            self.check_batch(recipients, amounts)?;
+        }
+        Ok(effect)
+    }
+
+    /// Returns the exact output bytes of a successful call with the given effect, so other
+    /// implementations of the precompile can be diff-tested against this one.
+    ///
+    /// `balance` is the balance of the burned account before a burn, and is ignored for other
+    /// effects. Outputs are laid out as follows:
+    ///
+    /// - `burn` with [`BurnMode::Revert`]: empty, or `true` ABI-encoded as a 32-byte `bool` if
+    ///   [`return_bool`](Self::return_bool) is set
+    /// - `burn` with [`BurnMode::Clamp`]: the burned amount, `min(amount, balance)`, as a 32-byte
+    ///   big-endian `uint256`
+    /// - all mints: empty, or `true` if [`return_bool`](Self::return_bool) is set
+    /// - single-recipient mints with [`saturating_mint`](Self::saturating_mint): the amount
+    ///   credited as a 32-byte `uint256`
+    /// - `burn` with a nonce: as `burn`
+    /// - `mintBurnNet`: as a burn of the delta's magnitude if negative, as a mint otherwise
+    /// - `approve`: `true` ABI-encoded as a 32-byte `bool`, or the allowance set as a 32-byte
+    ///   `uint256` if [`allowance_output`](Self::allowance_output) is set
+    /// - every other state-modifying function: empty
+    ///
+    /// Returns `None` for [`SimulatedEffect::View`], whose output depends on state, for burns if
+    /// both [`allowance_output`](Self::allowance_output) and
+    /// [`burn_allowance_required`](Self::burn_allowance_required) are set, whose output depends
+    /// on the caller and its remaining allowance, and for burns if
+    /// [`burn_summary_output`](Self::burn_summary_output) is set, whose output depends on the
+    /// total burned, and for single-recipient mints if
+    /// [`saturating_mint`](Self::saturating_mint) is set, whose output depends on the recipient's
+    /// balance.
+    pub fn expected_output(&self, effect: &SimulatedEffect, balance: U256) -> Option<Bytes> {
+        let is_burn = match effect {
+            SimulatedEffect::Burn { .. } | SimulatedEffect::BurnWithNonce { .. } => true,
+            SimulatedEffect::MintBurnNet { delta, .. } => delta.is_negative(),
+            _ => false,
+        };
+        if is_burn
+            && (self.config.burn_summary_output
+                || (self.config.allowance_output && self.config.burn_allowance_required))
+        {
+            return None;
+        }
+        let single_mint = matches!(
+            effect,
+            SimulatedEffect::Mint { .. }
+                | SimulatedEffect::MintWithMessage { .. }
+                | SimulatedEffect::MintWithApproval { .. }
+                | SimulatedEffect::MintBurnNet { .. }
+                | SimulatedEffect::ExecuteEmergencyMint { .. }
+        );
+        if self.config.saturating_mint && single_mint && !is_burn {
+            return None;
+        }
+
+        let output = match effect {
+            SimulatedEffect::Burn { amount, .. }
+            | SimulatedEffect::BurnWithNonce { amount, .. } => match self.config.burn_mode {
+                BurnMode::Clamp => self.burn_output((*amount).min(balance)),
+                BurnMode::Revert => self.burn_output(*amount),
+            },
+            SimulatedEffect::MintBurnNet { delta, .. } if delta.is_negative() => {
+                let amount = delta.unsigned_abs();
+                match self.config.burn_mode {
+                    BurnMode::Clamp => self.burn_output(amount.min(balance)),
+                    BurnMode::Revert => self.burn_output(amount),
+                }
+            }
+            SimulatedEffect::Mint { .. }
+            | SimulatedEffect::MintWithMessage { .. }
+            | SimulatedEffect::MintWithApproval { .. }
+            | SimulatedEffect::MintBatch { .. }
+            | SimulatedEffect::MintBurnNet { .. }
+            | SimulatedEffect::ExecuteEmergencyMint { .. } => self.mint_output(),
+            SimulatedEffect::Approve { value, .. } => self.approve_output(*value),
+            SimulatedEffect::Permit { .. }
+            | SimulatedEffect::SetPaused(_)
+            | SimulatedEffect::SetOperationPaused { .. }
+            | SimulatedEffect::SetGasCost(_)
+            | SimulatedEffect::ProposeBridge(_)
+            | SimulatedEffect::AcceptBridge
+            | SimulatedEffect::SetMintAllowance { .. }
+            | SimulatedEffect::ScheduleEmergencyMint { .. } => Bytes::new(),
+            SimulatedEffect::View => return None,
+        };
+        Some(output)
+    }
+
+    /// Reverts unless `caller` is the authorized bridge or holds the role required by the
+    /// function selected by `data`.
+    fn authorize(
+        &self,
+        caller: Address,
+        authorized_bridge: Address,
+        data: &[u8],
+    ) -> Result<(), NativeMinterError> {
+        // Defensive: the precompile never calls itself, so a caller at one of its addresses can
+        // only come from a misconfigured bridge
+        if self.addresses().any(|address| address == caller) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %caller,
+                "NativeMinter: caller is the NativeMinter itself"
+            );
+            return Err(NativeMinterError::Unauthorized);
+        }
+
+        if data.get(..4).is_some_and(|selector| PUBLIC_SELECTORS.iter().any(|s| s == selector)) {
+            return Ok(());
+        }
+
+        if data.get(..4).is_some_and(|selector| EMERGENCY_SELECTORS.iter().any(|s| s == selector)) {
+            let owner = self.config.emergency_mint.map(|emergency_mint| emergency_mint.owner);
+            if owner != Some(caller) {
+                reject!(
+                    self,
+                    target: "rkb::native_minter",
+                    %caller,
+                    owner = ?self.redact(owner),
+                    "NativeMinter: unauthorized caller"
+                );
+                return Err(NativeMinterError::Unauthorized);
+            }
+            return Ok(());
+        }
+
+        if data.get(..4).is_some_and(|selector| GOVERNOR_SELECTORS.iter().any(|s| s == selector)) {
+            if self.config.governor != Some(caller) {
+                reject!(
+                    self,
+                    target: "rkb::native_minter",
+                    %caller,
+                    governor = ?self.redact(self.config.governor),
+                    "NativeMinter: unauthorized caller"
+                );
+                return Err(NativeMinterError::Unauthorized);
+            }
+            return Ok(());
+        }
+
+        let role = data.get(..4).and_then(|selector| Role::for_selector(selector.try_into().ok()?));
+        let has_role = role.is_some_and(|role| self.role_members(role).contains(&caller));
+        if caller != authorized_bridge && !has_role {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %caller,
+                authorized = %self.redact(authorized_bridge),
+                ?role,
+                "NativeMinter: unauthorized caller"
+            );
+            return Err(NativeMinterError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Decodes the arguments of the function `name`, rejecting trailing bytes after them in
+    /// strict mode.
+    fn decode_args<C: SolCall>(
+        &self,
+        args: &[u8],
+        name: &'static str,
+    ) -> Result<C, NativeMinterError> {
+        let decoded = C::abi_decode_raw(args).map_err(|_| NativeMinterError::InvalidArgs(name))?;
+        if self.config.strict_calldata && args.len() != decoded.abi_encoded_size() {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                function = name,
+                len = args.len(),
+                expected = decoded.abi_encoded_size(),
+                "NativeMinter: unexpected trailing calldata"
+            );
+            return Err(NativeMinterError::UnexpectedTrailingCalldata);
+        }
+        Ok(decoded)
+    }
+
+    /// Decodes the calldata of a state-modifying function called by `caller` into the effect it
+    /// has.
+    fn decode(&self, caller: Address, data: &[u8]) -> Result<SimulatedEffect, NativeMinterError> {
+        // Need at least 4 bytes for function selector
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Err(NativeMinterError::InvalidCalldataLength);
+        };
+
+        let effect = match *selector {
+            // mint(address,uint256) selector: 0x40c10f19
+            <mintCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintCall>(args, "mint")?;
+                SimulatedEffect::Mint { recipient: decoded.recipient, amount: decoded.amount }
+            }
+            // burn(address,uint256) selector: 0x9dc29fac
+            <burn_0Call as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<burn_0Call>(args, "burn")?;
+                SimulatedEffect::Burn { from: decoded.from, amount: decoded.amount }
+            }
+            <burn_1Call as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<burn_1Call>(args, "burn")?;
+                SimulatedEffect::BurnWithNonce {
+                    from: decoded.from,
+                    amount: decoded.amount,
+                    nonce: decoded.nonce,
+                }
+            }
+            <mintBurnNetCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintBurnNetCall>(args, "mintBurnNet")?;
+                SimulatedEffect::MintBurnNet { account: decoded.account, delta: decoded.delta }
+            }
+            <mintWithMessageCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintWithMessageCall>(args, "mintWithMessage")?;
+                SimulatedEffect::MintWithMessage {
+                    recipient: decoded.recipient,
+                    amount: decoded.amount,
+                    message_id: decoded.messageId,
+                    origin: None,
+                }
+            }
+            <mintWithMessageFromCall as SolCall>::SELECTOR => {
+                let decoded =
+                    self.decode_args::<mintWithMessageFromCall>(args, "mintWithMessageFrom")?;
+                SimulatedEffect::MintWithMessage {
+                    recipient: decoded.recipient,
+                    amount: decoded.amount,
+                    message_id: decoded.messageId,
+                    origin: Some(decoded.origin),
+                }
+            }
+            <mintBatchCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintBatchCall>(args, "mintBatch")?;
+                SimulatedEffect::MintBatch {
+                    recipients: decoded.recipients,
+                    amounts: decoded.amounts,
+                }
+            }
+            <mintToSelfCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintToSelfCall>(args, "mintToSelf")?;
+                SimulatedEffect::Mint { recipient: caller, amount: decoded.amount }
+            }
+            <mintWithApprovalCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintWithApprovalCall>(args, "mintWithApproval")?;
+                SimulatedEffect::MintWithApproval {
+                    recipient: decoded.recipient,
+                    amount: decoded.amount,
+                    signature: decoded.approverSig,
+                }
+            }
+            <approveCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<approveCall>(args, "approve")?;
+                SimulatedEffect::Approve {
+                    owner: caller,
+                    spender: decoded.spender,
+                    value: decoded.value,
+                }
+            }
+            <permitCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<permitCall>(args, "permit")?;
+                let y_parity = match decoded.v {
+                    27 => false,
+                    28 => true,
+                    _ => return Err(NativeMinterError::InvalidSignature),
+                };
+                SimulatedEffect::Permit {
+                    owner: decoded.owner,
+                    spender: decoded.spender,
+                    value: decoded.value,
+                    deadline: decoded.deadline,
+                    signature: Signature::new(decoded.r.into(), decoded.s.into(), y_parity),
+                }
+            }
+            <pauseCall as SolCall>::SELECTOR => {
+                self.decode_args::<pauseCall>(args, "pause")?;
+                SimulatedEffect::SetPaused(true)
+            }
+            <unpauseCall as SolCall>::SELECTOR => {
+                self.decode_args::<unpauseCall>(args, "unpause")?;
+                SimulatedEffect::SetPaused(false)
+            }
+            <pauseMintCall as SolCall>::SELECTOR => {
+                self.decode_args::<pauseMintCall>(args, "pauseMint")?;
+                SimulatedEffect::SetOperationPaused {
+                    operation: PausableOperation::Mint,
+                    paused: true,
+                }
+            }
+            <unpauseMintCall as SolCall>::SELECTOR => {
+                self.decode_args::<unpauseMintCall>(args, "unpauseMint")?;
+                SimulatedEffect::SetOperationPaused {
+                    operation: PausableOperation::Mint,
+                    paused: false,
+                }
+            }
+            <pauseBurnCall as SolCall>::SELECTOR => {
+                self.decode_args::<pauseBurnCall>(args, "pauseBurn")?;
+                SimulatedEffect::SetOperationPaused {
+                    operation: PausableOperation::Burn,
+                    paused: true,
+                }
+            }
+            <unpauseBurnCall as SolCall>::SELECTOR => {
+                self.decode_args::<unpauseBurnCall>(args, "unpauseBurn")?;
+                SimulatedEffect::SetOperationPaused {
+                    operation: PausableOperation::Burn,
+                    paused: false,
+                }
+            }
+            <setGasCostCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<setGasCostCall>(args, "setGasCost")?;
+                SimulatedEffect::SetGasCost(decoded.gasCost)
+            }
+            <proposeBridgeCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<proposeBridgeCall>(args, "proposeBridge")?;
+                SimulatedEffect::ProposeBridge(decoded.newBridge)
+            }
+            <acceptBridgeCall as SolCall>::SELECTOR => {
+                self.decode_args::<acceptBridgeCall>(args, "acceptBridge")?;
+                SimulatedEffect::AcceptBridge
+            }
+            <mintAllowanceCall as SolCall>::SELECTOR => {
+                let decoded = self.decode_args::<mintAllowanceCall>(args, "mintAllowance")?;
+                SimulatedEffect::SetMintAllowance { minter: decoded.minter, amount: decoded.amount }
+            }
+            <scheduleEmergencyMintCall as SolCall>::SELECTOR => {
+                let decoded =
+                    self.decode_args::<scheduleEmergencyMintCall>(args, "scheduleEmergencyMint")?;
+                SimulatedEffect::ScheduleEmergencyMint {
+                    recipient: decoded.recipient,
+                    amount: decoded.amount,
+                }
+            }
+            <executeEmergencyMintCall as SolCall>::SELECTOR => {
+                let decoded =
+                    self.decode_args::<executeEmergencyMintCall>(args, "executeEmergencyMint")?;
+                SimulatedEffect::ExecuteEmergencyMint {
+                    recipient: decoded.recipient,
+                    amount: decoded.amount,
+                }
+            }
+            _ => {
+                reject!(
+                    self,
+                    target: "rkb::native_minter",
+                    selector = ?selector,
+                    "NativeMinter: unknown function selector"
+                );
+                return Err(NativeMinterError::UnknownFunction);
+            }
+        };
+
+        Ok(effect)
+    }
+
+    /// Returns the authorized bridges: the bridge enforced for this call and the additional
+    /// bridges.
+    fn authorized_bridges(
+        &self,
+        input: &mut PrecompileInput<'_>,
+    ) -> Result<BTreeSet<Address>, PrecompileError> {
+        let mut bridges = self.config.additional_bridges.clone();
+        bridges.insert(self.resolve_authorized_bridge(input)?);
+        Ok(bridges)
+    }
+
+    /// Returns the bridge address enforced for this call, according to the [`BridgeSource`].
+    fn resolve_authorized_bridge(
+        &self,
+        input: &mut PrecompileInput<'_>,
+    ) -> Result<Address, PrecompileError> {
+        match self.config.bridge_source {
+            BridgeSource::Config => Ok(self.config.authorized_bridge),
+            BridgeSource::State => {
+                let bridge = self.sload_address(input, AUTHORIZED_BRIDGE_SLOT)?;
+                if bridge.is_zero() {
+                    return Ok(self.config.authorized_bridge);
+                }
+                Ok(bridge)
+            }
+        }
+    }
+
+    /// Reverts if the current block is below the activation block.
+    fn ensure_active(&self, input: &PrecompileInput<'_>) -> Result<(), PrecompileError> {
+        let Some(activation_block) = self.config.activation_block else {
+            return Ok(());
+        };
+
+        let block_number = input.internals().block_number();
+        if block_number < U256::from(activation_block) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %block_number,
+                activation_block,
+                "NativeMinter: not yet active"
+            );
+            return Err(NativeMinterError::NotYetActive.into());
+        }
+        Ok(())
+    }
+
+    /// Reverts if the base fee of the current block is below the minimum for minting.
+    fn ensure_min_base_fee(&self, input: &PrecompileInput<'_>) -> Result<(), PrecompileError> {
+        let Some(min_base_fee) = self.config.min_base_fee else {
+            return Ok(());
+        };
+
+        let base_fee = input.internals().block_env().basefee();
+        if u128::from(base_fee) < min_base_fee {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                base_fee,
+                min_base_fee,
+                "NativeMinter: base fee below minimum"
+            );
+            return Err(NativeMinterError::BaseFeeTooLow.into());
+        }
+        Ok(())
+    }
+
+    /// Returns whether `operation` is paused, individually or along with every other operation.
+    fn is_paused(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        operation: PausableOperation,
+    ) -> Result<bool, PrecompileError> {
+        let flags = self.sload(input, PAUSED_SLOT)?;
+        Ok(!(flags & (PAUSE_ALL | operation.pause_flag())).is_zero())
+    }
+
+    /// Reverts if `operation` is paused.
+    fn ensure_not_paused(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        operation: PausableOperation,
+    ) -> Result<(), PrecompileError> {
+        if self.is_paused(input, operation)? {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                caller = %input.caller,
+                ?operation,
+                "NativeMinter: paused"
+            );
+            return Err(NativeMinterError::Paused.into());
+        }
+        Ok(())
+    }
+
+    /// Records `amount` against the rate limit window of the current block timestamp, reverting if
+    /// it would exceed the limit.
+    fn consume_rate_limit(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        amount: U256,
+    ) -> Result<(), PrecompileError> {
+        let Some(rate_limit) = self.config.rate_limit else {
+            return Ok(());
+        };
+
+        let timestamp = input.internals().block_timestamp();
+        let window_start = self.sload(input, RATE_LIMIT_START_SLOT)?;
+        let minted = if timestamp >= window_start.saturating_add(U256::from(rate_limit.window_secs))
+        {
+            self.sstore(input, RATE_LIMIT_START_SLOT, timestamp)?;
+            U256::ZERO
+        } else {
+            self.sload(input, RATE_LIMIT_MINTED_SLOT)?
+        };
+
+        let minted = minted.saturating_add(amount);
+        if minted > rate_limit.max_amount {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %amount,
+                %timestamp,
+                %window_start,
+                "NativeMinter: rate limit exceeded"
+            );
+            return Err(NativeMinterError::RateLimitExceeded.into());
+        }
+
+        self.sstore(input, RATE_LIMIT_MINTED_SLOT, minted)
+    }
+
+    /// Execute the mintAllowance operation - set the amount `minter` may mint as a delegated
+    /// minter.
+    fn execute_set_mint_allowance(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        minter: Address,
+        amount: U256,
+    ) -> PrecompileResult {
+        debug!(
+            target: "rkb::native_minter",
+            %minter,
+            %amount,
+            "Setting NativeMinter mint allowance"
+        );
+
+        self.sstore(input, storage::mint_allowance_slot(minter), amount)?;
+
+        Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, Bytes::new()))
+    }
+
+    /// Execute the approve operation - set the amount `spender` may burn from `owner`.
+    fn execute_approve(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        owner: Address,
+        spender: Address,
+        value: U256,
+    ) -> PrecompileResult {
+        debug!(
+            target: "rkb::native_minter",
+            %owner,
+            %spender,
+            %value,
+            "Setting NativeMinter burn allowance"
+        );
+
+        self.sstore(input, storage::allowance_slot(owner, spender), value)?;
+
+        Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, self.approve_output(value)))
+    }
+
+    /// Execute the permit operation - verify the owner's signature and set the allowance.
+    fn execute_permit(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        signature: Signature,
+    ) -> PrecompileResult {
+        if input.internals().block_timestamp() > deadline {
+            return Err(NativeMinterError::PermitExpired.into());
+        }
+
+        let nonce_slot = storage::nonce_slot(owner);
+        let nonce = self.sload(input, nonce_slot)?;
+        let hash = Permit { owner, spender, value, nonce, deadline }
+            .eip712_signing_hash(&self.eip712_domain());
+
+        let signer = match signature.normalize_s() {
+            // Reject malleable signatures with a high `s`, as `ecrecover` based implementations do
+            Some(_) => None,
+            None => signature.recover_address_from_prehash(&hash).ok(),
+        };
+        if signer != Some(owner) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %owner,
+                ?signer,
+                "NativeMinter: invalid permit signature"
+            );
+            return Err(NativeMinterError::InvalidSignature.into());
+        }
+
+        self.sstore(input, nonce_slot, nonce + U256::from(1))?;
+
+        self.execute_approve(input, owner, spender, value)
+            .map(|output| PrecompileOutput::new(output.gas_used, Bytes::new()))
+    }
+
+    /// Reverts if a mint of `amount` to `recipient` needs an approval and `signature` isn't a valid
+    /// one, consuming the approval nonce if it is.
+    fn check_approval(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+        signature: Option<&Bytes>,
+    ) -> Result<(), PrecompileError> {
+        let Some(mint_approver) = self.config.mint_approver.filter(|gate| amount > gate.threshold)
+        else {
+            return Ok(());
+        };
+        let Some(signature) = signature else {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                %amount,
+                "NativeMinter: approval required"
+            );
+            return Err(NativeMinterError::ApprovalRequired.into());
+        };
+
+        let nonce = self.sload(input, APPROVAL_NONCE_SLOT)?;
+        let hash =
+            MintApproval { recipient, amount, nonce }.eip712_signing_hash(&self.eip712_domain());
+
+        let signer = Signature::from_raw(signature)
+            .ok()
+            // Reject malleable signatures with a high `s`, as for permits
+            .filter(|signature| signature.normalize_s().is_none())
+            .and_then(|signature| signature.recover_address_from_prehash(&hash).ok());
+        if signer != Some(mint_approver.approver) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                %amount,
+                ?signer,
+                "NativeMinter: invalid approval signature"
+            );
+            return Err(NativeMinterError::InvalidSignature.into());
+        }
+
+        self.sstore(input, APPROVAL_NONCE_SLOT, nonce + U256::from(1))
+    }
+
+    /// Consumes `amount` of the allowance `owner` granted to the caller, returning the remaining
+    /// allowance.
+    ///
+    /// An allowance of `type(uint256).max` is never consumed.
+    fn spend_allowance(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        owner: Address,
+        amount: U256,
+    ) -> Result<U256, PrecompileError> {
+        let slot = storage::allowance_slot(owner, input.caller);
+        let allowance = self.sload(input, slot)?;
+        if allowance == U256::MAX {
+            return Ok(allowance);
+        }
+
+        let Some(remaining) = allowance.checked_sub(amount) else {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %owner,
+                spender = %input.caller,
+                %amount,
+                %allowance,
+                "NativeMinter: insufficient allowance for burn"
+            );
+            return Err(NativeMinterError::InsufficientAllowance.into());
+        };
+        self.sstore(input, slot, remaining)?;
+        Ok(remaining)
+    }
+
+    /// Returns whether the call is a `mint` by a delegated minter: a caller that is neither the
+    /// authorized bridge nor a [`Role::Minter`], but holds a mint allowance.
+    fn is_delegated_mint(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        authorized_bridge: Address,
+    ) -> Result<bool, PrecompileError> {
+        let caller = input.caller;
+        if input.data.get(..4) != Some(<mintCall as SolCall>::SELECTOR.as_slice())
+            || caller == authorized_bridge
+            || self.role_members(Role::Minter).contains(&caller)
+            || self.addresses().any(|address| address == caller)
+        {
+            return Ok(false);
+        }
+
+        Ok(!self.sload(input, storage::mint_allowance_slot(caller))?.is_zero())
+    }
+
+    /// Consumes `amount` of the caller's mint allowance, reverting if it has insufficient
+    /// allowance.
+    fn spend_mint_allowance(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        amount: U256,
+    ) -> Result<(), PrecompileError> {
+        let slot = storage::mint_allowance_slot(input.caller);
+        let allowance = self.sload(input, slot)?;
+        let Some(remaining) = allowance.checked_sub(amount) else {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                minter = %input.caller,
+                %amount,
+                %allowance,
+                "NativeMinter: mint allowance exceeded"
+            );
+            return Err(NativeMinterError::MintAllowanceExceeded.into());
+        };
+        self.sstore(input, slot, remaining)
+    }
+
+    /// Reverts if deduplication is enabled and `amount` was already minted to `recipient` in this
+    /// transaction, recording the mint otherwise.
+    fn ensure_not_minted_in_tx(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<(), PrecompileError> {
+        if !self.config.mint_dedup {
+            return Ok(());
+        }
+
+        let slot = storage::minted_in_tx_slot(recipient, amount);
+        if !storage::tload(input, slot).is_zero() {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                %amount,
+                "NativeMinter: duplicate mint in tx"
+            );
+            return Err(NativeMinterError::DuplicateMint.into());
+        }
+        storage::tstore(input, slot, U256::from(1));
+        Ok(())
+    }
+
+    /// Execute the pause/unpause operation.
+    fn execute_set_paused(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        paused: bool,
+    ) -> PrecompileResult {
+        debug!(
+            target: "rkb::native_minter",
+            caller = %input.caller,
+            paused,
+            "Setting NativeMinter pause state"
+        );
+
+        // Resuming clears the flags of individually paused operations too
+        let flags = if paused { self.sload(input, PAUSED_SLOT)? | PAUSE_ALL } else { U256::ZERO };
+        self.set_config(input, PAUSED_SLOT, flags)?;
+
+        Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, Bytes::new()))
+    }
+
+    /// Execute the pauseMint/unpauseMint/pauseBurn/unpauseBurn operations.
+    fn execute_set_operation_paused(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        operation: PausableOperation,
+        paused: bool,
+    ) -> PrecompileResult {
+        debug!(
+            target: "rkb::native_minter",
+            caller = %input.caller,
+            ?operation,
+            paused,
+            "Setting NativeMinter operation pause state"
+        );
+
+        let flags = self.sload(input, PAUSED_SLOT)?;
+        let flags =
+            if paused { flags | operation.pause_flag() } else { flags & !operation.pause_flag() };
+        self.set_config(input, PAUSED_SLOT, flags)?;
+
+        Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, Bytes::new()))
+    }
+
+    /// Execute the setGasCost operation - set the gas charged per call.
+    ///
+    /// The call itself is charged the cost in effect before it.
+    fn execute_set_gas_cost(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        gas_cost: u64,
+    ) -> PrecompileResult {
+        let gas_used = self.gas_cost(input, 1)?;
+
+        warn!(
+            target: "rkb::native_minter",
+            caller = %input.caller,
+            gas_cost,
+            "Setting NativeMinter gas cost"
+        );
+
+        self.set_config(input, GAS_COST_SLOT, U256::from(gas_cost))?;
+
+        Ok(PrecompileOutput::new(gas_used, Bytes::new()))
+    }
+
+    /// Execute the proposeBridge operation - record the proposed bridge until it accepts.
+    fn execute_propose_bridge(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        new_bridge: Address,
+    ) -> PrecompileResult {
+        // With the bridge sourced from the configuration, a rotation would silently have no effect
+        if self.config.bridge_source != BridgeSource::State {
+            return Err(NativeMinterError::BridgeNotInState.into());
+        }
+
+        warn!(
+            target: "rkb::native_minter",
+            caller = %input.caller,
+            %new_bridge,
+            "Proposing new NativeMinter bridge"
+        );
+
+        self.set_config(input, PENDING_BRIDGE_SLOT, storage::address_to_word(new_bridge))?;
+
+        Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, Bytes::new()))
+    }
+
+    /// Execute the acceptBridge operation - make the proposed bridge the authorized bridge.
+    fn execute_accept_bridge(&self, input: &mut PrecompileInput<'_>) -> PrecompileResult {
+        let pending_bridge = self.sload_address(input, PENDING_BRIDGE_SLOT)?;
+        if pending_bridge.is_zero() || pending_bridge != input.caller {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                caller = %input.caller,
+                pending_bridge = %self.redact(pending_bridge),
+                "NativeMinter: unauthorized caller"
+            );
+            return Err(NativeMinterError::Unauthorized.into());
+        }
+
+        warn!(
+            target: "rkb::native_minter",
+            new_bridge = %pending_bridge,
+            "Rotating NativeMinter bridge"
+        );
+
+        // Report the bridge in effect before, as the slot is unset until the first rotation
+        let old_bridge = self.resolve_authorized_bridge(input)?;
+        self.sstore(input, AUTHORIZED_BRIDGE_SLOT, storage::address_to_word(pending_bridge))?;
+        self.sstore(input, PENDING_BRIDGE_SLOT, U256::ZERO)?;
+        emit(
+            input,
+            ConfigChanged {
+                key: AUTHORIZED_BRIDGE_SLOT.into(),
+                oldValue: old_bridge.into_word(),
+                newValue: pending_bridge.into_word(),
+            },
+        );
+
+        Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, Bytes::new()))
+    }
+
+    /// Execute the mint operation - credit native tokens to recipient.
+    fn execute_mint(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+    ) -> PrecompileResult {
+        self.mint_with_events(input, recipient, amount, true)
+    }
+
+    /// Mints `amount` to `recipient`, emitting the events of the [`EventScheme`] if
+    /// `emit_events` is set.
+    fn mint_with_events(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+        emit_events: bool,
+    ) -> PrecompileResult {
+        self.ensure_active(input)?;
+        self.ensure_min_base_fee(input)?;
+        self.ensure_not_paused(input, PausableOperation::Mint)?;
+        self.ensure_within_call_cap(amount)?;
+        self.ensure_not_precompile(recipient)?;
+        self.ensure_recipient_exists(input, recipient)?;
+        self.count_recipient(input, recipient)?;
+        self.enforce_mint_cooldown(input, recipient)?;
+        self.ensure_not_minted_in_tx(input, recipient, amount)?;
+        self.consume_rate_limit(input, amount)?;
+        self.ensure_within_supply_cap(input, amount)?;
+        self.update_breaker(input, amount)?;
+
+        let fee = self.config.mint_fee.map(|fee| fee.of(amount)).unwrap_or_default();
+
+        debug!(
+            target: "rkb::native_minter",
+            %recipient,
+            %amount,
+            %fee,
+            "Minting native tokens"
+        );
+
+        let Some(net_amount) = amount.checked_sub(fee) else {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %amount,
+                %fee,
+                "NativeMinter: fee exceeds amount"
+            );
+            return Err(NativeMinterError::FeeExceedsAmount.into());
+        };
+        let credited = self.creditable(input, recipient, net_amount)?;
+        self.credit(input, recipient, credited, emit_events)?;
+        let mut minted = credited;
+        if let Some(mint_fee) = self.config.mint_fee.filter(|_| !fee.is_zero()) {
+            let fee = self.creditable(input, mint_fee.recipient, fee)?;
+            self.credit(input, mint_fee.recipient, fee, emit_events)?;
+            minted += fee;
+        }
+
+        self.sadd(input, TOTAL_MINTED_SLOT, minted)?;
+
+        let gas_used = self.gas_cost(input, 1)?;
+        self.send_record(MintBurnRecord::Mint { recipient, amount: minted });
+        let output = if self.config.saturating_mint {
+            credited.abi_encode().into()
+        } else {
+            self.mint_output()
+        };
+        Ok(PrecompileOutput::new(gas_used, output))
+    }
+
+    /// Returns how much of `amount` can be credited to `recipient` without overflowing its
+    /// balance: all of it, or up to `U256::MAX` under saturating mints. Reverts otherwise.
+    fn creditable(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<U256, PrecompileError> {
+        let headroom = U256::MAX - self.balance_of(input, recipient)?;
+        if amount <= headroom {
+            return Ok(amount);
+        }
+
+        if !self.config.saturating_mint {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                %amount,
+                %headroom,
+                "NativeMinter: balance overflow"
+            );
+            return Err(NativeMinterError::BalanceOverflow.into());
+        }
+
+        warn!(
+            target: "rkb::native_minter",
+            %recipient,
+            %amount,
+            credited = %headroom,
+            "NativeMinter: mint saturating the recipient's balance"
+        );
+        Ok(headroom)
+    }
+
+    /// Execute the scheduleEmergencyMint operation - record the block timestamp an emergency mint
+    /// was scheduled at.
+    ///
+    /// Scheduling an already scheduled mint restarts its timelock.
+    fn execute_schedule_emergency_mint(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+    ) -> PrecompileResult {
+        let timestamp = input.internals().block_timestamp();
+
+        warn!(
+            target: "rkb::native_minter",
+            %recipient,
+            %amount,
+            %timestamp,
+            "Emergency mint scheduled"
+        );
+
+        self.sstore(input, storage::emergency_mint_slot(recipient, amount), timestamp)?;
+
+        Ok(PrecompileOutput::new(self.gas_cost(input, 1)?, Bytes::new()))
+    }
+
+    /// Execute the executeEmergencyMint operation - mint a scheduled emergency mint once its
+    /// timelock has elapsed.
+    fn execute_emergency_mint(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+    ) -> PrecompileResult {
+        let Some(emergency_mint) = self.config.emergency_mint else {
+            return Err(NativeMinterError::Unauthorized.into());
+        };
+
+        let slot = storage::emergency_mint_slot(recipient, amount);
+        let scheduled_at = self.sload(input, slot)?;
+        if scheduled_at.is_zero() {
+            return Err(NativeMinterError::EmergencyMintNotScheduled.into());
+        }
+
+        let timestamp = input.internals().block_timestamp();
+        if timestamp < scheduled_at.saturating_add(U256::from(emergency_mint.delay_secs)) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                %amount,
+                %scheduled_at,
+                %timestamp,
+                "NativeMinter: emergency mint timelocked"
+            );
+            return Err(NativeMinterError::EmergencyMintTimelocked.into());
+        }
+
+        self.sstore(input, slot, U256::ZERO)?;
+
+        self.execute_mint(input, recipient, amount)
+    }
+
+    /// Increments the balance of `recipient` by `amount`, unless running in observe-only mode.
+    ///
+    /// The recipient is credited silently, even if it is a contract, see the
+    /// [module docs](self#contract-recipients).
+    fn credit(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+        emit_events: bool,
+    ) -> Result<(), PrecompileError> {
+        if self.config.observe_only {
+            debug!(target: "rkb::native_minter", %recipient, "Observe-only: skipping mint");
+        } else {
+            match self.config.mint_target {
+                // Use EvmInternals to increment the recipient's balance
+                MintTarget::Native => {
+                    input.internals_mut().balance_incr(recipient, amount).map_err(|e| {
+                        PrecompileError::other(format!("NativeMinter: mint failed: {e}"))
+                    })?
+                }
+                MintTarget::Erc20(target) => {
+                    let balance = self.balance_of(input, recipient)?;
+                    let new_balance = balance.checked_add(amount).ok_or_else(|| {
+                        PrecompileError::other_static("NativeMinter: mint failed")
+                    })?;
+                    self.set_erc20_balance(input, target, recipient, new_balance)?;
+                    self.update_erc20_supply(input, target, Address::ZERO, recipient, amount)?;
+                }
+            }
+        }
+
+        if self.config.track_minted_to {
+            self.sadd(input, storage::minted_to_slot(recipient), amount)?;
+        }
+
+        if let Some(scheme) = self.config.event_scheme.filter(|_| emit_events) {
+            if scheme.emits_native() {
+                emit(input, Mint { recipient, amount });
+            }
+            if scheme.emits_erc20_transfer() {
+                emit(input, Transfer { from: Address::ZERO, to: recipient, value: amount });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reverts if burning from nonexistent accounts is rejected and `from` doesn't exist.
+    fn ensure_burn_account_exists(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        from: Address,
+    ) -> Result<(), PrecompileError> {
+        if !self.config.reject_missing_account_burn
+            || !matches!(self.config.mint_target, MintTarget::Native)
+        {
+            return Ok(());
+        }
+
+        let account = input.internals_mut().load_account(from).map_err(|e| {
+            PrecompileError::other(format!("NativeMinter: load account failed: {e}"))
+        })?;
+        if account.data.info.is_empty() {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %from,
+                "NativeMinter: account does not exist"
+            );
+            return Err(NativeMinterError::AccountNotFound.into());
+        }
+        Ok(())
+    }
+
+    /// Reverts if burns by the bridge are restricted to the transaction origin, the caller is an
+    /// authorized bridge and `from` is not the origin.
+    fn ensure_origin_burn(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        from: Address,
+    ) -> Result<(), PrecompileError> {
+        if !self.config.require_origin_burn
+            || !self.authorized_bridges(input)?.contains(&input.caller)
+        {
+            return Ok(());
+        }
+
+        let origin = input.internals().tx_origin();
+        if from != origin {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %from,
+                %origin,
+                "NativeMinter: burn not from transaction origin"
+            );
+            return Err(NativeMinterError::BurnNotFromOrigin.into());
+        }
+        Ok(())
+    }
+
+    /// Credits the value attached to a direct call back to the caller, see [`ValueMode::Refund`].
+    fn refund_value(&self, input: &mut PrecompileInput<'_>) -> Result<(), PrecompileError> {
+        let (holder, caller, value) = (input.target_address, input.caller, input.value);
+        let refund_failed =
+            |e| PrecompileError::other(format!("NativeMinter: value refund failed: {e}"));
+
+        let balance =
+            input.internals_mut().load_account(holder).map_err(refund_failed)?.data.info.balance;
+        input
+            .internals_mut()
+            .set_balance(holder, balance.saturating_sub(value))
+            .map_err(refund_failed)?;
+        input.internals_mut().balance_incr(caller, value).map_err(refund_failed)?;
+
+        debug!(target: "rkb::native_minter", %caller, %value, "Refunded value attached to call");
+        Ok(())
+    }
+
+    /// Reverts if mints only credit existing accounts and `recipient` doesn't exist.
+    fn ensure_recipient_exists(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+    ) -> Result<(), PrecompileError> {
+        if !self.config.require_existing_recipient
+            || !matches!(self.config.mint_target, MintTarget::Native)
+        {
+            return Ok(());
+        }
+
+        let account = input.internals_mut().load_account(recipient).map_err(|e| {
+            PrecompileError::other(format!("NativeMinter: load account failed: {e}"))
+        })?;
+        if account.data.info.is_empty() {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                "NativeMinter: recipient does not exist"
+            );
+            return Err(NativeMinterError::RecipientNotFound.into());
+        }
+        Ok(())
+    }
+
+    /// Adds `amount` to the block's total towards the circuit breaker, reverting if it would
+    /// exceed the threshold.
+    fn update_breaker(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        amount: U256,
+    ) -> Result<(), PrecompileError> {
+        let Some(threshold) = self.config.breaker_threshold else {
+            return Ok(());
+        };
+
+        // Block numbers are stored plus one, so that zero means nothing was minted yet
+        let block = input.internals().block_number().saturating_add(U256::from(1));
+        let same_block = self.sload(input, BREAKER_BLOCK_SLOT)? == block;
+        let minted = if same_block { self.sload(input, BREAKER_MINTED_SLOT)? } else { U256::ZERO };
+
+        let minted = minted.saturating_add(amount);
+        if minted > threshold {
+            warn!(
+                target: "rkb::native_minter",
+                %amount,
+                %minted,
+                %threshold,
+                "NativeMinter: circuit breaker tripped"
+            );
+            return Err(NativeMinterError::CircuitBreakerTripped.into());
+        }
+        if !same_block {
+            self.sstore(input, BREAKER_BLOCK_SLOT, block)?;
+        }
+        self.sstore(input, BREAKER_MINTED_SLOT, minted)
+    }
+
+    /// Counts `recipient` towards the per-block recipient cap, reverting if it is a new recipient
+    /// and the cap is already reached.
+    fn count_recipient(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+    ) -> Result<(), PrecompileError> {
+        let Some(max) = self.config.max_recipients_per_block else {
+            return Ok(());
+        };
+
+        // Block numbers are stored plus one, so that zero means nothing was counted yet
+        let block = input.internals().block_number().saturating_add(U256::from(1));
+        let slot = storage::last_mint_block_slot(recipient);
+        if self.sload(input, slot)? == block {
+            return Ok(());
+        }
+
+        let count = if self.sload(input, RECIPIENTS_BLOCK_SLOT)? == block {
+            self.sload(input, RECIPIENTS_IN_BLOCK_SLOT)?
+        } else {
+            self.sstore(input, RECIPIENTS_BLOCK_SLOT, block)?;
+            U256::ZERO
+        };
+
+        if count >= U256::from(max) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                %count,
+                "NativeMinter: too many recipients this block"
+            );
+            return Err(NativeMinterError::TooManyRecipients.into());
+        }
+
+        self.sstore(input, RECIPIENTS_IN_BLOCK_SLOT, count + U256::from(1))?;
+        self.sstore(input, slot, block)
+    }
+
+    /// Reverts if `recipient` was minted to less than the mint cooldown ago, then records this
+    /// block as its last mint.
+    fn enforce_mint_cooldown(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+    ) -> Result<(), PrecompileError> {
+        let Some(cooldown) = self.config.mint_cooldown_blocks else {
+            return Ok(());
+        };
+
+        // Block numbers are stored plus one, so that zero means never minted to
+        let block = input.internals().block_number().saturating_add(U256::from(1));
+        let slot = storage::mint_cooldown_slot(recipient);
+        let last = self.sload(input, slot)?;
+        if !last.is_zero() && block.saturating_sub(last) < U256::from(cooldown) {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                last_mint_block = %(last - U256::from(1)),
+                cooldown,
+                "NativeMinter: mint cooldown not elapsed"
+            );
+            return Err(NativeMinterError::MintCooldown.into());
+        }
+
+        self.sstore(input, slot, block)
+    }
+
+    /// Reverts if `recipient` is a precompile.
+    fn ensure_not_precompile(&self, recipient: Address) -> Result<(), PrecompileError> {
+        if self.addresses().any(|address| address == recipient)
+            || self.precompile_addresses.contains(&recipient)
+        {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %recipient,
+                "NativeMinter: cannot mint to precompile"
+            );
+            return Err(NativeMinterError::MintToPrecompile.into());
+        }
+        Ok(())
+    }
+
+    /// Reverts if `amount` exceeds the cap on a single mint or burn.
+    fn ensure_within_call_cap(&self, amount: U256) -> Result<(), PrecompileError> {
+        if amount > self.config.max_amount_per_call {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %amount,
+                max_amount_per_call = %self.config.max_amount_per_call,
+                "NativeMinter: amount exceeds per-call cap"
+            );
+            return Err(NativeMinterError::AmountExceedsCallCap.into());
+        }
+        Ok(())
+    }
+
+    /// Reverts if minting `amount` would raise the net minted supply above the supply cap.
+    fn ensure_within_supply_cap(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        amount: U256,
+    ) -> Result<(), PrecompileError> {
+        let Some(supply_cap) = self.config.supply_cap else {
+            return Ok(());
+        };
+
+        let minted = self.sload(input, TOTAL_MINTED_SLOT)?;
+        let burned = self.sload(input, TOTAL_BURNED_SLOT)?;
+        let supply = minted.saturating_sub(burned);
+        if supply.saturating_add(amount) > supply_cap {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %amount,
+                %supply,
+                %supply_cap,
+                "NativeMinter: supply cap exceeded"
+            );
+            return Err(NativeMinterError::SupplyCapExceeded.into());
+        }
+        Ok(())
+    }
+
+    /// Execute a batch of mints, charging the mint gas cost per recipient.
+    fn execute_mint_batch(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> PrecompileResult {
+        self.check_batch(&recipients, &amounts)?;
+
+        let gas_cost = self.gas_cost(input, recipients.len().max(1) as u64)?;
+        ensure_enough_gas(input.gas, gas_cost)?;
+
+        let aggregate = self.config.aggregate_batch_events && self.config.event_scheme.is_some();
+        let batch_mint = aggregate.then(|| BatchMint {
+            count: U256::from(recipients.len()),
+            totalAmount: amounts
+                .iter()
+                .fold(U256::ZERO, |total, amount| total.saturating_add(*amount)),
+            recipientsHash: keccak256(recipients.abi_encode()),
+        });
+
+        for (recipient, amount) in recipients.into_iter().zip(amounts) {
+            self.mint_with_events(input, recipient, amount, !aggregate)?;
+        }
+
+        if let Some(batch_mint) = batch_mint {
+            emit(input, batch_mint);
+        }
+
+        Ok(PrecompileOutput::new(gas_cost, self.mint_output()))
+    }
+
+    /// Reverts if a `mintBatch` call exceeds the maximum batch size or its arguments differ in
+    /// length.
+    fn check_batch(
+        &self,
+        recipients: &[Address],
+        amounts: &[U256],
+    ) -> Result<(), NativeMinterError> {
+        if recipients.len() > self.config.max_batch_size {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                len = recipients.len(),
+                max = self.config.max_batch_size,
+                "NativeMinter: batch too large"
+            );
+            return Err(NativeMinterError::BatchTooLarge);
+        }
+
+        if recipients.len() != amounts.len() {
+            return Err(NativeMinterError::BatchLengthMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Execute a mint tied to a Hyperlane message, recording the message ID as processed.
+    fn execute_mint_with_message(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        recipient: Address,
+        amount: U256,
+        message_id: B256,
+        origin: Option<u32>,
+    ) -> PrecompileResult {
+        let slot = storage::processed_message_slot(message_id);
+        if !self.sload(input, slot)?.is_zero() {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %message_id,
+                "NativeMinter: message already processed"
+            );
+            return Err(NativeMinterError::MessageAlreadyProcessed.into());
+        }
+
+        self.sstore(input, slot, U256::from(1))?;
+
+        let output = self.execute_mint(input, recipient, amount)?;
+        if let Some(origin) = origin {
+            emit(input, BridgeIn { messageId: message_id, recipient, amount, origin });
+        }
+        Ok(output)
+    }
+
+    /// Execute a burn tied to a nonce of `from`, recording the nonce as used.
+    fn execute_burn_with_nonce(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        from: Address,
+        amount: U256,
+        nonce: U256,
+    ) -> PrecompileResult {
+        let (slot, bit) = storage::burn_nonce_slot(from, nonce);
+        let used = self.sload(input, slot)?;
+        if !(used & bit).is_zero() {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %from,
+                %nonce,
+                "NativeMinter: burn nonce already used"
+            );
+            return Err(NativeMinterError::BurnNonceUsed.into());
+        }
+
+        self.sstore(input, slot, used | bit)?;
+
+        self.execute_burn(input, from, amount)
+    }
+
+    /// Execute the burn operation - debit native tokens from an address.
+    fn execute_burn(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        from: Address,
+        amount: U256,
+    ) -> PrecompileResult {
+        self.ensure_active(input)?;
+        self.ensure_not_paused(input, PausableOperation::Burn)?;
+        self.ensure_within_call_cap(amount)?;
+        self.ensure_origin_burn(input, from)?;
+
+        debug!(
+            target: "rkb::native_minter",
+            %from,
+            %amount,
+            "Burning native tokens"
+        );
+
+        self.ensure_burn_account_exists(input, from)?;
+        let current_balance = self.balance_of(input, from)?;
+
+        // Check sufficient balance, clamping the burn to the balance if configured
+        let amount = match self.config.burn_mode {
+            BurnMode::Clamp => amount.min(current_balance),
+            BurnMode::Revert => amount,
+        };
+        if current_balance < amount {
+            reject!(
+                self,
+                target: "rkb::native_minter",
+                %from,
+                %amount,
+                %current_balance,
+                "NativeMinter: insufficient balance for burn"
+            );
+            return Err(NativeMinterError::InsufficientBalance.into());
+        }
+
+        let remaining_allowance = if self.config.burn_allowance_required && from != input.caller {
+            Some(self.spend_allowance(input, from, amount)?)
+        } else {
+            None
+        };
+
+        // Calculate new balance and set it
+        let new_balance = current_balance - amount;
+        if self.config.observe_only {
+            debug!(target: "rkb::native_minter", %from, "Observe-only: skipping burn");
+        } else {
+            match self.config.mint_target {
+                MintTarget::Native => {
+                    input.internals_mut().set_balance(from, new_balance).map_err(|e| {
+                        PrecompileError::other(format!("NativeMinter: burn failed: {e}"))
+                    })?;
+                }
+                MintTarget::Erc20(target) => {
+                    self.set_erc20_balance(input, target, from, new_balance)?;
+                    self.update_erc20_supply(input, target, from, Address::ZERO, amount)?;
+                }
+            }
+        }
+
+        let total_burned = self.sadd(input, TOTAL_BURNED_SLOT, amount)?;
+
+        if let Some(scheme) = self.config.event_scheme {
+            if scheme.emits_native() {
+                emit(input, Burn { from, amount });
+            }
+            if scheme.emits_erc20_transfer() {
+                emit(input, Transfer { from, to: Address::ZERO, value: amount });
+            }
+        }
+
+        let output = if self.config.burn_summary_output {
+            (new_balance, total_burned).abi_encode_params().into()
+        } else {
+            match remaining_allowance.filter(|_| self.config.allowance_output) {
+                Some(remaining_allowance) => remaining_allowance.abi_encode().into(),
+                None => self.burn_output(amount),
+            }
+        };
+        let gas_used = self.gas_cost(input, 1)?;
+        self.send_record(MintBurnRecord::Burn { from, amount });
+        Ok(PrecompileOutput::new(gas_used, output))
+    }
+
+    /// Sends `record` to the mint channel, if any, without blocking.
+    fn send_record(&self, record: MintBurnRecord) {
+        let Some(sender) = &self.config.mint_channel else {
+            return;
+        };
+        if let Err(err) = sender.try_send(record) {
+            debug!(target: "rkb::native_minter", %err, "Dropped mint channel record");
+            metrics::counter!("rkb_dropped_records_total").increment(1);
+        }
+    }
+
+    /// Returns the balance of `account` in the [`MintTarget`].
+    fn balance_of(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        account: Address,
+    ) -> Result<U256, PrecompileError> {
+        match self.config.mint_target {
+            MintTarget::Native => {
+                let account = input.internals_mut().load_account(account).map_err(|e| {
+                    PrecompileError::other(format!("NativeMinter: load account failed: {e}"))
+                })?;
+                Ok(account.data.info.balance)
+            }
+            MintTarget::Erc20(target) => {
+                storage::sload_at(input, target.token, target.balance_slot(account))
+            }
+        }
+    }
+
+    /// Sets the balance of `account` in the ERC-20 token.
+    fn set_erc20_balance(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        target: Erc20Target,
+        account: Address,
+        balance: U256,
+    ) -> Result<(), PrecompileError> {
+        storage::sstore_at(input, target.token, target.balance_slot(account), balance)
+    }
+
+    /// Records a mint (`from` is zero) or burn (`to` is zero) of `value` in the ERC-20 token's
+    /// total supply, and emits the `Transfer` event the token would.
+    fn update_erc20_supply(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        target: Erc20Target,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), PrecompileError> {
+        if let Some(slot) = target.total_supply_slot {
+            let supply = storage::sload_at(input, target.token, slot)?;
+            let supply = if from.is_zero() {
+                supply.saturating_add(value)
+            } else {
+                supply.saturating_sub(value)
+            };
+            storage::sstore_at(input, target.token, slot, supply)?;
+        }
+
+        input.internals_mut().log(Log {
+            address: target.token,
+            data: Transfer { from, to, value }.encode_log_data(),
+        });
+        Ok(())
+    }
+
+    /// Returns the output of a successful `approve` setting the allowance to `value`.
+    fn approve_output(&self, value: U256) -> Bytes {
+        if self.config.allowance_output {
+            value.abi_encode().into()
+        } else {
+            true.abi_encode().into()
+        }
+    }
+
+    /// Returns the output of a successful mint.
+    fn mint_output(&self) -> Bytes {
+        if self.config.return_bool {
+            true.abi_encode().into()
+        } else {
+            Bytes::new()
+        }
+    }
+
+    /// Returns the output of a successful burn of `burned`, after any clamping.
+    fn burn_output(&self, burned: U256) -> Bytes {
+        match self.config.burn_mode {
+            BurnMode::Clamp => Bytes::from(burned.to_be_bytes::<32>()),
+            BurnMode::Revert if self.config.return_bool => true.abi_encode().into(),
+            BurnMode::Revert => Bytes::new(),
+        }
+    }
+
+    /// Returns the gas charged for the given call performing `operations` operations.
+    ///
+    /// Mints and burns are charged their own cost, other calls the gas model's base. A cost set by
+    /// the governor replaces all three.
+    fn gas_cost(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        operations: u64,
+    ) -> Result<u64, PrecompileError> {
+        let selector = input.data.get(..4).and_then(|selector| <[u8; 4]>::try_from(selector).ok());
+        let base = self.base_gas_cost(input, selector)?;
+        let execution = self.config.gas_model.cost_with_base(base, input.data.len(), operations);
+        Ok(execution.saturating_add(self.decode_gas_cost(input.data.len())))
+    }
+
+    /// Returns the gas charged for decoding `data_len` bytes of calldata.
+    fn decode_gas_cost(&self, data_len: usize) -> u64 {
+        self.config.decode_gas_per_word.saturating_mul(data_len.div_ceil(32) as u64)
+    }
+
+    /// Returns the gas charged per operation by the function with the given selector, excluding
+    /// any calldata-dependent component.
+    fn base_gas_cost(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        selector: Option<[u8; 4]>,
+    ) -> Result<u64, PrecompileError> {
+        if selector.is_some_and(|selector| VIEW_SELECTORS.contains(&selector)) {
+            return Ok(self.config.view_gas_cost);
+        }
+
+        if self.config.governor.is_some() {
+            let governed = self.sload(input, GAS_COST_SLOT)?;
+            if !governed.is_zero() {
+                return Ok(governed.saturating_to());
+            }
+        }
+
+        Ok(match selector {
+            Some(
+                <mintCall as SolCall>::SELECTOR
+                | <mintWithMessageCall as SolCall>::SELECTOR
+                | <mintWithMessageFromCall as SolCall>::SELECTOR
+                | <mintBatchCall as SolCall>::SELECTOR
+                | <mintToSelfCall as SolCall>::SELECTOR
+                | <mintWithApprovalCall as SolCall>::SELECTOR
+                | <executeEmergencyMintCall as SolCall>::SELECTOR,
+            ) => self.config.mint_gas_cost,
+            Some(<burn_0Call as SolCall>::SELECTOR | <burn_1Call as SolCall>::SELECTOR) => {
+                self.config.burn_gas_cost
+            }
+            // The direction of a net mint or burn is only known once decoded
+            Some(<mintBurnNetCall as SolCall>::SELECTOR) => {
+                self.config.mint_gas_cost.max(self.config.burn_gas_cost)
+            }
+            _ => self.config.gas_model.base(),
+        })
+    }
+
+    /// Wraps a configured value for logging, redacting it under [`Verbosity::Terse`].
+    fn redact<T>(&self, value: T) -> Redacted<T> {
+        Redacted((self.config.error_verbosity == Verbosity::Detailed).then_some(value))
+    }
+
+    /// Writes a runtime configuration setting to `slot`, emitting `ConfigChanged`.
+    fn set_config(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        slot: U256,
+        value: U256,
+    ) -> Result<(), PrecompileError> {
+        let old_value = self.sload(input, slot)?;
+        self.sstore(input, slot, value)?;
+        emit(
+            input,
+            ConfigChanged { key: slot.into(), oldValue: old_value.into(), newValue: value.into() },
+        );
+        Ok(())
+    }
+
+    /// Reads a logical slot from the precompile's storage namespace.
+    fn sload(&self, input: &mut PrecompileInput<'_>, slot: U256) -> Result<U256, PrecompileError> {
+        storage::sload(input, self.storage_slot(slot))
+    }
+
+    /// Writes a logical slot in the precompile's storage namespace.
+    fn sstore(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        slot: U256,
+        value: U256,
+    ) -> Result<(), PrecompileError> {
+        storage::sstore(input, self.storage_slot(slot), value)
+    }
+
+    /// Adds `amount` to the counter in a logical slot, returning the new value.
+    fn sadd(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        slot: U256,
+        amount: U256,
+    ) -> Result<U256, PrecompileError> {
+        storage::sadd(input, self.storage_slot(slot), amount)
+    }
+
+    /// Reads an address stored in a logical slot.
+    fn sload_address(
+        &self,
+        input: &mut PrecompileInput<'_>,
+        slot: U256,
+    ) -> Result<Address, PrecompileError> {
+        storage::sload_address(input, self.storage_slot(slot))
+    }
+}
+
+/// Callers that only act in the system calls the node makes around a block's transactions: the
+/// system address calling the system contracts, and the system contracts themselves.
+pub(crate) const SYSTEM_ADDRESSES: [Address; 5] = [
+    SYSTEM_ADDRESS,
+    BEACON_ROOTS_ADDRESS,
+    HISTORY_STORAGE_ADDRESS,
+    WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS,
+    CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS,
+];
+
+/// Selectors of the view functions, callable by anyone including via STATICCALL.
+const VIEW_SELECTORS: [[u8; 4]; 18] = [
+    <wasProcessedCall as SolCall>::SELECTOR,
+    <totalMintedCall as SolCall>::SELECTOR,
+    <totalBurnedCall as SolCall>::SELECTOR,
+    <totalSupplyCall as SolCall>::SELECTOR,
+    <allowanceCall as SolCall>::SELECTOR,
+    <noncesCall as SolCall>::SELECTOR,
+    <DOMAIN_SEPARATORCall as SolCall>::SELECTOR,
+    <mintedToCall as SolCall>::SELECTOR,
+    <decimalsCall as SolCall>::SELECTOR,
+    <featuresCall as SolCall>::SELECTOR,
+    <versionCall as SolCall>::SELECTOR,
+    <gasCostCall as SolCall>::SELECTOR,
+    <bridgeCountCall as SolCall>::SELECTOR,
+    <bridgeAtCall as SolCall>::SELECTOR,
+    <isPausedCall as SolCall>::SELECTOR,
+    <isMintPausedCall as SolCall>::SELECTOR,
+    <isBurnPausedCall as SolCall>::SELECTOR,
+    <approvalNonceCall as SolCall>::SELECTOR,
+];
+
+/// Selectors of the state-modifying functions callable by anyone.
+///
+/// These only modify state owned by the caller, or by the signer of a permit. `acceptBridge` is
+/// only callable by the proposed bridge, which is checked against state when it executes.
+const PUBLIC_SELECTORS: [[u8; 4]; 3] = [
+    <approveCall as SolCall>::SELECTOR,
+    <permitCall as SolCall>::SELECTOR,
+    <acceptBridgeCall as SolCall>::SELECTOR,
+];
+
+/// Selectors of the runtime administration functions, only callable by the governor.
+const GOVERNOR_SELECTORS: [[u8; 4]; 2] =
+    [<setGasCostCall as SolCall>::SELECTOR, <proposeBridgeCall as SolCall>::SELECTOR];
+
+/// Selectors of the emergency mint functions, only callable by the [`EmergencyMint`] owner.
+const EMERGENCY_SELECTORS: [[u8; 4]; 2] = [
+    <scheduleEmergencyMintCall as SolCall>::SELECTOR,
+    <executeEmergencyMintCall as SolCall>::SELECTOR,
+];
+
+/// Names of all functions of the interface, by selector, as reported in metrics.
+const FUNCTIONS: [([u8; 4], &str); 41] = [
+    (<mintCall as SolCall>::SELECTOR, "mint"),
+    (<burn_0Call as SolCall>::SELECTOR, "burn"),
+    (<burn_1Call as SolCall>::SELECTOR, "burn"),
+    (<mintWithMessageCall as SolCall>::SELECTOR, "mintWithMessage"),
+    (<mintWithMessageFromCall as SolCall>::SELECTOR, "mintWithMessageFrom"),
+    (<mintBatchCall as SolCall>::SELECTOR, "mintBatch"),
+    (<mintToSelfCall as SolCall>::SELECTOR, "mintToSelf"),
+    (<mintWithApprovalCall as SolCall>::SELECTOR, "mintWithApproval"),
+    (<approvalNonceCall as SolCall>::SELECTOR, "approvalNonce"),
+    (<approveCall as SolCall>::SELECTOR, "approve"),
+    (<permitCall as SolCall>::SELECTOR, "permit"),
+    (<pauseCall as SolCall>::SELECTOR, "pause"),
+    (<unpauseCall as SolCall>::SELECTOR, "unpause"),
+    (<pauseMintCall as SolCall>::SELECTOR, "pauseMint"),
+    (<unpauseMintCall as SolCall>::SELECTOR, "unpauseMint"),
+    (<pauseBurnCall as SolCall>::SELECTOR, "pauseBurn"),
+    (<unpauseBurnCall as SolCall>::SELECTOR, "unpauseBurn"),
+    (<scheduleEmergencyMintCall as SolCall>::SELECTOR, "scheduleEmergencyMint"),
+    (<executeEmergencyMintCall as SolCall>::SELECTOR, "executeEmergencyMint"),
+    (<setGasCostCall as SolCall>::SELECTOR, "setGasCost"),
+    (<proposeBridgeCall as SolCall>::SELECTOR, "proposeBridge"),
+    (<acceptBridgeCall as SolCall>::SELECTOR, "acceptBridge"),
+    (<mintAllowanceCall as SolCall>::SELECTOR, "mintAllowance"),
+    (<decimalsCall as SolCall>::SELECTOR, "decimals"),
+    (<featuresCall as SolCall>::SELECTOR, "features"),
+    (<mintBurnNetCall as SolCall>::SELECTOR, "mintBurnNet"),
+    (<versionCall as SolCall>::SELECTOR, "version"),
+    (<gasCostCall as SolCall>::SELECTOR, "gasCost"),
+    (<bridgeCountCall as SolCall>::SELECTOR, "bridgeCount"),
+    (<bridgeAtCall as SolCall>::SELECTOR, "bridgeAt"),
+    (<isPausedCall as SolCall>::SELECTOR, "isPaused"),
+    (<isMintPausedCall as SolCall>::SELECTOR, "isMintPaused"),
+    (<isBurnPausedCall as SolCall>::SELECTOR, "isBurnPaused"),
+    (<wasProcessedCall as SolCall>::SELECTOR, "wasProcessed"),
+    (<totalMintedCall as SolCall>::SELECTOR, "totalMinted"),
+    (<totalBurnedCall as SolCall>::SELECTOR, "totalBurned"),
+    (<totalSupplyCall as SolCall>::SELECTOR, "totalSupply"),
+    (<allowanceCall as SolCall>::SELECTOR, "allowance"),
+    (<noncesCall as SolCall>::SELECTOR, "nonces"),
+    (<DOMAIN_SEPARATORCall as SolCall>::SELECTOR, "DOMAIN_SEPARATOR"),
+    (<mintedToCall as SolCall>::SELECTOR, "mintedTo"),
+];
+
+/// Counts a call into the precompile in `rkb_native_minter_calls_total`, labeled by function
+/// name and outcome. Calls with an unknown selector are not counted.
+fn record_call(data: &[u8], result: &PrecompileResult) {
+    let Some(&(_, function)) =
+        FUNCTIONS.iter().find(|(selector, _)| data.get(..4) == Some(selector.as_slice()))
+    else {
+        return;
+    };
+    let outcome = if result.is_ok() { "success" } else { "revert" };
+    metrics::counter!("rkb_native_minter_calls_total", "selector" => function, "outcome" => outcome)
+        .increment(1);
+}
+
+/// Converts `amount` from `from` to `to` decimals, returning `None` on overflow or if scaling
+/// down is inexact.
+fn scale_decimals(amount: U256, from: u8, to: u8) -> Option<U256> {
+    if to >= from {
+        let factor = U256::from(10).checked_pow(U256::from(to - from))?;
+        amount.checked_mul(factor)
+    } else {
+        let factor = U256::from(10).checked_pow(U256::from(from - to))?;
+        (amount % factor).is_zero().then(|| amount / factor)
+    }
+}
+
+/// Reverts with [`PrecompileError::OutOfGas`] if the `available` gas is below the `required` gas.
+///
+/// The error has to stay the bare `OutOfGas` for EVM semantics, so both amounts are logged instead,
+/// helping integrators size the gas they forward under the 63/64 rule.
+fn ensure_enough_gas(available: u64, required: u64) -> Result<(), PrecompileError> {
+    if available < required {
+        warn!(
+            target: "rkb::native_minter",
+            "NativeMinter: out of gas (have {available}, need {required})"
+        );
+        return Err(PrecompileError::OutOfGas);
+    }
+    Ok(())
+}
+
+/// Reverts with [`PrecompileError::OutOfGas`] if the `available` gas is below the `required` gas
+/// for decoding the calldata, logging both as [`ensure_enough_gas`] does.
+fn ensure_enough_gas_to_decode(available: u64, required: u64) -> Result<(), PrecompileError> {
+    if available < required {
+        warn!(
+            target: "rkb::native_minter",
+            "NativeMinter: out of gas decoding calldata (have {available}, need {required})"
+        );
+        return Err(PrecompileError::OutOfGas);
+    }
+    Ok(())
+}
+
+/// A logged value, formatted as `<redacted>` if hidden by the [`Verbosity`].
+struct Redacted<T>(Option<T>);
+
+impl<T: fmt::Display> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(value) => value.fmt(f),
+            None => f.write_str("<redacted>"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(value) => value.fmt(f),
+            None => f.write_str("<redacted>"),
+        }
+    }
+}
+
+/// Emits `event` from the precompile's address.
+fn emit(input: &mut PrecompileInput<'_>, event: impl SolEvent) {
+    input
+        .internals_mut()
+        .log(Log { address: NATIVE_MINTER_ADDRESS, data: event.encode_log_data() });
+}
+
+/// Returns the error for a call that is not a direct call, distinguishing CALLCODE from
+/// DELEGATECALL.
+///
+/// [`PrecompileInput`] does not expose the call scheme, so it is inferred: both schemes execute
+/// the precompile in the calling contract's context (`target_address`), but CALLCODE sets the
+/// caller to that contract while DELEGATECALL preserves the caller of the calling contract. A
+/// DELEGATECALL made by a contract that was itself called by its own address is therefore
+/// reported as CALLCODE; either way the call is rejected.
+fn indirect_call_error(input: &PrecompileInput<'_>) -> NativeMinterError {
+    if input.caller == input.target_address {
+        NativeMinterError::CallCode
+    } else {
+        NativeMinterError::DelegateCall
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{run_precompile, CapturedLogs, TestCall, TestContext};
+    use alloy_primitives::{b256, bytes};
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+    use proptest::prelude::*;
+    use revm::{
+        database::InMemoryDB,
+        state::{AccountInfo, Bytecode},
+    };
+
+    const BRIDGE: Address = address!("0x1234567890abcdef1234567890abcdef12345678");
+    const OTHER_BRIDGE: Address = address!("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd");
+    const RECIPIENT: Address = address!("0x00000000000000000000000000000000000000aa");
+
+    fn mint_calldata(recipient: Address, amount: U256) -> Bytes {
+        mintCall { recipient, amount }.abi_encode().into()
+    }
+
+    fn burn_calldata(from: Address, amount: U256) -> Bytes {
+        burn_0Call { from, amount }.abi_encode().into()
+    }
+
+    #[test]
+    fn test_mint_selector() {
+        // mint(address,uint256) = keccak256("mint(address,uint256)")[0:4]
+        let expected: [u8; 4] = [0x40, 0xc1, 0x0f, 0x19];
+        assert_eq!(<mintCall as SolCall>::SELECTOR, expected);
+    }
+
+    #[test]
+    fn test_burn_selector() {
+        // burn(address,uint256) = keccak256("burn(address,uint256)")[0:4]
+        let expected: [u8; 4] = [0x9d, 0xc2, 0x9f, 0xac];
+        assert_eq!(<burn_0Call as SolCall>::SELECTOR, expected);
+    }
+
+    #[test]
+    fn test_precompile_address() {
+        assert_eq!(NATIVE_MINTER_ADDRESS, address!("0x0000000000000000000000000000000000000420"));
+    }
+
+    #[test]
+    fn test_bridge_from_state_authorizes_seeded_address() {
+        let precompile =
+            NativeMinterPrecompile::new(Address::ZERO).with_bridge_source(BridgeSource::State);
+        let mut ctx = TestContext::new();
+        ctx.set_storage(AUTHORIZED_BRIDGE_SLOT, storage::address_to_word(BRIDGE));
+
+        let amount = U256::from(1_000);
+        let output = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, amount)),
+        )
+        .unwrap();
+
+        assert_eq!(output.gas_used, MINT_GAS_COST);
+        assert_eq!(ctx.balance(RECIPIENT), amount);
+    }
+
+    #[test]
+    fn test_bridge_from_state_ignores_config_bridge() {
+        // The configured bridge is not the one seeded in state, so it must not be able to mint.
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_bridge_source(BridgeSource::State);
+        let mut ctx = TestContext::new();
+        ctx.set_storage(AUTHORIZED_BRIDGE_SLOT, storage::address_to_word(OTHER_BRIDGE));
+
+        let amount = U256::from(1_000);
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, amount)),
+        );
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: unauthorized caller")));
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(OTHER_BRIDGE, mint_calldata(RECIPIENT, amount)),
+        )
+        .unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), amount);
+    }
+
+    #[test]
+    fn test_bridge_from_state_unset_slot_falls_back_to_config() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_bridge_source(BridgeSource::State);
+        let mut ctx = TestContext::new();
+        let amount = U256::from(1_000);
+
+        // The zero address read from the unset slot must not be authorized.
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(Address::ZERO, mint_calldata(RECIPIENT, amount)),
+        );
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: unauthorized caller")));
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, amount)),
+        )
+        .unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), amount);
+    }
+
+    #[test]
+    fn test_fresh_state_matches_config_only() {
+        let calls = [
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+            TestCall::new(OTHER_BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+            TestCall::new(
+                BRIDGE,
+                burn_0Call { from: RECIPIENT, amount: U256::from(400) }.abi_encode(),
+            ),
+            TestCall::new(
+                BRIDGE,
+                burn_0Call { from: RECIPIENT, amount: U256::from(700) }.abi_encode(),
+            ),
+        ];
+
+        let config_only = NativeMinterPrecompile::new(BRIDGE);
+        let from_state =
+            NativeMinterPrecompile::new(BRIDGE).with_bridge_source(BridgeSource::State);
+
+        let mut config_ctx = TestContext::new();
+        let mut state_ctx = TestContext::new();
+        for call in calls {
+            assert_eq!(
+                run_precompile(&config_only, &mut config_ctx, call.clone()),
+                run_precompile(&from_state, &mut state_ctx, call)
+            );
+        }
+        assert_eq!(config_ctx.balance(RECIPIENT), state_ctx.balance(RECIPIENT));
+        assert_eq!(state_ctx.balance(RECIPIENT), U256::from(600));
+    }
+
+    #[test]
+    fn test_burn_clamp_burns_available_balance() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_burn_mode(BurnMode::Clamp);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(300));
+
+        let output = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+
+        assert_eq!(U256::from_be_slice(&output.bytes), U256::from(300));
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_burn_clamp_within_balance() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_burn_mode(BurnMode::Clamp);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(300));
+
+        let output = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(100))),
+        )
+        .unwrap();
+
+        assert_eq!(U256::from_be_slice(&output.bytes), U256::from(100));
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(200));
+    }
+
+    #[test]
+    fn test_burn_revert_mode_reverts_on_insufficient_balance() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(precompile.burn_mode(), BurnMode::Revert);
+
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(300));
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(1_000))),
+        );
+
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: insufficient balance"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(300));
+    }
+
+    #[test]
+    fn test_reentrant_call_reverts() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        // Simulate a nested call by entering with the guard already set by an outer call.
+        ctx.set_transient_storage(REENTRANCY_GUARD_SLOT, U256::from(1));
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        );
+
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: reentrant call")));
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_reentrancy_guard_cleared_on_exit() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        for _ in 0..2 {
+            run_precompile(
+                &precompile,
+                &mut ctx,
+                TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(ctx.transient_storage(REENTRANCY_GUARD_SLOT), U256::ZERO);
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+    }
+
+    const MESSAGE_ID: B256 = B256::repeat_byte(0x11);
+
+    fn mint_with_message_calldata(recipient: Address, amount: U256, message_id: B256) -> Bytes {
+        mintWithMessageCall { recipient, amount, messageId: message_id }.abi_encode().into()
+    }
+
+    fn was_processed(
+        precompile: &NativeMinterPrecompile,
+        ctx: &mut TestContext,
+        message_id: B256,
+    ) -> bool {
+        let calldata = wasProcessedCall { messageId: message_id }.abi_encode();
+        let output =
+            run_precompile(precompile, ctx, TestCall::new(Address::ZERO, calldata).static_call())
+                .unwrap();
+        bool::abi_decode(&output.bytes).unwrap()
+    }
+
+    #[test]
+    fn test_mint_with_message_records_message() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        assert!(!was_processed(&precompile, &mut ctx, MESSAGE_ID));
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(
+                BRIDGE,
+                mint_with_message_calldata(RECIPIENT, U256::from(1_000), MESSAGE_ID),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+        assert!(was_processed(&precompile, &mut ctx, MESSAGE_ID));
+        assert!(!was_processed(&precompile, &mut ctx, B256::repeat_byte(0x22)));
+    }
+
+    #[test]
+    fn test_mint_with_message_from_emits_bridge_in() {
+        const ORIGIN: u32 = 0x4365_6c65;
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_event_scheme(EventScheme::Native);
+        let mut ctx = TestContext::new();
+        let call = mintWithMessageFromCall {
+            recipient: RECIPIENT,
+            amount: U256::from(1_000),
+            messageId: MESSAGE_ID,
+            origin: ORIGIN,
+        };
+
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, call.abi_encode())).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+        assert!(was_processed(&precompile, &mut ctx, MESSAGE_ID));
+
+        // Emitted after the events of the scheme, with the message ID and recipient indexed
+        let logs = ctx.take_logs();
+        assert_eq!(logs.len(), 2);
+        let log = &logs[1];
+        assert_eq!(log.address, NATIVE_MINTER_ADDRESS);
+        assert_eq!(
+            log.data.topics(),
+            [BridgeIn::SIGNATURE_HASH, MESSAGE_ID, RECIPIENT.into_word()]
+        );
+        assert_eq!(log.data.data, Bytes::from((U256::from(1_000), ORIGIN).abi_encode_params()));
+        let event = BridgeIn::decode_log_data(&log.data).unwrap();
+        assert_eq!(
+            (event.messageId, event.recipient, event.amount, event.origin),
+            (MESSAGE_ID, RECIPIENT, U256::from(1_000), ORIGIN)
+        );
+
+        // Replays are rejected like for `mintWithMessage`
+        let result =
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, call.abi_encode()));
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: message already processed"))
+        );
+
+        // `mintWithMessage` has no origin, so emits no `BridgeIn`
+        let data =
+            mint_with_message_calldata(RECIPIENT, U256::from(1_000), B256::repeat_byte(0x22));
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data)).unwrap();
+        let logs = ctx.take_logs();
+        assert!(logs.iter().all(|log| log.topics()[0] != BridgeIn::SIGNATURE_HASH), "{logs:?}");
+    }
+
+    #[test]
+    fn test_mint_with_message_rejects_replay() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let call = TestCall::new(
+            BRIDGE,
+            mint_with_message_calldata(RECIPIENT, U256::from(1_000), MESSAGE_ID),
+        );
+
+        run_precompile(&precompile, &mut ctx, call.clone()).unwrap();
+        let result = run_precompile(&precompile, &mut ctx, call);
+
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: message already processed"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+    }
+
+    const MINTER: Address = address!("0x00000000000000000000000000000000000000b1");
+    const BURNER: Address = address!("0x00000000000000000000000000000000000000b2");
+    const PAUSER: Address = address!("0x00000000000000000000000000000000000000b3");
+
+    fn precompile_with_roles() -> NativeMinterPrecompile {
+        NativeMinterPrecompile::new(BRIDGE).add_minter(MINTER).add_burner(BURNER).add_pauser(PAUSER)
+    }
+
+    #[test]
+    fn test_minter_role_can_only_mint() {
+        let precompile = precompile_with_roles();
+        let mut ctx = TestContext::new();
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(MINTER, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+
+        for calldata in [burn_calldata(RECIPIENT, U256::from(1)), pauseCall {}.abi_encode().into()]
+        {
+            let result = run_precompile(&precompile, &mut ctx, TestCall::new(MINTER, calldata));
+            assert_eq!(
+                result,
+                Err(PrecompileError::other_static("NativeMinter: unauthorized caller"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_burner_role_can_only_burn() {
+        let precompile = precompile_with_roles();
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BURNER, burn_calldata(RECIPIENT, U256::from(400))),
+        )
+        .unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BURNER, mint_calldata(RECIPIENT, U256::from(1))),
+        );
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: unauthorized caller")));
+    }
+
+    #[test]
+    fn test_pauser_role_pauses_minting() {
+        let precompile = precompile_with_roles();
+        let mut ctx = TestContext::new();
+
+        run_precompile(&precompile, &mut ctx, TestCall::new(PAUSER, pauseCall {}.abi_encode()))
+            .unwrap();
+        assert_eq!(ctx.storage(PAUSED_SLOT), U256::from(1));
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))),
+        );
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: paused")));
+
+        // The pauser can't mint, even when unpaused
+        run_precompile(&precompile, &mut ctx, TestCall::new(PAUSER, unpauseCall {}.abi_encode()))
+            .unwrap();
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(PAUSER, mint_calldata(RECIPIENT, U256::from(1))),
+        );
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: unauthorized caller")));
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))),
+        )
+        .unwrap();
+    }
+
+    const RECIPIENT_2: Address = address!("0x00000000000000000000000000000000000000bb");
+    const RECIPIENT_3: Address = address!("0x00000000000000000000000000000000000000cc");
+
+    fn mint_batch_calldata(recipients: &[Address], amount: U256) -> Bytes {
+        mintBatchCall { recipients: recipients.to_vec(), amounts: vec![amount; recipients.len()] }
+            .abi_encode()
+            .into()
+    }
+
+    #[test]
+    fn test_mint_batch_at_max_size() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_max_batch_size(3);
+        let mut ctx = TestContext::new();
+        let recipients = [RECIPIENT, RECIPIENT_2, RECIPIENT_3];
+
+        let output = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_batch_calldata(&recipients, U256::from(10))),
+        )
+        .unwrap();
+
+        assert_eq!(output.gas_used, 3 * MINT_GAS_COST);
+        for recipient in recipients {
+            assert_eq!(ctx.balance(recipient), U256::from(10));
+        }
+    }
+
+    #[test]
+    fn test_mint_batch_over_max_size() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_max_batch_size(2);
+        let mut ctx = TestContext::new();
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(
+                BRIDGE,
+                mint_batch_calldata(&[RECIPIENT, RECIPIENT_2, RECIPIENT_3], U256::from(10)),
+            ),
+        );
+
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: batch too large")));
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_mint_batch_gas_scales_with_length() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(precompile.max_batch_size(), DEFAULT_MAX_BATCH_SIZE);
+        let mut ctx = TestContext::new();
+
+        let output = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_batch_calldata(&[RECIPIENT], U256::from(10))),
+        )
+        .unwrap();
+        assert_eq!(output.gas_used, MINT_GAS_COST);
+
+        // Enough gas for one mint, but not for two
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_batch_calldata(&[RECIPIENT, RECIPIENT_2], U256::from(10)))
+                .with_gas(2 * MINT_GAS_COST - 1),
+        );
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+    }
+
+    const PROXY: Address = address!("0x00000000000000000000000000000000000000dd");
+
+    #[test]
+    fn test_delegatecall_rejected() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        // The bridge calls a proxy, which delegatecalls the precompile
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))).delegated_from(PROXY),
+        );
+
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: DELEGATECALL not allowed"))
+        );
+    }
+
+    #[test]
+    fn test_delegatecall_from_trusted_proxy() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).allow_delegatecall_from(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        // A user calls the bridge proxy, which delegatecalls the precompile
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(RECIPIENT, mint_calldata(RECIPIENT, U256::from(1)))
+                .delegated_from(BRIDGE),
+        );
+        assert!(result.is_ok());
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1));
+
+        // Other proxies are still rejected
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))).delegated_from(PROXY),
+        );
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: DELEGATECALL not allowed"))
+        );
+
+        // CALLCODE is rejected even from a trusted proxy
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))).callcode_from(BRIDGE),
+        );
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: CALLCODE not allowed"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1));
+    }
+
+    #[test]
+    fn test_callcode_rejected() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))).callcode_from(BRIDGE),
+        );
+
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: CALLCODE not allowed"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+    }
+
+    fn total_supply(precompile: &NativeMinterPrecompile, ctx: &mut TestContext) -> U256 {
+        let output = run_precompile(
+            precompile,
+            ctx,
+            TestCall::new(Address::ZERO, totalSupplyCall {}.abi_encode()).static_call(),
+        )
+        .unwrap();
+        U256::abi_decode(&output.bytes).unwrap()
+    }
+
+    #[test]
+    fn test_supply_totals() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400))),
+        )
+        .unwrap();
+
+        assert_eq!(ctx.storage(TOTAL_MINTED_SLOT), U256::from(1_000));
+        assert_eq!(ctx.storage(TOTAL_BURNED_SLOT), U256::from(400));
+        assert_eq!(total_supply(&precompile, &mut ctx), U256::from(600));
+    }
+
+    #[test]
+    fn test_export_import_storage() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400))),
+        )
+        .unwrap();
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, pauseCall {}.abi_encode()))
+            .unwrap();
+
+        let exported = NativeMinterPrecompile::export_storage(&*ctx.commit()).unwrap();
+        assert_eq!(
+            exported,
+            BTreeMap::from([
+                (PAUSED_SLOT, U256::from(1)),
+                (TOTAL_MINTED_SLOT, U256::from(1_000)),
+                (TOTAL_BURNED_SLOT, U256::from(400)),
+            ])
+        );
+
+        let mut db = InMemoryDB::default();
+        NativeMinterPrecompile::import_storage(&mut db, &exported).unwrap();
+        let mut forked = TestContext::with_db(db);
+
+        assert_eq!(total_supply(&precompile, &mut forked), U256::from(600));
+        assert_eq!(forked.storage(PAUSED_SLOT), U256::from(1));
+    }
+
+    #[test]
+    fn test_per_byte_gas_scales_with_calldata() {
+        let gas_model = GasModel::PerByte { base: 5_000, per_byte: 16 };
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_gas_model(gas_model);
+        let mut ctx = TestContext::new();
+
+        let short = mint_calldata(RECIPIENT, U256::from(1));
+        let long = mint_with_message_calldata(RECIPIENT, U256::from(1), MESSAGE_ID);
+        assert!(long.len() > short.len());
+
+        let short_gas = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, short.clone()))
+            .unwrap()
+            .gas_used;
+        let long_gas = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, long.clone()))
+            .unwrap()
+            .gas_used;
+
+        assert_eq!(short_gas, MINT_GAS_COST + 16 * short.len() as u64);
+        assert_eq!(long_gas, MINT_GAS_COST + 16 * long.len() as u64);
+        assert!(long_gas > short_gas);
+
+        // Calls other than mints and burns are charged the model's base
+        let pause = pauseCall {}.abi_encode();
+        let output =
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, pause.clone())).unwrap();
+        assert_eq!(output.gas_used, 5_000 + 16 * pause.len() as u64);
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, unpauseCall {}.abi_encode()))
+            .unwrap();
+
+        // Not enough gas for the calldata-dependent part
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, short).with_gas(short_gas - 1),
+        );
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+    }
+
+    #[test]
+    fn test_flat_gas_ignores_calldata() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(precompile.gas_model(), GasModel::Flat(NATIVE_MINTER_GAS_COST));
+        let mut ctx = TestContext::new();
+
+        for calldata in [
+            mint_calldata(RECIPIENT, U256::from(1)),
+            mint_with_message_calldata(RECIPIENT, U256::from(1), MESSAGE_ID),
+        ] {
+            let output =
+                run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata)).unwrap();
+            assert_eq!(output.gas_used, MINT_GAS_COST);
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_resets_at_window_boundary() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_rate_limit(RateLimit::new(U256::from(1_000), 3_600));
+        let mut ctx = TestContext::new();
+        ctx.block.timestamp = U256::from(10_000);
+        let mint =
+            |amount: u64| TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(amount)));
+
+        run_precompile(&precompile, &mut ctx, mint(600)).unwrap();
+        assert_eq!(ctx.storage(RATE_LIMIT_START_SLOT), U256::from(10_000));
+
+        // Still within the window started at 10_000
+        ctx.block.timestamp = U256::from(10_000 + 3_599);
+        let result = run_precompile(&precompile, &mut ctx, mint(401));
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: rate limit exceeded")));
+        run_precompile(&precompile, &mut ctx, mint(400)).unwrap();
+
+        // The window ends at exactly `window_start + window_secs`
+        ctx.block.timestamp = U256::from(10_000 + 3_600);
+        run_precompile(&precompile, &mut ctx, mint(1_000)).unwrap();
+        assert_eq!(ctx.storage(RATE_LIMIT_START_SLOT), U256::from(13_600));
+        assert_eq!(ctx.storage(RATE_LIMIT_MINTED_SLOT), U256::from(1_000));
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+    }
+
+    #[test]
+    fn test_rate_limit_applies_to_batch() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_rate_limit(RateLimit::new(U256::from(25), 60));
+        let mut ctx = TestContext::new();
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(
+                BRIDGE,
+                mint_batch_calldata(&[RECIPIENT, RECIPIENT_2, RECIPIENT_3], U256::from(10)),
+            ),
+        );
+
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: rate limit exceeded")));
+    }
+
+    #[test]
+    fn test_simulate_mint() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let amount = U256::from(1_000);
+
+        let effect =
+            precompile.simulate(BRIDGE, &mint_calldata(RECIPIENT, amount), CallKind::Call).unwrap();
+
+        assert_eq!(effect, SimulatedEffect::Mint { recipient: RECIPIENT, amount });
+    }
+
+    #[test]
+    fn test_simulate_matches_call_rejections() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let calldata = mint_calldata(RECIPIENT, U256::from(1_000));
+
+        assert_eq!(
+            precompile.simulate(OTHER_BRIDGE, &calldata, CallKind::Call),
+            Err(NativeMinterError::Unauthorized)
+        );
+        assert_eq!(
+            precompile.simulate(BRIDGE, &calldata, CallKind::StaticCall),
+            Err(NativeMinterError::StaticCall)
+        );
+        assert_eq!(
+            precompile.simulate(BRIDGE, &calldata, CallKind::DelegateCall),
+            Err(NativeMinterError::DelegateCall)
+        );
+        assert_eq!(
+            precompile.simulate(BRIDGE, &calldata[..20], CallKind::Call),
+            Err(NativeMinterError::InvalidArgs("mint"))
+        );
+        assert_eq!(
+            precompile.simulate(
+                OTHER_BRIDGE,
+                &totalSupplyCall {}.abi_encode(),
+                CallKind::StaticCall
+            ),
+            Ok(SimulatedEffect::View)
+        );
+    }
+
+    #[test]
+    fn test_simulate_checks_batch() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_max_batch_size(1);
+
+        let calldata = mint_batch_calldata(&[RECIPIENT, RECIPIENT_2], U256::from(10));
+        assert_eq!(
+            precompile.simulate(BRIDGE, &calldata, CallKind::Call),
+            Err(NativeMinterError::BatchTooLarge)
+        );
+
+        let calldata = mint_batch_calldata(&[RECIPIENT], U256::from(10));
+        assert_eq!(
+            precompile.simulate(BRIDGE, &calldata, CallKind::Call),
+            Ok(SimulatedEffect::MintBatch {
+                recipients: vec![RECIPIENT],
+                amounts: vec![U256::from(10)]
+            })
+        );
+    }
+
+    #[test]
+    fn test_gas_at_exact_cost() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let mint = || TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1)));
+
+        let output = run_precompile(&precompile, &mut ctx, mint().with_gas(MINT_GAS_COST)).unwrap();
+        assert_eq!(output.gas_used, MINT_GAS_COST);
+
+        let result = run_precompile(&precompile, &mut ctx, mint().with_gas(MINT_GAS_COST - 1));
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+
+        let output =
+            run_precompile(&precompile, &mut ctx, mint().with_gas(MINT_GAS_COST + 1)).unwrap();
+        assert_eq!(output.gas_used, MINT_GAS_COST);
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2));
+    }
+
+    #[test]
+    fn test_burn_gas_at_exact_cost() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(10));
+        let burn = || TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(1)));
+
+        let output = run_precompile(&precompile, &mut ctx, burn().with_gas(BURN_GAS_COST)).unwrap();
+        assert_eq!(output.gas_used, BURN_GAS_COST);
+
+        let result = run_precompile(&precompile, &mut ctx, burn().with_gas(BURN_GAS_COST - 1));
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(9));
+    }
+
+    #[test]
+    fn test_mint_to_self_credits_caller() {
+        // mintToSelf(uint256) = keccak256("mintToSelf(uint256)")[0:4]
+        assert_eq!(<mintToSelfCall as SolCall>::SELECTOR, [0xbb, 0x6d, 0x5e, 0x3b]);
+
+        let precompile = precompile_with_roles();
+        let mut ctx = TestContext::new();
+        let calldata = mintToSelfCall { amount: U256::from(1_000) }.abi_encode();
+
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata.clone())).unwrap();
+        run_precompile(&precompile, &mut ctx, TestCall::new(MINTER, calldata.clone())).unwrap();
+        assert_eq!(ctx.balance(BRIDGE), U256::from(1_000));
+        assert_eq!(ctx.balance(MINTER), U256::from(1_000));
+
+        for caller in [OTHER_BRIDGE, BURNER] {
+            let result =
+                run_precompile(&precompile, &mut ctx, TestCall::new(caller, calldata.clone()));
+            assert_eq!(
+                result,
+                Err(PrecompileError::other_static("NativeMinter: unauthorized caller"))
+            );
+            assert_eq!(ctx.balance(caller), U256::ZERO);
+        }
+    }
+
+    fn permit_calldata(
+        precompile: &NativeMinterPrecompile,
+        signer: &PrivateKeySigner,
+        spender: Address,
+        value: U256,
+        nonce: U256,
+        deadline: U256,
+    ) -> Bytes {
+        let owner = signer.address();
+        let hash = Permit { owner, spender, value, nonce, deadline }
+            .eip712_signing_hash(&precompile.eip712_domain());
+        let signature = signer.sign_hash_sync(&hash).unwrap();
+
+        permitCall {
+            owner,
+            spender,
+            value,
+            deadline,
+            v: 27 + signature.v() as u8,
+            r: signature.r().into(),
+            s: signature.s().into(),
+        }
+        .abi_encode()
+        .into()
+    }
+
+    fn owner_signer() -> PrivateKeySigner {
+        PrivateKeySigner::from_bytes(&B256::repeat_byte(0x42)).unwrap()
+    }
+
+    #[test]
+    fn test_permit_sets_allowance() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_chain_id(1234);
+        let signer = owner_signer();
+        let owner = signer.address();
+        let mut ctx = TestContext::new();
+        ctx.block.timestamp = U256::from(1_000);
+
+        let calldata = permit_calldata(
+            &precompile,
+            &signer,
+            BRIDGE,
+            U256::from(500),
+            U256::ZERO,
+            U256::from(1_000),
+        );
+        // Anyone may submit the permit
+        run_precompile(&precompile, &mut ctx, TestCall::new(OTHER_BRIDGE, calldata)).unwrap();
+
+        assert_eq!(ctx.storage(storage::allowance_slot(owner, BRIDGE)), U256::from(500));
+        assert_eq!(ctx.storage(storage::nonce_slot(owner)), U256::from(1));
+    }
+
+    #[test]
+    fn test_permit_expired() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let signer = owner_signer();
+        let mut ctx = TestContext::new();
+        ctx.block.timestamp = U256::from(1_001);
+
+        let calldata = permit_calldata(
+            &precompile,
+            &signer,
+            BRIDGE,
+            U256::from(500),
+            U256::ZERO,
+            U256::from(1_000),
+        );
+        let result = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata));
+
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: permit expired")));
+        assert_eq!(ctx.storage(storage::allowance_slot(signer.address(), BRIDGE)), U256::ZERO);
+    }
+
+    #[test]
+    fn test_permit_replay_rejected() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let signer = owner_signer();
+        let mut ctx = TestContext::new();
+
+        let calldata =
+            permit_calldata(&precompile, &signer, BRIDGE, U256::from(500), U256::ZERO, U256::MAX);
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata.clone())).unwrap();
+
+        // Lower the allowance, then try to restore it by replaying the permit
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(
+                signer.address(),
+                approveCall { spender: BRIDGE, value: U256::from(1) }.abi_encode(),
+            ),
+        )
+        .unwrap();
+        let result = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata));
+
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: invalid signature")));
+        assert_eq!(ctx.storage(storage::allowance_slot(signer.address(), BRIDGE)), U256::from(1));
+    }
+
+    #[test]
+    fn test_burn_consumes_allowance() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_burn_allowance_required(true);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400))),
+        );
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: insufficient allowance"))
+        );
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(
+                RECIPIENT,
+                approveCall { spender: BRIDGE, value: U256::from(500) }.abi_encode(),
+            ),
+        )
+        .unwrap();
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400))),
+        )
+        .unwrap();
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+        assert_eq!(ctx.storage(storage::allowance_slot(RECIPIENT, BRIDGE)), U256::from(100));
+    }
+
+    #[test]
+    fn test_observe_only_skips_balance_changes() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_observe_only();
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT_2, U256::from(1_000));
+
+        let output = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+        assert_eq!(output.gas_used, MINT_GAS_COST);
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT_2, U256::from(400))),
+        )
+        .unwrap();
+        assert_eq!(ctx.balance(RECIPIENT_2), U256::from(1_000));
+
+        // Checks are still performed
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(OTHER_BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        );
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: unauthorized caller")));
+    }
+
+    /// Selectors of every function in the interface.
+    const ALL_SELECTORS: [[u8; 4]; 42] = [
+        <mintCall as SolCall>::SELECTOR,
+        <burn_0Call as SolCall>::SELECTOR,
+        <burn_1Call as SolCall>::SELECTOR,
+        <mintWithMessageCall as SolCall>::SELECTOR,
+        <mintWithMessageFromCall as SolCall>::SELECTOR,
+        <mintBatchCall as SolCall>::SELECTOR,
+        <mintToSelfCall as SolCall>::SELECTOR,
+        <mintWithApprovalCall as SolCall>::SELECTOR,
+        <approvalNonceCall as SolCall>::SELECTOR,
+        <approveCall as SolCall>::SELECTOR,
+        <permitCall as SolCall>::SELECTOR,
+        <pauseCall as SolCall>::SELECTOR,
+        <unpauseCall as SolCall>::SELECTOR,
+        <pauseMintCall as SolCall>::SELECTOR,
+        <unpauseMintCall as SolCall>::SELECTOR,
+        <pauseBurnCall as SolCall>::SELECTOR,
+        <unpauseBurnCall as SolCall>::SELECTOR,
+        <scheduleEmergencyMintCall as SolCall>::SELECTOR,
+        <executeEmergencyMintCall as SolCall>::SELECTOR,
+        <setGasCostCall as SolCall>::SELECTOR,
+        <proposeBridgeCall as SolCall>::SELECTOR,
+        <acceptBridgeCall as SolCall>::SELECTOR,
+        <mintAllowanceCall as SolCall>::SELECTOR,
+        <decimalsCall as SolCall>::SELECTOR,
+        <featuresCall as SolCall>::SELECTOR,
+        <mintBurnNetCall as SolCall>::SELECTOR,
+        <versionCall as SolCall>::SELECTOR,
+        <gasCostCall as SolCall>::SELECTOR,
+        <bridgeCountCall as SolCall>::SELECTOR,
+        <bridgeAtCall as SolCall>::SELECTOR,
+        <isPausedCall as SolCall>::SELECTOR,
+        <isMintPausedCall as SolCall>::SELECTOR,
+        <isBurnPausedCall as SolCall>::SELECTOR,
+        <wasProcessedCall as SolCall>::SELECTOR,
+        <totalMintedCall as SolCall>::SELECTOR,
+        <totalBurnedCall as SolCall>::SELECTOR,
+        <totalSupplyCall as SolCall>::SELECTOR,
+        <allowanceCall as SolCall>::SELECTOR,
+        <noncesCall as SolCall>::SELECTOR,
+        <DOMAIN_SEPARATORCall as SolCall>::SELECTOR,
+        <mintedToCall as SolCall>::SELECTOR,
+        [0xde, 0xad, 0xbe, 0xef],
+    ];
+
+    #[test]
+    fn test_selectors_unique() {
+        // A collision would silently route calls of one function to another
+        let selectors: BTreeSet<_> = ALL_SELECTORS.iter().collect();
+        assert_eq!(selectors.len(), ALL_SELECTORS.len());
+
+        for selector in VIEW_SELECTORS
+            .iter()
+            .chain(&PUBLIC_SELECTORS)
+            .chain(&EMERGENCY_SELECTORS)
+            .chain(&GOVERNOR_SELECTORS)
+        {
+            assert!(selectors.contains(selector), "{selector:?} missing from ALL_SELECTORS");
+        }
+    }
+
+    /// Runs `data` from the bridge against a fresh state, which must not panic.
+    fn call_never_panics(data: &[u8]) {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_max_batch_size(8);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+
+        // Any outcome is fine, as long as it is an output or a `PrecompileError`
+        let _ = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data.to_vec()));
+    }
+
+    #[test]
+    fn test_malformed_calldata_corpus() {
+        let valid: [Bytes; 5] = [
+            mint_calldata(RECIPIENT, U256::from(1)),
+            burn_calldata(RECIPIENT, U256::from(1)),
+            mint_with_message_calldata(RECIPIENT, U256::from(1), MESSAGE_ID),
+            mint_batch_calldata(&[RECIPIENT, RECIPIENT_2], U256::from(1)),
+            approveCall { spender: BRIDGE, value: U256::MAX }.abi_encode().into(),
+        ];
+
+        // Every truncation of valid calldata, including the selector itself
+        for calldata in &valid {
+            for len in 0..calldata.len() {
+                call_never_panics(&calldata[..len]);
+            }
+        }
+
+        // Dynamic arrays with out-of-range offsets and lengths
+        let mut batch = mint_batch_calldata(&[RECIPIENT], U256::from(1)).to_vec();
+        for word in [4, 36, 68, 132] {
+            let mut malformed = batch.clone();
+            malformed[word..word + 32].fill(0xff);
+            call_never_panics(&malformed);
+        }
+        batch.truncate(4 + 64);
+        call_never_panics(&batch);
+
+        // Permits with an invalid `v`
+        let mut permit = permitCall {
+            owner: RECIPIENT,
+            spender: BRIDGE,
+            value: U256::from(1),
+            deadline: U256::MAX,
+            v: 0,
+            r: B256::ZERO,
+            s: B256::ZERO,
+        }
+        .abi_encode();
+        call_never_panics(&permit);
+        permit[4 + 4 * 32 + 31] = 0xff;
+        call_never_panics(&permit);
+    }
+
+    proptest! {
+        #[test]
+        fn fuzz_random_calldata(data in proptest::collection::vec(any::<u8>(), 0..512)) {
+            call_never_panics(&data);
+        }
+
+        #[test]
+        fn fuzz_calldata_with_known_selector(
+            selector in proptest::sample::select(ALL_SELECTORS.to_vec()),
+            args in proptest::collection::vec(any::<u8>(), 0..512),
+        ) {
+            call_never_panics(&[selector.as_slice(), &args].concat());
+        }
+    }
+
+    #[test]
+    fn test_mint_dedup_within_tx() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_mint_dedup(true);
+        let mut ctx = TestContext::new();
+        let mint = |recipient, amount: u64| {
+            TestCall::new(BRIDGE, mint_calldata(recipient, U256::from(amount)))
+        };
+
+        // Both calls share the context's transient storage, as within a single transaction
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT, 1_000)).unwrap();
+        let result = run_precompile(&precompile, &mut ctx, mint(RECIPIENT, 1_000));
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: duplicate mint in tx"))
+        );
+
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT, 999)).unwrap();
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT_2, 1_000)).unwrap();
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_999));
+        assert_eq!(ctx.balance(RECIPIENT_2), U256::from(1_000));
+    }
+
+    #[test]
+    fn test_mint_dedup_disabled_by_default() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert!(!precompile.mint_dedup());
+        let mut ctx = TestContext::new();
+
+        for _ in 0..2 {
+            run_precompile(
+                &precompile,
+                &mut ctx,
+                TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+            )
+            .unwrap();
+        }
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+    }
+
+    #[test]
+    fn test_supply_cap() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_supply_cap(U256::from(1_000));
+        let mut ctx = TestContext::new();
+        let mint =
+            |amount: u64| TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(amount)));
+
+        run_precompile(&precompile, &mut ctx, mint(1_000)).unwrap();
+        let result = run_precompile(&precompile, &mut ctx, mint(1));
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: supply cap exceeded")));
+
+        // Burning frees up room under the cap
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(300))),
+        )
+        .unwrap();
+        run_precompile(&precompile, &mut ctx, mint(300)).unwrap();
+        assert_eq!(total_supply(&precompile, &mut ctx), U256::from(1_000));
+    }
+
+    #[test]
+    fn test_mint_fee() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_mint_fee(MintFee::new(250, RECIPIENT_3));
+        let mut ctx = TestContext::new();
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(975));
+        assert_eq!(ctx.balance(RECIPIENT_3), U256::from(25));
+        assert_eq!(ctx.storage(TOTAL_MINTED_SLOT), U256::from(1_000));
+    }
+
+    #[test]
+    fn test_mint_fee_does_not_overflow() {
+        let fee = MintFee::new(MintFee::MAX_BPS, RECIPIENT_3);
+        assert_eq!(fee.of(U256::MAX), U256::MAX);
+        assert_eq!(MintFee::new(1, RECIPIENT_3).of(U256::from(19_999)), U256::from(1));
+
+        // A fee above 100% is clamped rather than exceeding, or wrapping around, the amount
+        let fee = MintFee::new(u16::MAX, RECIPIENT_3);
+        assert_eq!(fee.of(U256::from(1_000)), U256::from(1_000));
+        assert_eq!(fee.of(U256::MAX), U256::MAX);
+    }
+
+    #[test]
+    fn test_unvalidated_mint_fee_above_max() {
+        // Builders don't validate the fee, so the mint must not underflow on its own
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_mint_fee(MintFee::new(20_000, RECIPIENT_3))
+            .with_saturating_mint(true);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(5));
+
+        let mint = TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+        run_precompile(&precompile, &mut ctx, mint).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(5));
+        assert_eq!(ctx.balance(RECIPIENT_3), U256::from(1_000));
+        assert_eq!(ctx.storage(TOTAL_MINTED_SLOT), U256::from(1_000));
+    }
+
+    #[test]
+    fn test_mint_and_burn_gas_costs() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        let mint = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+        let burn = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+
+        assert_eq!(mint.gas_used, MINT_GAS_COST);
+        assert_eq!(burn.gas_used, BURN_GAS_COST);
+    }
+
+    #[test]
+    fn test_custom_mint_and_burn_gas_costs() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_mint_gas_cost(1_000).with_burn_gas_cost(2_000);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+
+        let burn = || TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(1)));
+        let result = run_precompile(&precompile, &mut ctx, burn().with_gas(1_999));
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+        assert_eq!(run_precompile(&precompile, &mut ctx, burn()).unwrap().gas_used, 2_000);
+
+        let output = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_batch_calldata(&[RECIPIENT, RECIPIENT_2], U256::from(1))),
+        )
+        .unwrap();
+        assert_eq!(output.gas_used, 2 * 1_000);
+    }
+
+    #[test]
+    fn test_erc20_transfer_events() {
+        // Transfer(address,address,uint256)
+        assert_eq!(
+            Transfer::SIGNATURE_HASH,
+            b256!("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_event_scheme(EventScheme::Erc20Transfer);
+        let mut ctx = TestContext::new();
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400))),
+        )
+        .unwrap();
+
+        let logs = ctx.take_logs();
+        assert_eq!(logs.len(), 2);
+        assert!(logs.iter().all(|log| log.address == NATIVE_MINTER_ADDRESS));
+
+        let (mint, burn) = (&logs[0].data, &logs[1].data);
+        assert_eq!(
+            mint.topics(),
+            [Transfer::SIGNATURE_HASH, Address::ZERO.into_word(), RECIPIENT.into_word()]
+        );
+        assert_eq!(mint.data, Bytes::from(U256::from(1_000).abi_encode()));
+        assert_eq!(
+            burn.topics(),
+            [Transfer::SIGNATURE_HASH, RECIPIENT.into_word(), Address::ZERO.into_word()]
+        );
+        assert_eq!(burn.data, Bytes::from(U256::from(400).abi_encode()));
+    }
+
+    #[test]
+    fn test_event_schemes() {
+        let mint = TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+
+        let mut ctx = TestContext::new();
+        run_precompile(&NativeMinterPrecompile::new(BRIDGE), &mut ctx, mint.clone()).unwrap();
+        assert!(ctx.take_logs().is_empty());
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_event_scheme(EventScheme::Native);
+        run_precompile(&precompile, &mut ctx, mint.clone()).unwrap();
+        let logs = ctx.take_logs();
+        assert_eq!(logs.len(), 1);
+        let event = Mint::decode_log_data(&logs[0].data).unwrap();
+        assert_eq!((event.recipient, event.amount), (RECIPIENT, U256::from(1_000)));
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_event_scheme(EventScheme::Both);
+        run_precompile(&precompile, &mut ctx, mint).unwrap();
+        let topics: Vec<_> = ctx.take_logs().iter().map(|log| log.data.topics()[0]).collect();
+        assert_eq!(topics, [Mint::SIGNATURE_HASH, Transfer::SIGNATURE_HASH]);
+    }
+
+    #[test]
+    fn test_activation_block() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_activation_block(100);
+        let mut ctx = TestContext::new();
+        let mint = || TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+        let burn = || TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400)));
+        let not_active = Err(PrecompileError::other_static("NativeMinter: not yet active"));
+
+        ctx.block.number = U256::from(99);
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint()), not_active);
+        assert_eq!(run_precompile(&precompile, &mut ctx, burn()), not_active);
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+
+        ctx.block.number = U256::from(100);
+        run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        run_precompile(&precompile, &mut ctx, burn()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+
+        ctx.block.number = U256::from(101);
+        run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        run_precompile(&precompile, &mut ctx, burn()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_200));
+    }
+
+    const OWNER: Address = address!("0x00000000000000000000000000000000000000dd");
+
+    fn emergency_mint_calldata(schedule: bool, recipient: Address, amount: U256) -> Bytes {
+        if schedule {
+            scheduleEmergencyMintCall { recipient, amount }.abi_encode().into()
+        } else {
+            executeEmergencyMintCall { recipient, amount }.abi_encode().into()
+        }
+    }
+
+    #[test]
+    fn test_emergency_mint_timelock() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_emergency_mint(EmergencyMint::new(OWNER, 3_600));
+        let mut ctx = TestContext::new();
+        let amount = U256::from(1_000);
+        let schedule = TestCall::new(OWNER, emergency_mint_calldata(true, RECIPIENT, amount));
+        let execute = || TestCall::new(OWNER, emergency_mint_calldata(false, RECIPIENT, amount));
+
+        ctx.block.timestamp = U256::from(10_000);
+        run_precompile(&precompile, &mut ctx, schedule).unwrap();
+        assert_eq!(
+            ctx.storage(storage::emergency_mint_slot(RECIPIENT, amount)),
+            U256::from(10_000)
+        );
+
+        // Executing before the delay has elapsed reverts
+        ctx.block.timestamp = U256::from(10_000 + 3_599);
+        let result = run_precompile(&precompile, &mut ctx, execute());
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: emergency mint timelocked"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+
+        ctx.block.timestamp = U256::from(10_000 + 3_600);
+        run_precompile(&precompile, &mut ctx, execute()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), amount);
+        assert_eq!(total_supply(&precompile, &mut ctx), amount);
+
+        // The schedule is consumed by the execution
+        let result = run_precompile(&precompile, &mut ctx, execute());
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: emergency mint not scheduled"))
+        );
+    }
+
+    #[test]
+    fn test_emergency_mint_only_owner() {
+        let calldata = emergency_mint_calldata(true, RECIPIENT, U256::from(1_000));
+        let unauthorized = Err(PrecompileError::other_static("NativeMinter: unauthorized caller"));
+
+        // Disabled by default, even for the bridge
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let result = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata.clone()));
+        assert_eq!(result, unauthorized);
+
+        let precompile = precompile.with_emergency_mint(EmergencyMint::new(OWNER, 3_600));
+        let result = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata));
+        assert_eq!(result, unauthorized);
+
+        // The owner can't mint without the timelock
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(OWNER, mint_calldata(RECIPIENT, U256::from(1))),
+        );
+        assert_eq!(result, unauthorized);
+    }
+
+    #[test]
+    fn test_expected_output_wire_format() {
+        let amount = U256::from(1_000);
+        let mint = SimulatedEffect::Mint { recipient: RECIPIENT, amount };
+        let burn = SimulatedEffect::Burn { from: RECIPIENT, amount };
+        let approve = SimulatedEffect::Approve { owner: RECIPIENT, spender: BRIDGE, value: amount };
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(precompile.expected_output(&mint, U256::ZERO), Some(Bytes::new()));
+        assert_eq!(precompile.expected_output(&burn, amount), Some(Bytes::new()));
+        assert_eq!(
+            precompile.expected_output(&approve, U256::ZERO),
+            Some(bytes!("0x0000000000000000000000000000000000000000000000000000000000000001"))
+        );
+        assert_eq!(precompile.expected_output(&SimulatedEffect::View, U256::ZERO), None);
+
+        // Clamped burns return the burned amount, capped at the balance
+        let precompile = precompile.with_burn_mode(BurnMode::Clamp);
+        assert_eq!(
+            precompile.expected_output(&burn, U256::from(600)),
+            Some(bytes!("0x0000000000000000000000000000000000000000000000000000000000000258"))
+        );
+        assert_eq!(
+            precompile.expected_output(&burn, U256::from(5_000)),
+            Some(bytes!("0x00000000000000000000000000000000000000000000000000000000000003e8"))
+        );
+    }
+
+    #[test]
+    fn test_expected_output_matches_call() {
+        for burn_mode in [BurnMode::Revert, BurnMode::Clamp] {
+            let precompile = NativeMinterPrecompile::new(BRIDGE).with_burn_mode(burn_mode);
+            let mut ctx = TestContext::new();
+            ctx.set_balance(RECIPIENT, U256::from(600));
+
+            for data in [
+                mint_calldata(RECIPIENT, U256::from(1_000)),
+                burn_calldata(RECIPIENT, U256::from(400)),
+                burn_calldata(RECIPIENT, U256::from(10_000)),
+            ] {
+                let effect = precompile.simulate(BRIDGE, &data, CallKind::Call).unwrap();
+                let expected = precompile.expected_output(&effect, ctx.balance(RECIPIENT));
+
+                match run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data)) {
+                    Ok(output) => assert_eq!(Some(output.bytes), expected),
+                    Err(err) => {
+                        assert_eq!(burn_mode, BurnMode::Revert);
+                        assert_eq!(
+                            err,
+                            PrecompileError::other_static("NativeMinter: insufficient balance")
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_log_target() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_log_target("chainA::native_minter");
+        let mut ctx = TestContext::new();
+        let logs = CapturedLogs::capture(|| {
+            let result = run_precompile(
+                &precompile,
+                &mut ctx,
+                TestCall::new(RECIPIENT, mint_calldata(RECIPIENT, U256::from(1))),
+            );
+            assert!(result.is_err());
+        });
+
+        assert!(logs.contains("native_minter{log_target=chainA::native_minter}"), "{logs}");
+        assert!(logs.contains("NativeMinter: unauthorized caller"), "{logs}");
+    }
+
+    fn minted_to(
+        precompile: &NativeMinterPrecompile,
+        ctx: &mut TestContext,
+        recipient: Address,
+    ) -> U256 {
+        let output = run_precompile(
+            precompile,
+            ctx,
+            TestCall::new(RECIPIENT, mintedToCall { recipient }.abi_encode()).static_call(),
+        )
+        .unwrap();
+        U256::abi_decode(&output.bytes).unwrap()
+    }
+
+    #[test]
+    fn test_minted_to_tracking() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_minted_to_tracking(true);
+        let mut ctx = TestContext::new();
+        let mint = |recipient, amount: u64| {
+            TestCall::new(BRIDGE, mint_calldata(recipient, U256::from(amount)))
+        };
+
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT, 1_000)).unwrap();
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT_2, 300)).unwrap();
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT, 500)).unwrap();
+
+        assert_eq!(minted_to(&precompile, &mut ctx, RECIPIENT), U256::from(1_500));
+        assert_eq!(minted_to(&precompile, &mut ctx, RECIPIENT_2), U256::from(300));
+        assert_eq!(minted_to(&precompile, &mut ctx, RECIPIENT_3), U256::ZERO);
+    }
+
+    #[test]
+    fn test_minted_to_tracking_disabled_by_default() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+
+        assert_eq!(minted_to(&precompile, &mut ctx, RECIPIENT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_edge_contexts_never_panic() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let calldata: [Bytes; 4] = [
+            Bytes::new(),
+            Bytes::from_static(&[0x40]),
+            Bytes::from(<mintCall as SolCall>::SELECTOR.to_vec()),
+            Bytes::from(<wasProcessedCall as SolCall>::SELECTOR.to_vec()),
+        ];
+
+        for data in calldata {
+            let call = TestCall::new(BRIDGE, data);
+            for call in [
+                call.clone(),
+                call.clone().with_gas(0),
+                call.clone().static_call(),
+                call.clone().delegated_from(PROXY),
+                call.clone().callcode_from(PROXY),
+            ] {
+                let result = run_precompile(&precompile, &mut TestContext::new(), call.clone());
+                assert!(result.is_err(), "{call:?} succeeded");
+            }
+        }
+    }
+
+    const TOKEN: Address = address!("0x00000000000000000000000000000000000000ee");
+
+    #[test]
+    fn test_erc20_mint_target() {
+        let target = Erc20Target::openzeppelin(TOKEN);
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_mint_target(MintTarget::Erc20(target));
+        let mut ctx = TestContext::new();
+        let balance_slot = storage::mapping_slot(RECIPIENT.into_word(), U256::ZERO);
+        let total_supply_slot = U256::from(2);
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+
+        assert_eq!(ctx.storage_at(TOKEN, balance_slot), U256::from(1_000));
+        assert_eq!(ctx.storage_at(TOKEN, total_supply_slot), U256::from(1_000));
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+
+        let logs = ctx.take_logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].address, TOKEN);
+        let event = Transfer::decode_log_data(&logs[0].data).unwrap();
+        assert_eq!(
+            (event.from, event.to, event.value),
+            (Address::ZERO, RECIPIENT, U256::from(1_000))
+        );
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400))),
+        )
+        .unwrap();
+
+        assert_eq!(ctx.storage_at(TOKEN, balance_slot), U256::from(600));
+        assert_eq!(ctx.storage_at(TOKEN, total_supply_slot), U256::from(600));
+
+        // Burns are checked against the token balance
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(601))),
+        );
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: insufficient balance"))
+        );
+    }
+
+    #[test]
+    fn test_mint_to_contract_does_not_run_its_code() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+
+        // A recipient whose code always reverts: PUSH0 PUSH0 REVERT
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            RECIPIENT,
+            AccountInfo::from_bytecode(Bytecode::new_raw(bytes!("0x5f5ffd"))),
+        );
+        let mut ctx = TestContext::with_db(db);
+
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000))),
+        )
+        .unwrap();
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+    }
+
+    const GOVERNOR: Address = address!("0x00000000000000000000000000000000000000ff");
+
+    #[test]
+    fn test_governor_sets_gas_cost() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_governor(GOVERNOR);
+        let mut ctx = TestContext::new();
+        let mint = || TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1)));
+        let set_gas_cost = |gas_cost| setGasCostCall { gasCost: gas_cost }.abi_encode();
+
+        // The configured cost applies while the slot is uninitialized
+        let output = run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        assert_eq!(output.gas_used, MINT_GAS_COST);
+
+        let output =
+            run_precompile(&precompile, &mut ctx, TestCall::new(GOVERNOR, set_gas_cost(9_000)))
+                .unwrap();
+        assert_eq!(output.gas_used, NATIVE_MINTER_GAS_COST);
+        assert_eq!(ctx.storage(GAS_COST_SLOT), U256::from(9_000));
+
+        let output = run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        assert_eq!(output.gas_used, 9_000);
+        let result = run_precompile(&precompile, &mut ctx, mint().with_gas(8_999));
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+
+        // Views keep their own cost
+        let view = TestCall::new(RECIPIENT, totalSupplyCall {}.abi_encode()).static_call();
+        let output = run_precompile(&precompile, &mut ctx, view).unwrap();
+        assert_eq!(output.gas_used, VIEW_GAS_COST);
+
+        // Zero restores the configured cost
+        run_precompile(&precompile, &mut ctx, TestCall::new(GOVERNOR, set_gas_cost(0))).unwrap();
+        let output = run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        assert_eq!(output.gas_used, MINT_GAS_COST);
+    }
+
+    #[test]
+    fn test_set_gas_cost_only_governor() {
+        let calldata = setGasCostCall { gasCost: 1 }.abi_encode();
+        let unauthorized = Err(PrecompileError::other_static("NativeMinter: unauthorized caller"));
+        let mut ctx = TestContext::new();
+
+        // Without a governor nobody can set the gas cost, not even the bridge
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let result = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata.clone()));
+        assert_eq!(result, unauthorized);
+
+        let precompile = precompile.with_governor(GOVERNOR);
+        let result = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata));
+        assert_eq!(result, unauthorized);
+        assert_eq!(ctx.storage(GAS_COST_SLOT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_return_bool() {
+        let amount = U256::from(1_000);
+        let mint = || TestCall::new(BRIDGE, mint_calldata(RECIPIENT, amount));
+        let burn = || TestCall::new(BRIDGE, burn_calldata(RECIPIENT, amount));
+
+        // Empty output by default
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert!(!precompile.return_bool());
+        let mut ctx = TestContext::new();
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint()).unwrap().bytes, Bytes::new());
+        assert_eq!(run_precompile(&precompile, &mut ctx, burn()).unwrap().bytes, Bytes::new());
+
+        let precompile = precompile.with_return_bool(true);
+        let mut ctx = TestContext::new();
+        let output = run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        assert_eq!(output.bytes.len(), 32);
+        assert_eq!(output.bytes, Bytes::from(true.abi_encode()));
+        let output = run_precompile(&precompile, &mut ctx, burn()).unwrap();
+        assert_eq!(output.bytes, Bytes::from(true.abi_encode()));
+
+        let effect = SimulatedEffect::Mint { recipient: RECIPIENT, amount };
+        assert_eq!(precompile.expected_output(&effect, U256::ZERO), Some(true.abi_encode().into()));
+    }
+
+    #[test]
+    fn test_execute_mint_and_burn_directly() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let call = TestCall::new(BRIDGE, Bytes::new());
+
+        {
+            let mut input = call.input(&mut ctx);
+            precompile.execute_mint(&mut input, RECIPIENT, U256::from(1_000)).unwrap();
+            precompile.execute_burn(&mut input, RECIPIENT, U256::from(400)).unwrap();
+        }
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+
+        let result = precompile.execute_burn(&mut call.input(&mut ctx), RECIPIENT, U256::from(601));
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: insufficient balance"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+    }
+
+    #[test]
+    fn test_decimals() {
+        let decimals = |precompile: &NativeMinterPrecompile| {
+            // Callable by anyone, including via STATICCALL
+            let call = TestCall::new(RECIPIENT, decimalsCall {}.abi_encode()).static_call();
+            let output = run_precompile(precompile, &mut TestContext::new(), call).unwrap();
+            decimalsCall::abi_decode_returns(&output.bytes).unwrap()
+        };
+
+        assert_eq!(decimals(&NativeMinterPrecompile::new(BRIDGE)), 18);
+        assert_eq!(decimals(&NativeMinterPrecompile::new(BRIDGE).with_decimals(6)), 6);
+    }
+
+    #[test]
+    fn test_scale_to_asset() {
+        let usdc = Address::with_last_byte(0x06);
+        let wbtc = Address::with_last_byte(0x08);
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_asset_decimals(usdc, 6)
+            .with_asset_decimals(wbtc, 8)
+            .with_asset_decimals(TOKEN, 24);
+        let amount = U256::from(1_500_000_000_000_000_000u64);
+
+        assert_eq!(precompile.asset_decimals(usdc), 6);
+        assert_eq!(precompile.scale_to_asset(usdc, amount), Some(U256::from(1_500_000)));
+        assert_eq!(precompile.scale_to_asset(wbtc, amount), Some(U256::from(150_000_000)));
+        assert_eq!(precompile.scale_to_asset(TOKEN, amount), Some(amount * U256::from(1_000_000)));
+
+        // The native token, and assets without configured decimals, use the native decimals
+        assert_eq!(precompile.asset_decimals(NATIVE_MINTER_ADDRESS), 18);
+        assert_eq!(precompile.scale_to_asset(NATIVE_MINTER_ADDRESS, amount), Some(amount));
+        let precompile = precompile.with_decimals(6);
+        assert_eq!(precompile.scale_to_asset(RECIPIENT, U256::from(7)), Some(U256::from(7)));
+        assert_eq!(precompile.scale_to_asset(wbtc, U256::from(7)), Some(U256::from(700)));
+
+        // Dust below the asset's precision and overflows don't convert
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_asset_decimals(usdc, 6);
+        assert_eq!(precompile.scale_to_asset(usdc, amount + U256::from(1)), None);
+        let precompile = precompile.with_asset_decimals(TOKEN, 24);
+        assert_eq!(precompile.scale_to_asset(TOKEN, U256::MAX), None);
+    }
+
+    #[test]
+    fn test_max_amount_per_call() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_max_amount_per_call(U256::from(1_000));
+        let exceeds_cap =
+            Err(PrecompileError::other_static("NativeMinter: amount exceeds per-call cap"));
+        let mut ctx = TestContext::new();
+        let call = |ctx: &mut TestContext, data| {
+            run_precompile(&precompile, ctx, TestCall::new(BRIDGE, data))
+        };
+
+        assert!(call(&mut ctx, mint_calldata(RECIPIENT, U256::from(999))).is_ok());
+        assert!(call(&mut ctx, mint_calldata(RECIPIENT, U256::from(1_000))).is_ok());
+        assert_eq!(call(&mut ctx, mint_calldata(RECIPIENT, U256::from(1_001))), exceeds_cap);
+
+        assert!(call(&mut ctx, burn_calldata(RECIPIENT, U256::from(999))).is_ok());
+        assert!(call(&mut ctx, burn_calldata(RECIPIENT, U256::from(1_000))).is_ok());
+        assert_eq!(call(&mut ctx, burn_calldata(RECIPIENT, U256::from(1_001))), exceeds_cap);
+
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+        assert_eq!(NativeMinterPrecompile::new(BRIDGE).max_amount_per_call(), U256::MAX);
+    }
+
+    #[test]
+    fn test_features() {
+        let features = |precompile: &NativeMinterPrecompile| {
+            let call = TestCall::new(RECIPIENT, featuresCall {}.abi_encode()).static_call();
+            let output = run_precompile(precompile, &mut TestContext::new(), call).unwrap();
+            U256::abi_decode(&output.bytes).unwrap()
+        };
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(precompile.features(), Features::default());
+        assert_eq!(features(&precompile), U256::ZERO);
+
+        let precompile =
+            precompile.add_pauser(RECIPIENT).with_mint_fee(MintFee::new(250, RECIPIENT_2));
+        assert!(precompile.features().contains(Features::PAUSABLE));
+        assert!(precompile.features().contains(Features::MINT_FEE));
+        assert!(!precompile.features().contains(Features::RATE_LIMIT));
+        assert_eq!(features(&precompile), U256::from(0b110));
+    }
+
+    #[test]
+    fn test_strict_calldata() {
+        let calldata = mint_calldata(RECIPIENT, U256::from(1));
+        let trailing = [calldata.as_ref(), &[0u8; 32]].concat();
+        let mut ctx = TestContext::new();
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert!(precompile.strict_calldata());
+        assert!(run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, calldata)).is_ok());
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, trailing.clone())),
+            Err(PrecompileError::other_static("NativeMinter: unexpected trailing calldata"))
+        );
+        let view = [totalMintedCall {}.abi_encode().as_slice(), &[0]].concat();
+        assert!(run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, view)).is_err());
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1));
+
+        let precompile = precompile.with_strict_calldata(false);
+        assert!(run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, trailing)).is_ok());
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2));
+    }
+
+    #[test]
+    fn test_mint_burn_net() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let net = |delta: i64| {
+            let delta = I256::try_from(delta).unwrap();
+            TestCall::new(BRIDGE, mintBurnNetCall { account: RECIPIENT, delta }.abi_encode())
+        };
+
+        // A positive delta credits, a negative one debits
+        let output = run_precompile(&precompile, &mut ctx, net(1_000)).unwrap();
+        assert_eq!(output.gas_used, BURN_GAS_COST);
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+        run_precompile(&precompile, &mut ctx, net(-400)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+        assert_eq!(ctx.storage(TOTAL_MINTED_SLOT), U256::from(1_000));
+        assert_eq!(ctx.storage(TOTAL_BURNED_SLOT), U256::from(400));
+
+        // A negative delta exceeding the balance reverts
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, net(-601)),
+            Err(PrecompileError::other_static("NativeMinter: insufficient balance"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+
+        // Only the bridge may call it
+        let result =
+            run_precompile(&precompile, &mut ctx, TestCall { caller: RECIPIENT, ..net(1) });
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: unauthorized caller")));
+    }
+
+    #[test]
+    fn test_version() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+
+        let call = TestCall::new(RECIPIENT, versionCall {}.abi_encode()).static_call();
+        let output = run_precompile(&precompile, &mut ctx, call).unwrap();
+        assert_eq!(versionCall::abi_decode_returns(&output.bytes).unwrap(), LOGIC_VERSION);
+    }
+
+    #[test]
+    fn test_native_minter_never_authorizes_itself() {
+        let precompile = NativeMinterPrecompile::new(NATIVE_MINTER_ADDRESS);
+        let mut ctx = TestContext::new();
+
+        let result = run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(NATIVE_MINTER_ADDRESS, mint_calldata(RECIPIENT, U256::from(1))),
+        );
+        assert_eq!(result, Err(PrecompileError::other_static("NativeMinter: unauthorized caller")));
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_burn_from_missing_account() {
+        let burn =
+            |amount: u64| TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(amount)));
+        let mut ctx = TestContext::new();
+
+        // By default a fresh account is burned from like any other
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, burn(1)),
+            Err(PrecompileError::other_static("NativeMinter: insufficient balance"))
+        );
+        assert!(run_precompile(&precompile, &mut ctx, burn(0)).is_ok());
+
+        let precompile = precompile.with_reject_missing_account_burn(true);
+        for amount in [0, 1] {
+            assert_eq!(
+                run_precompile(&precompile, &mut ctx, burn(amount)),
+                Err(PrecompileError::other_static("NativeMinter: account does not exist"))
+            );
+        }
+
+        // Once the account exists, burns are checked against its balance
+        ctx.set_balance(RECIPIENT, U256::from(1));
+        assert!(run_precompile(&precompile, &mut ctx, burn(1)).is_ok());
+    }
+
+    #[test]
+    fn test_calls_counted_by_selector_and_outcome() {
+        use metrics_util::{
+            debugging::{DebugValue, DebuggingRecorder},
+            MetricKind,
+        };
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            let mint = || mint_calldata(RECIPIENT, U256::from(1));
+            let _ = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, mint()));
+            let _ = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, mint()));
+            let _ = run_precompile(&precompile, &mut ctx, TestCall::new(RECIPIENT, mint()));
+            let burn = burn_calldata(RECIPIENT, U256::from(5));
+            let _ = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, burn));
+            let _ = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, vec![0xde, 0xad]));
+        });
+
+        let mut counts = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter(|(key, ..)| {
+                key.kind() == MetricKind::Counter
+                    && key.key().name() == "rkb_native_minter_calls_total"
+            })
+            .map(|(key, _, _, value)| {
+                let label = |name| {
+                    key.key().labels().find(|label| label.key() == name).unwrap().value().to_owned()
+                };
+                let DebugValue::Counter(count) = value else { unreachable!() };
+                (label("selector"), label("outcome"), count)
+            })
+            .collect::<Vec<_>>();
+        counts.sort();
+
+        assert_eq!(
+            counts,
+            [
+                ("burn".to_owned(), "revert".to_owned(), 1),
+                ("mint".to_owned(), "revert".to_owned(), 1),
+                ("mint".to_owned(), "success".to_owned(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_functions_cover_interface() {
+        for selector in &ALL_SELECTORS[..ALL_SELECTORS.len() - 1] {
+            assert!(FUNCTIONS.iter().any(|(function, _)| function == selector), "{selector:?}");
+        }
+    }
+
+    /// Returns the canonical signatures of the functions declared by the `INativeMinter`
+    /// interface in the module docs, such as `mint(address,uint256)`.
+    fn documented_signatures() -> Vec<String> {
+        let docs = include_str!("native_minter.rs")
+            .lines()
+            .filter_map(|line| line.strip_prefix("//!"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let (_, interface) = docs.split_once("interface INativeMinter {").unwrap();
+        let (interface, _) = interface.split_once('}').unwrap();
+
+        interface
+            .split(';')
+            .filter_map(|declaration| {
+                let (name, rest) = declaration.trim().strip_prefix("function ")?.split_once('(')?;
+                let (params, _) = rest.split_once(')')?;
+                let types: Vec<_> =
+                    params.split(',').filter_map(|param| param.split_whitespace().next()).collect();
+                Some(format!("{name}({})", types.join(",")))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_documented_interface_conformance() {
+        let signatures = documented_signatures();
+        assert_eq!(signatures[..2], ["mint(address,uint256)", "burn(address,uint256)"]);
+
+        for signature in &signatures {
+            let (name, _) = signature.split_once('(').unwrap();
+            let (_, function) = FUNCTIONS
+                .iter()
+                .find(|(selector, _)| selector[..] == keccak256(signature)[..4])
+                .unwrap_or_else(|| panic!("{signature} is documented but not implemented"));
+            assert_eq!(*function, name, "{signature}");
+        }
+
+        // Every implemented function is documented, and only once
+        let unique: BTreeSet<_> = signatures.iter().collect();
+        assert_eq!(unique.len(), signatures.len());
+        assert_eq!(signatures.len(), FUNCTIONS.len());
+    }
+
+    #[test]
+    fn test_allowance_output() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_burn_allowance_required(true)
+            .with_allowance_output(true);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+
+        let approve = approveCall { spender: BRIDGE, value: U256::from(700) }.abi_encode();
+        let output =
+            run_precompile(&precompile, &mut ctx, TestCall::new(RECIPIENT, approve)).unwrap();
+        assert_eq!(U256::abi_decode(&output.bytes).unwrap(), U256::from(700));
+
+        // A burn consuming the allowance returns what is left of it
+        let burn = burn_calldata(RECIPIENT, U256::from(300));
+        let output = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, burn)).unwrap();
+        assert_eq!(U256::abi_decode(&output.bytes).unwrap(), U256::from(400));
+
+        // A burn from the caller itself consumes no allowance and keeps its usual output
+        ctx.set_balance(BRIDGE, U256::from(1_000));
+        let burn = burn_calldata(BRIDGE, U256::from(100));
+        let output = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, burn)).unwrap();
+        assert!(output.bytes.is_empty());
+    }
+
+    #[test]
+    fn test_min_base_fee() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_min_base_fee(1_000);
+        let mut ctx = TestContext::new();
+        let mint = || TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+        let burn = || TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400)));
+
+        ctx.block.basefee = 999;
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, mint()),
+            Err(PrecompileError::other_static("NativeMinter: base fee below minimum"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+
+        ctx.block.basefee = 1_000;
+        run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+
+        // Burns are allowed regardless of the base fee
+        ctx.block.basefee = 0;
+        run_precompile(&precompile, &mut ctx, burn()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+
+        ctx.block.basefee = 2_000;
+        run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_600));
+    }
+
+    #[test]
+    fn test_out_of_gas_log() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let logs = CapturedLogs::capture(|| {
+            let result = run_precompile(
+                &precompile,
+                &mut ctx,
+                TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1)))
+                    .with_gas(NATIVE_MINTER_GAS_COST - 1),
+            );
+            assert_eq!(result, Err(PrecompileError::OutOfGas));
+        });
+
+        let expected = format!(
+            "NativeMinter: out of gas (have {}, need {})",
+            NATIVE_MINTER_GAS_COST - 1,
+            NATIVE_MINTER_GAS_COST
+        );
+        assert!(logs.contains(&expected), "{logs}");
+    }
+
+    #[test]
+    fn test_mint_channel_drops_records_when_full() {
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(2);
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_mint_channel(sender);
+        let mut ctx = TestContext::new();
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            let mint = mint_calldata(RECIPIENT, U256::from(1_000));
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, mint.clone())).unwrap();
+            let burn = burn_calldata(RECIPIENT, U256::from(400));
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, burn)).unwrap();
+            // The channel is full, the call still succeeds
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, mint)).unwrap();
+        });
+
+        assert_eq!(
+            receiver.try_recv().unwrap(),
+            MintBurnRecord::Mint { recipient: RECIPIENT, amount: U256::from(1_000) }
+        );
+        assert_eq!(
+            receiver.try_recv().unwrap(),
+            MintBurnRecord::Burn { from: RECIPIENT, amount: U256::from(400) }
+        );
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_600));
+
+        let dropped = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .find(|(key, ..)| key.key().name() == "rkb_dropped_records_total")
+            .map(|(_, _, _, value)| value);
+        assert!(matches!(dropped, Some(DebugValue::Counter(1))), "{dropped:?}");
+    }
+
+    #[test]
+    fn test_mint_to_precompile_rejected() {
+        let ecrecover = Address::with_last_byte(1);
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_precompile_addresses([ecrecover]);
+        let mut ctx = TestContext::new();
+        let mint = |recipient| TestCall::new(BRIDGE, mint_calldata(recipient, U256::from(1_000)));
+        let rejected =
+            Err(PrecompileError::other_static("NativeMinter: cannot mint to precompile"));
+
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(NATIVE_MINTER_ADDRESS)), rejected);
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(ecrecover)), rejected);
+        assert_eq!(ctx.balance(ecrecover), U256::ZERO);
+
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+    }
+
+    #[test]
+    fn test_per_evm_settings_share_config() {
+        let config = Arc::new(NativeMinterConfig::new(BRIDGE));
+        let precompile = NativeMinterPrecompile::from_config(Arc::clone(&config))
+            .with_chain_id(1234)
+            .with_precompile_addresses([Address::with_last_byte(1)]);
+        assert!(Arc::ptr_eq(precompile.shared_config(), &config));
+
+        // Modifying the configuration copies it, leaving the shared one untouched
+        let precompile = precompile.with_supply_cap(U256::from(1_000));
+        assert!(!Arc::ptr_eq(precompile.shared_config(), &config));
+        assert_eq!(precompile.chain_id(), 1234);
+        assert_eq!(config.supply_cap, None);
+    }
+
+    const NEW_BRIDGE: Address = address!("0x00000000000000000000000000000000000000ee");
+
+    #[test]
+    fn test_two_step_bridge_rotation() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_bridge_source(BridgeSource::State)
+            .with_governor(GOVERNOR);
+        let mut ctx = TestContext::new();
+        let mint = |caller| TestCall::new(caller, mint_calldata(RECIPIENT, U256::from(1_000)));
+        let accept = |caller| TestCall::new(caller, acceptBridgeCall {}.abi_encode());
+        let unauthorized = Err(PrecompileError::other_static("NativeMinter: unauthorized caller"));
+
+        // Only the governor may propose, and nothing can be accepted before a proposal
+        let propose = proposeBridgeCall { newBridge: NEW_BRIDGE }.abi_encode();
+        let result = run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, propose.clone()));
+        assert_eq!(result, unauthorized);
+        assert_eq!(run_precompile(&precompile, &mut ctx, accept(NEW_BRIDGE)), unauthorized);
+
+        run_precompile(&precompile, &mut ctx, TestCall::new(GOVERNOR, propose)).unwrap();
+        assert_eq!(ctx.storage(PENDING_BRIDGE_SLOT), storage::address_to_word(NEW_BRIDGE));
+
+        // The proposal doesn't take effect until accepted, and only the proposed bridge may accept
+        run_precompile(&precompile, &mut ctx, mint(BRIDGE)).unwrap();
+        assert_eq!(run_precompile(&precompile, &mut ctx, accept(OTHER_BRIDGE)), unauthorized);
+        assert_eq!(run_precompile(&precompile, &mut ctx, accept(BRIDGE)), unauthorized);
+
+        run_precompile(&precompile, &mut ctx, accept(NEW_BRIDGE)).unwrap();
+        assert_eq!(ctx.storage(AUTHORIZED_BRIDGE_SLOT), storage::address_to_word(NEW_BRIDGE));
+        assert_eq!(ctx.storage(PENDING_BRIDGE_SLOT), U256::ZERO);
+
+        // The old bridge lost its authorization
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(BRIDGE)), unauthorized);
+        run_precompile(&precompile, &mut ctx, mint(NEW_BRIDGE)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+    }
+
+    #[test]
+    fn test_propose_bridge_requires_state_source() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_governor(GOVERNOR);
+        let mut ctx = TestContext::new();
+        let propose = proposeBridgeCall { newBridge: NEW_BRIDGE }.abi_encode();
+
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, TestCall::new(GOVERNOR, propose)),
+            Err(PrecompileError::other_static("NativeMinter: bridge not sourced from state"))
+        );
+        assert_eq!(ctx.storage(PENDING_BRIDGE_SLOT), U256::ZERO);
+    }
+
+    #[test]
+    fn test_require_existing_recipient() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_require_existing_recipient(true);
+        let mut ctx = TestContext::new();
+        let mint = |recipient| TestCall::new(BRIDGE, mint_calldata(recipient, U256::from(1_000)));
+
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, mint(RECIPIENT_2)),
+            Err(PrecompileError::other_static("NativeMinter: recipient does not exist"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT_2), U256::ZERO);
+
+        ctx.set_balance(RECIPIENT, U256::from(1));
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_001));
+    }
+
+    #[test]
+    fn test_config_changed_events() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_bridge_source(BridgeSource::State)
+            .with_governor(GOVERNOR);
+        let mut ctx = TestContext::new();
+        let config_changed = |ctx: &mut TestContext| {
+            let logs = ctx.take_logs();
+            assert_eq!(logs.len(), 1);
+            assert_eq!(logs[0].address, NATIVE_MINTER_ADDRESS);
+            let event = ConfigChanged::decode_log_data(&logs[0].data).unwrap();
+            (event.key, event.oldValue, event.newValue)
+        };
+
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, pauseCall {}.abi_encode()))
+            .unwrap();
+        assert_eq!(
+            config_changed(&mut ctx),
+            (PAUSED_SLOT.into(), B256::ZERO, U256::from(1).into())
+        );
+
+        let propose = proposeBridgeCall { newBridge: NEW_BRIDGE }.abi_encode();
+        run_precompile(&precompile, &mut ctx, TestCall::new(GOVERNOR, propose)).unwrap();
+        assert_eq!(
+            config_changed(&mut ctx),
+            (PENDING_BRIDGE_SLOT.into(), B256::ZERO, NEW_BRIDGE.into_word())
+        );
+
+        let accept = acceptBridgeCall {}.abi_encode();
+        run_precompile(&precompile, &mut ctx, TestCall::new(NEW_BRIDGE, accept)).unwrap();
+        assert_eq!(
+            config_changed(&mut ctx),
+            (AUTHORIZED_BRIDGE_SLOT.into(), BRIDGE.into_word(), NEW_BRIDGE.into_word())
+        );
+    }
+
+    /// Call kind of a [`TestVector`].
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum VectorCallKind {
+        Call,
+        StaticCall,
+        DelegateCall,
+        CallCode,
+    }
+
+    /// Observable outcome of a [`TestVector`], with the balance of [`RECIPIENT`] after the call.
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(tag = "result", rename_all = "snake_case")]
+    enum VectorOutcome {
+        Success { output: Bytes, gas_used: u64, balance: u64 },
+        Revert { reason: String, balance: u64 },
+        OutOfGas { balance: u64 },
+    }
+
+    /// A call into a default-configured precompile against a state where [`RECIPIENT`] holds
+    /// `balance`, and its expected outcome.
+    #[derive(Debug, serde::Deserialize)]
+    struct TestVector {
+        name: String,
+        caller: Address,
+        calldata: Bytes,
+        call_kind: VectorCallKind,
+        gas: u64,
+        balance: u64,
+        expected: VectorOutcome,
+    }
+
+    #[test]
+    fn test_reference_vectors() {
+        let vectors: Vec<TestVector> =
+            serde_json::from_str(include_str!("../testdata/native_minter_vectors.json")).unwrap();
+        assert!(vectors.len() >= 15);
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        for vector in vectors {
+            let mut ctx = TestContext::new();
+            ctx.set_balance(RECIPIENT, U256::from(vector.balance));
+
+            let call = TestCall::new(vector.caller, vector.calldata).with_gas(vector.gas);
+            let call = match vector.call_kind {
+                VectorCallKind::Call => call,
+                VectorCallKind::StaticCall => call.static_call(),
+                VectorCallKind::DelegateCall => call.delegated_from(PROXY),
+                VectorCallKind::CallCode => call.callcode_from(vector.caller),
+            };
+            let result = run_precompile(&precompile, &mut ctx, call);
+
+            let name = &vector.name;
+            let balance = match vector.expected {
+                VectorOutcome::Success { output, gas_used, balance } => {
+                    let result = result.unwrap_or_else(|err| panic!("{name}: {err:?}"));
+                    assert_eq!(result.bytes, output, "{name}");
+                    assert_eq!(result.gas_used, gas_used, "{name}");
+                    balance
+                }
+                VectorOutcome::Revert { reason, balance } => {
+                    assert_eq!(result, Err(PrecompileError::other(reason)), "{name}");
+                    balance
+                }
+                VectorOutcome::OutOfGas { balance } => {
+                    assert_eq!(result, Err(PrecompileError::OutOfGas), "{name}");
+                    balance
+                }
+            };
+            assert_eq!(ctx.balance(RECIPIENT), U256::from(balance), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_pause_single_operation() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let admin = |data: Vec<u8>| TestCall::new(BRIDGE, data);
+        let mint = || TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+        let burn = || TestCall::new(BRIDGE, burn_calldata(RECIPIENT, U256::from(400)));
+        let paused = Err(PrecompileError::other_static("NativeMinter: paused"));
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+
+        // Mints paused, users can still exit
+        run_precompile(&precompile, &mut ctx, admin(pauseMintCall {}.abi_encode())).unwrap();
+        assert_eq!(ctx.storage(PAUSED_SLOT), PAUSE_MINT);
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint()), paused);
+        run_precompile(&precompile, &mut ctx, burn()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+
+        // And the reverse
+        run_precompile(&precompile, &mut ctx, admin(unpauseMintCall {}.abi_encode())).unwrap();
+        run_precompile(&precompile, &mut ctx, admin(pauseBurnCall {}.abi_encode())).unwrap();
+        assert_eq!(ctx.storage(PAUSED_SLOT), PAUSE_BURN);
+        assert_eq!(run_precompile(&precompile, &mut ctx, burn()), paused);
+        run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_600));
+
+        // A full pause overrides individual unpauses, and unpause clears every flag
+        run_precompile(&precompile, &mut ctx, admin(pauseCall {}.abi_encode())).unwrap();
+        run_precompile(&precompile, &mut ctx, admin(unpauseBurnCall {}.abi_encode())).unwrap();
+        assert_eq!(run_precompile(&precompile, &mut ctx, burn()), paused);
+        run_precompile(&precompile, &mut ctx, admin(pauseMintCall {}.abi_encode())).unwrap();
+        run_precompile(&precompile, &mut ctx, admin(unpauseCall {}.abi_encode())).unwrap();
+        assert_eq!(ctx.storage(PAUSED_SLOT), U256::ZERO);
+        run_precompile(&precompile, &mut ctx, mint()).unwrap();
+        run_precompile(&precompile, &mut ctx, burn()).unwrap();
+    }
+
+    #[test]
+    fn test_max_recipients_per_block() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_max_recipients_per_block(2);
+        let mut ctx = TestContext::new();
+        ctx.block.number = U256::from(10);
+        let mint = |recipient| TestCall::new(BRIDGE, mint_calldata(recipient, U256::from(1_000)));
+
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT)).unwrap();
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT_2)).unwrap();
+        // Minting again to a recipient already counted in the block doesn't count it twice
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT)).unwrap();
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, mint(RECIPIENT_3)),
+            Err(PrecompileError::other_static("NativeMinter: too many recipients this block"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+        assert_eq!(ctx.balance(RECIPIENT_3), U256::ZERO);
+
+        ctx.block.number = U256::from(11);
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT_3)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT_3), U256::from(1_000));
+        assert_eq!(ctx.storage(RECIPIENTS_IN_BLOCK_SLOT), U256::from(1));
+    }
+
+    const RELAYER: Address = address!("0x00000000000000000000000000000000000000b4");
+
+    #[test]
+    fn test_delegated_minter() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let mint =
+            |amount: u64| TestCall::new(RELAYER, mint_calldata(RECIPIENT, U256::from(amount)));
+        let unauthorized = Err(PrecompileError::other_static("NativeMinter: unauthorized caller"));
+
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(100)), unauthorized);
+
+        let delegate =
+            mintAllowanceCall { minter: RELAYER, amount: U256::from(1_000) }.abi_encode();
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, TestCall::new(RELAYER, delegate.clone())),
+            unauthorized
+        );
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, delegate)).unwrap();
+
+        run_precompile(&precompile, &mut ctx, mint(600)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+        assert_eq!(ctx.storage(storage::mint_allowance_slot(RELAYER)), U256::from(400));
+
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, mint(401)),
+            Err(PrecompileError::other_static("NativeMinter: mint allowance exceeded"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(600));
+
+        // A delegated minter may only call `mint`
+        let burn = TestCall::new(RELAYER, burn_calldata(RECIPIENT, U256::from(1)));
+        assert_eq!(run_precompile(&precompile, &mut ctx, burn), unauthorized);
+
+        run_precompile(&precompile, &mut ctx, mint(400)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(1)), unauthorized);
+    }
+
+    #[test]
+    fn test_burn_summary_output() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_burn_summary_output(true);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+        ctx.set_balance(RECIPIENT_2, U256::from(1_000));
+        let burn =
+            |from, amount: u64| TestCall::new(BRIDGE, burn_calldata(from, U256::from(amount)));
+
+        run_precompile(&precompile, &mut ctx, burn(RECIPIENT_2, 100)).unwrap();
+        let output = run_precompile(&precompile, &mut ctx, burn(RECIPIENT, 300)).unwrap();
+        let (new_balance, total_burned) = <(U256, U256)>::abi_decode_params(&output.bytes).unwrap();
+        assert_eq!(new_balance, U256::from(700));
+        assert_eq!(total_burned, U256::from(400));
+        assert_eq!(new_balance, ctx.balance(RECIPIENT));
+
+        let effect = precompile
+            .simulate(BRIDGE, &burn_calldata(RECIPIENT, U256::from(1)), CallKind::Call)
+            .unwrap();
+        assert_eq!(precompile.expected_output(&effect, U256::from(700)), None);
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_breaker_threshold(U256::from(1_000));
+        let mut ctx = TestContext::new();
+        ctx.block.number = U256::from(10);
+        let mint =
+            |amount: u64| TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(amount)));
+        let tripped = Err(PrecompileError::other_static("NativeMinter: circuit breaker tripped"));
+
+        run_precompile(&precompile, &mut ctx, mint(600)).unwrap();
+        run_precompile(&precompile, &mut ctx, mint(400)).unwrap();
+        assert_eq!(ctx.storage(PAUSED_SLOT), U256::ZERO);
+
+        // The block's total restarts in the next block
+        ctx.block.number = U256::from(11);
+        run_precompile(&precompile, &mut ctx, mint(1_000)).unwrap();
+        assert_eq!(ctx.storage(PAUSED_SLOT), U256::ZERO);
+
+        // The mint that would push the total over the threshold reverts, without pausing minting
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(1)), tripped);
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+        assert_eq!(ctx.storage(PAUSED_SLOT), U256::ZERO);
+        assert_eq!(ctx.storage(BREAKER_MINTED_SLOT), U256::from(1_000));
+
+        // Minting resumes in the next block
+        ctx.block.number = U256::from(12);
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(1_001)), tripped);
+        run_precompile(&precompile, &mut ctx, mint(1)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_001));
+    }
+
+    #[test]
+    fn test_gas_cost_view() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).with_mint_gas_cost(7_000).with_burn_gas_cost(8_000);
+        let mut ctx = TestContext::new();
+        let mut gas_cost = |selector: [u8; 4]| {
+            let data = gasCostCall { selector: selector.into() }.abi_encode();
+            let call = TestCall::new(RECIPIENT, data).static_call();
+            let output = run_precompile(&precompile, &mut ctx, call).unwrap();
+            U256::abi_decode(&output.bytes).unwrap()
+        };
+
+        assert_eq!(gas_cost(<mintCall as SolCall>::SELECTOR), U256::from(7_000));
+        assert_eq!(gas_cost(<burn_0Call as SolCall>::SELECTOR), U256::from(8_000));
+        assert_eq!(gas_cost(<pauseCall as SolCall>::SELECTOR), U256::from(NATIVE_MINTER_GAS_COST));
+        assert_eq!(gas_cost(<totalSupplyCall as SolCall>::SELECTOR), U256::from(VIEW_GAS_COST));
+
+        // The reported cost is the one charged for the call
+        let output = run_precompile(
+            &precompile,
+            &mut TestContext::new(),
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))),
+        )
+        .unwrap();
+        assert_eq!(output.gas_used, 7_000);
+    }
+
+    #[test]
+    fn test_decode_and_execution_gas_phases() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_decode_gas_per_word(100);
+        let recipients = [RECIPIENT, RECIPIENT_2, RECIPIENT_3, PROXY];
+        let data = mint_batch_calldata(&recipients, U256::from(1_000));
+        let decode = 100 * data.len().div_ceil(32) as u64;
+        let total = decode + 4 * MINT_GAS_COST;
+
+        let run = |data: Bytes, gas: u64| {
+            let mut ctx = TestContext::new();
+            let mut result = None;
+            let logs = CapturedLogs::capture(|| {
+                let call = TestCall::new(BRIDGE, data).with_gas(gas);
+                result = Some(run_precompile(&precompile, &mut ctx, call));
+            });
+            (result.unwrap(), ctx.balance(RECIPIENT), logs)
+        };
+
+        // Not enough to decode: even malformed arguments run out of gas before being decoded
+        let mut malformed = data.to_vec();
+        malformed.truncate(data.len() - 1);
+        let (result, _, logs) = run(malformed.clone().into(), decode - 1);
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+        let expected = format!(
+            "out of gas decoding calldata (have {}, need {})",
+            decode - 1,
+            100 * malformed.len().div_ceil(32) as u64
+        );
+        assert!(logs.contains(&expected), "{logs}");
+
+        // Enough to decode, so malformed arguments are reported as such
+        let (result, _, _) = run(malformed.into(), total - 1);
+        assert_eq!(
+            result,
+            Err(PrecompileError::other_static("NativeMinter: invalid mintBatch args"))
+        );
+
+        // Enough to decode but not to mint every recipient
+        let (result, balance, logs) = run(data.clone(), total - 1);
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+        assert_eq!(balance, U256::ZERO);
+        assert!(logs.contains(&format!("out of gas (have {}, need {total})", total - 1)), "{logs}");
+
+        let (result, balance, _) = run(data, total);
+        assert_eq!(result.unwrap().gas_used, total);
+        assert_eq!(balance, U256::from(1_000));
+    }
+
+    #[test]
+    fn test_storage_salt_namespaces_storage() {
+        let salts = [B256::with_last_byte(1), B256::with_last_byte(2)];
+        let mut ctx = TestContext::new();
+
+        for (salt, amount) in salts.into_iter().zip([1_000u64, 2_000]) {
+            let precompile = NativeMinterPrecompile::new(BRIDGE).with_storage_salt(salt);
+            let mint = TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(amount)));
+            run_precompile(&precompile, &mut ctx, mint).unwrap();
+        }
+
+        // Each salt sees only its own mints, in disjoint slots
+        for (salt, amount) in salts.into_iter().zip([1_000u64, 2_000]) {
+            let precompile = NativeMinterPrecompile::new(BRIDGE).with_storage_salt(salt);
+            let call = TestCall::new(RECIPIENT, totalMintedCall {}.abi_encode()).static_call();
+            let output = run_precompile(&precompile, &mut ctx, call).unwrap();
+            assert_eq!(U256::abi_decode(&output.bytes).unwrap(), U256::from(amount));
+            let slot = storage::namespaced_slot(salt, TOTAL_MINTED_SLOT);
+            assert_eq!(ctx.storage(slot), U256::from(amount));
+        }
+        assert_eq!(ctx.storage(TOTAL_MINTED_SLOT), U256::ZERO);
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(3_000));
+
+        // Tooling locates mapping entries through the precompile's salt
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_storage_salt(salts[0]);
+        let approve = approveCall { spender: BRIDGE, value: U256::from(500) }.abi_encode();
+        run_precompile(&precompile, &mut ctx, TestCall::new(RECIPIENT, approve)).unwrap();
+        let slot = precompile.storage_slot(storage::allowance_slot(RECIPIENT, BRIDGE));
+        assert_eq!(ctx.storage(slot), U256::from(500));
+        assert_eq!(ctx.storage(storage::allowance_slot(RECIPIENT, BRIDGE)), U256::ZERO);
+    }
+
+    #[test]
+    fn test_bridge_enumeration() {
+        let precompile =
+            NativeMinterPrecompile::new(OTHER_BRIDGE).add_bridge(BRIDGE).add_bridge(RECIPIENT);
+        let mut ctx = TestContext::new();
+        let mut view = |data: Vec<u8>| {
+            run_precompile(&precompile, &mut ctx, TestCall::new(RECIPIENT_2, data).static_call())
+        };
+
+        let output = view(bridgeCountCall {}.abi_encode()).unwrap();
+        assert_eq!(U256::abi_decode(&output.bytes).unwrap(), U256::from(3));
+
+        // Listed in ascending order regardless of which bridge is the authorized one
+        for (index, bridge) in [RECIPIENT, BRIDGE, OTHER_BRIDGE].into_iter().enumerate() {
+            let output = view(bridgeAtCall { index: U256::from(index) }.abi_encode()).unwrap();
+            assert_eq!(Address::abi_decode(&output.bytes).unwrap(), bridge);
+        }
+
+        for index in [U256::from(3), U256::MAX] {
+            assert_eq!(
+                view(bridgeAtCall { index }.abi_encode()),
+                Err(PrecompileError::other_static("NativeMinter: bridge index out of bounds"))
+            );
+        }
+
+        // Additional bridges hold every role
+        for role in [Role::Minter, Role::Burner, Role::Pauser] {
+            assert_eq!(precompile.role_members(role), &BTreeSet::from([BRIDGE, RECIPIENT]));
+        }
+    }
+
+    #[test]
+    fn test_mint_cooldown() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_mint_cooldown_blocks(10);
+        let mut ctx = TestContext::new();
+        let mint = |recipient| TestCall::new(BRIDGE, mint_calldata(recipient, U256::from(1_000)));
+        let cooldown =
+            Err(PrecompileError::other_static("NativeMinter: mint cooldown not elapsed"));
+
+        ctx.block.number = U256::from(5);
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT)).unwrap();
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(RECIPIENT)), cooldown);
+
+        // Other recipients are not throttled
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT_2)).unwrap();
+
+        ctx.block.number = U256::from(14);
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint(RECIPIENT)), cooldown);
+
+        ctx.block.number = U256::from(15);
+        run_precompile(&precompile, &mut ctx, mint(RECIPIENT)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+        assert_eq!(
+            ctx.storage(storage::mint_cooldown_slot(RECIPIENT)),
+            U256::from(16),
+            "next cooldown starts from the latest mint"
+        );
+    }
+
+    #[test]
+    fn test_burn_with_nonce() {
+        assert_eq!(burn_1Call::SIGNATURE, "burn(address,uint256,uint256)");
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+        ctx.set_balance(RECIPIENT_2, U256::from(1_000));
+        let burn = |from, nonce: u64| {
+            let data = burn_1Call { from, amount: U256::from(100), nonce: U256::from(nonce) };
+            TestCall::new(BRIDGE, data.abi_encode())
+        };
+
+        run_precompile(&precompile, &mut ctx, burn(RECIPIENT, 7)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(900));
+
+        // A retried burn is rejected without burning again
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, burn(RECIPIENT, 7)),
+            Err(PrecompileError::other_static("NativeMinter: burn nonce already used"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(900));
+
+        // Distinct nonces, including one sharing the bitmap word, and other accounts' nonces
+        run_precompile(&precompile, &mut ctx, burn(RECIPIENT, 8)).unwrap();
+        run_precompile(&precompile, &mut ctx, burn(RECIPIENT, 1_000)).unwrap();
+        run_precompile(&precompile, &mut ctx, burn(RECIPIENT_2, 7)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(700));
+        assert_eq!(ctx.balance(RECIPIENT_2), U256::from(900));
+
+        let (slot, _) = storage::burn_nonce_slot(RECIPIENT, U256::from(7));
+        assert_eq!(ctx.storage(slot), U256::from(0b11 << 7));
+    }
+
+    #[test]
+    fn test_aggregate_batch_events() {
+        let recipients = [RECIPIENT, RECIPIENT_2, RECIPIENT_3];
+        let amounts = [U256::from(100), U256::from(200), U256::from(300)];
+        let batch = || {
+            let data = mintBatchCall { recipients: recipients.to_vec(), amounts: amounts.to_vec() };
+            TestCall::new(BRIDGE, data.abi_encode())
+        };
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_event_scheme(EventScheme::Both);
+        let mut ctx = TestContext::new();
+
+        run_precompile(&precompile, &mut ctx, batch()).unwrap();
+        assert_eq!(ctx.take_logs().len(), 6);
+
+        let precompile = precompile.with_aggregate_batch_events(true);
+        run_precompile(&precompile, &mut ctx, batch()).unwrap();
+        let logs = ctx.take_logs();
+        assert_eq!(logs.len(), 1);
+        let event = BatchMint::decode_log_data(&logs[0].data).unwrap();
+        assert_eq!(event.count, U256::from(3));
+        assert_eq!(event.totalAmount, U256::from(600));
+        assert_eq!(event.recipientsHash, keccak256(recipients.to_vec().abi_encode()));
+        assert_eq!(ctx.balance(RECIPIENT_3), U256::from(600));
+
+        // Single mints keep their per-mint events
+        run_precompile(
+            &precompile,
+            &mut ctx,
+            TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1))),
+        )
+        .unwrap();
+        assert_eq!(ctx.take_logs().len(), 2);
+    }
+
+    #[test]
+    fn test_system_caller_rejected() {
+        // Even a system address configured as the bridge is rejected
+        for caller in SYSTEM_ADDRESSES {
+            let precompile = NativeMinterPrecompile::new(caller);
+            let mut ctx = TestContext::new();
+            for data in [
+                mint_calldata(RECIPIENT, U256::from(1_000)),
+                totalMintedCall {}.abi_encode().into(),
+            ] {
+                assert_eq!(
+                    run_precompile(&precompile, &mut ctx, TestCall::new(caller, data)),
+                    Err(PrecompileError::other_static("NativeMinter: system context not allowed"))
+                );
+            }
+            assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_error_verbosity() {
+        let run = |verbosity| {
+            let precompile = NativeMinterPrecompile::new(BRIDGE).with_error_verbosity(verbosity);
+            let call = TestCall::new(OTHER_BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+            let mut result = None;
+            let logs = CapturedLogs::capture(|| {
+                result = Some(run_precompile(&precompile, &mut TestContext::new(), call));
+            });
+            (result.unwrap(), logs)
+        };
+        let unauthorized = Err(PrecompileError::other_static("NativeMinter: unauthorized caller"));
+
+        let (result, logs) = run(Verbosity::Terse);
+        assert_eq!(result, unauthorized);
+        assert!(logs.contains("unauthorized caller"), "{logs}");
+        assert!(logs.contains("authorized=<redacted>"), "{logs}");
+        assert!(!logs.contains(&BRIDGE.to_string()), "{logs}");
+
+        let (result, logs) = run(Verbosity::Detailed);
+        assert_eq!(result, unauthorized);
+        assert!(logs.contains(&format!("authorized={BRIDGE}")), "{logs}");
+        assert!(logs.contains(&format!("caller={OTHER_BRIDGE}")), "{logs}");
+    }
+
+    fn mint_with_approval_calldata(
+        precompile: &NativeMinterPrecompile,
+        signer: &PrivateKeySigner,
+        recipient: Address,
+        amount: U256,
+        nonce: U256,
+    ) -> Bytes {
+        let hash = MintApproval { recipient, amount, nonce }
+            .eip712_signing_hash(&precompile.eip712_domain());
+        let signature = signer.sign_hash_sync(&hash).unwrap();
+        mintWithApprovalCall { recipient, amount, approverSig: signature.as_bytes().into() }
+            .abi_encode()
+            .into()
+    }
+
+    #[test]
+    fn test_mint_approval() {
+        let approver = PrivateKeySigner::from_bytes(&B256::repeat_byte(0x43)).unwrap();
+        let precompile = NativeMinterPrecompile::new(BRIDGE)
+            .with_mint_approver(MintApprover::new(approver.address(), U256::from(1_000)));
+        let mut ctx = TestContext::new();
+        let large = U256::from(5_000);
+        let approval_required =
+            Err(PrecompileError::other_static("NativeMinter: approval required"));
+        let invalid_signature =
+            Err(PrecompileError::other_static("NativeMinter: invalid signature"));
+
+        // Below the threshold, mints need no approval
+        let mint = TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+        run_precompile(&precompile, &mut ctx, mint).unwrap();
+
+        let mint = TestCall::new(BRIDGE, mint_calldata(RECIPIENT, large));
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint), approval_required);
+
+        let data =
+            mint_with_approval_calldata(&precompile, &approver, RECIPIENT, large, U256::ZERO);
+        run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data.clone())).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(6_000));
+        assert_eq!(ctx.storage(APPROVAL_NONCE_SLOT), U256::from(1));
+        let call = TestCall::new(RECIPIENT, approvalNonceCall {}.abi_encode()).static_call();
+        let output = run_precompile(&precompile, &mut ctx, call).unwrap();
+        assert_eq!(approvalNonceCall::abi_decode_returns(&output.bytes).unwrap(), U256::from(1));
+
+        // An approval is only valid once
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data)),
+            invalid_signature
+        );
+
+        // Signed by another account, or for another amount
+        let other = owner_signer();
+        let data =
+            mint_with_approval_calldata(&precompile, &other, RECIPIENT, large, U256::from(1));
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data)),
+            invalid_signature
+        );
+        let hash = MintApproval { recipient: RECIPIENT, amount: large, nonce: U256::from(1) }
+            .eip712_signing_hash(&precompile.eip712_domain());
+        let signature = approver.sign_hash_sync(&hash).unwrap();
+        let data = mintWithApprovalCall {
+            recipient: RECIPIENT,
+            amount: large + U256::from(1),
+            approverSig: signature.as_bytes().into(),
+        };
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data.abi_encode())),
+            invalid_signature
+        );
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(6_000));
+    }
+
+    #[test]
+    fn test_pause_views() {
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        let admin = |data: Vec<u8>| TestCall::new(BRIDGE, data);
+        let pause_state = |ctx: &mut TestContext| {
+            [
+                isPausedCall {}.abi_encode(),
+                isMintPausedCall {}.abi_encode(),
+                isBurnPausedCall {}.abi_encode(),
+            ]
+            .map(|data| {
+                let call = TestCall::new(RECIPIENT, data).static_call();
+                let output = run_precompile(&precompile, ctx, call).unwrap();
+                bool::abi_decode(&output.bytes).unwrap()
+            })
+        };
+
+        assert_eq!(pause_state(&mut ctx), [false, false, false]);
+
+        run_precompile(&precompile, &mut ctx, admin(pauseCall {}.abi_encode())).unwrap();
+        assert_eq!(pause_state(&mut ctx), [true, true, true]);
+
+        run_precompile(&precompile, &mut ctx, admin(unpauseCall {}.abi_encode())).unwrap();
+        run_precompile(&precompile, &mut ctx, admin(pauseMintCall {}.abi_encode())).unwrap();
+        assert_eq!(pause_state(&mut ctx), [false, true, false]);
+
+        run_precompile(&precompile, &mut ctx, admin(unpauseMintCall {}.abi_encode())).unwrap();
+        run_precompile(&precompile, &mut ctx, admin(pauseBurnCall {}.abi_encode())).unwrap();
+        assert_eq!(pause_state(&mut ctx), [false, false, true]);
+    }
+
+    #[test]
+    fn test_max_calldata_len() {
+        let mut ctx = TestContext::new();
+        let data = mint_calldata(RECIPIENT, U256::from(1_000));
+        let too_large = Err(PrecompileError::other_static("NativeMinter: calldata too large"));
+        assert_eq!(
+            NativeMinterPrecompile::new(BRIDGE).max_calldata_len(),
+            DEFAULT_MAX_CALLDATA_LEN
+        );
+
+        // Just under and at the limit
+        for max in [data.len() + 1, data.len()] {
+            let precompile = NativeMinterPrecompile::new(BRIDGE).with_max_calldata_len(max);
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data.clone())).unwrap();
+        }
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+
+        // Just over the limit
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_max_calldata_len(data.len() - 1);
+        assert_eq!(run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data)), too_large);
+
+        // Views are bounded too, and so is calldata past the default limit
+        let view = TestCall::new(RECIPIENT, totalMintedCall {}.abi_encode()).static_call();
+        assert_eq!(run_precompile(&precompile.with_max_calldata_len(3), &mut ctx, view), too_large);
+        let mut data = mint_calldata(RECIPIENT, U256::from(1_000)).to_vec();
+        data.resize(DEFAULT_MAX_CALLDATA_LEN + 1, 0);
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, data)), too_large);
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(2_000));
+    }
+
+    #[test]
+    fn test_view_gas_cost() {
+        let mut ctx = TestContext::new();
+        let total_supply =
+            || TestCall::new(RECIPIENT, totalSupplyCall {}.abi_encode()).static_call();
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(precompile.view_gas_cost(), VIEW_GAS_COST);
+        let output = run_precompile(&precompile, &mut ctx, total_supply()).unwrap();
+        assert_eq!(output.gas_used, VIEW_GAS_COST);
+        assert_ne!(output.gas_used, NATIVE_MINTER_GAS_COST);
+        let result =
+            run_precompile(&precompile, &mut ctx, total_supply().with_gas(VIEW_GAS_COST - 1));
+        assert_eq!(result, Err(PrecompileError::OutOfGas));
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_view_gas_cost(1_200);
+        let output = run_precompile(&precompile, &mut ctx, total_supply()).unwrap();
+        assert_eq!(output.gas_used, 1_200);
+
+        // State-modifying calls are unaffected
+        let mint = TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1)));
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint).unwrap().gas_used, MINT_GAS_COST);
+    }
+
+    #[test]
+    fn test_rejection_log_level() {
+        let run = |level| {
+            let precompile = NativeMinterPrecompile::new(BRIDGE).with_rejection_log_level(level);
+            let call = TestCall::new(OTHER_BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+            CapturedLogs::capture_at(Level::TRACE, || {
+                assert_eq!(
+                    run_precompile(&precompile, &mut TestContext::new(), call),
+                    Err(PrecompileError::other_static("NativeMinter: unauthorized caller"))
+                );
+            })
+        };
+
+        assert_eq!(NativeMinterPrecompile::new(BRIDGE).rejection_log_level(), Level::WARN);
+        for (level, name) in
+            [(Level::ERROR, "ERROR"), (Level::WARN, " WARN"), (Level::DEBUG, "DEBUG")]
+        {
+            let logs = run(level);
+            let line = logs.lines().find(|line| line.contains("unauthorized caller")).unwrap();
+            assert!(line.contains(name), "{line}");
+        }
+
+        // Silenced by a subscriber above the configured level
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_rejection_log_level(Level::DEBUG);
+        let call = TestCall::new(OTHER_BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+        let logs = CapturedLogs::capture(|| {
+            run_precompile(&precompile, &mut TestContext::new(), call).unwrap_err();
+        });
+        assert!(!logs.contains("unauthorized caller"), "{logs}");
+    }
+
+    #[test]
+    fn test_saturating_mint() {
+        let mint =
+            |amount: u64| TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(amount)));
+        let near_max = U256::MAX - U256::from(100);
+
+        // Reverts on overflow by default
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, near_max);
+        assert_eq!(
+            run_precompile(&precompile, &mut ctx, mint(1_000)),
+            Err(PrecompileError::other_static("NativeMinter: balance overflow"))
+        );
+        assert_eq!(ctx.balance(RECIPIENT), near_max);
+
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_saturating_mint(true);
+        assert!(precompile.features().contains(Features::SATURATING_MINT));
+        let credited = |output: PrecompileOutput| U256::abi_decode(&output.bytes).unwrap();
+
+        // Within the headroom, the full amount is credited
+        let output = run_precompile(&precompile, &mut ctx, mint(40)).unwrap();
+        assert_eq!(credited(output), U256::from(40));
+
+        // Clamped to the remaining headroom
+        let output = run_precompile(&precompile, &mut ctx, mint(1_000)).unwrap();
+        assert_eq!(credited(output), U256::from(60));
+        assert_eq!(ctx.balance(RECIPIENT), U256::MAX);
+        assert_eq!(ctx.storage(TOTAL_MINTED_SLOT), U256::from(100));
+
+        let output = run_precompile(&precompile, &mut ctx, mint(1)).unwrap();
+        assert_eq!(credited(output), U256::ZERO);
+        assert_eq!(ctx.storage(TOTAL_MINTED_SLOT), U256::from(100));
+    }
+
+    #[test]
+    fn test_value_attached() {
+        let value = U256::from(5);
+        let mint = || TestCall::new(BRIDGE, mint_calldata(RECIPIENT, U256::from(1_000)));
+        let view = || TestCall::new(RECIPIENT, totalMintedCall {}.abi_encode());
+
+        // The EVM transfers the value to the precompile before executing it
+        let transferred = || {
+            let mut ctx = TestContext::new();
+            ctx.set_balance(NATIVE_MINTER_ADDRESS, value);
+            ctx
+        };
+
+        // Rejected by default, views included
+        let precompile = NativeMinterPrecompile::new(BRIDGE);
+        assert_eq!(precompile.value_mode(), ValueMode::Reject);
+        let not_accepted = Err(PrecompileError::other_static("NativeMinter: value not accepted"));
+        let mut ctx = transferred();
+        assert_eq!(run_precompile(&precompile, &mut ctx, mint().with_value(value)), not_accepted);
+        assert_eq!(run_precompile(&precompile, &mut ctx, view().with_value(value)), not_accepted);
+        assert_eq!(ctx.balance(RECIPIENT), U256::ZERO);
+
+        // The value of a DELEGATECALL stays with the trusted proxy
+        let precompile = precompile.allow_delegatecall_from(BRIDGE);
+        let call = TestCall::new(RECIPIENT, mint_calldata(RECIPIENT, U256::from(1_000)));
+        let call = call.with_value(value).delegated_from(BRIDGE);
+        run_precompile(&precompile, &mut ctx, call).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+
+        // Refunded to the caller
+        let precompile = NativeMinterPrecompile::new(BRIDGE).with_value_mode(ValueMode::Refund);
+        let mut ctx = transferred();
+        run_precompile(&precompile, &mut ctx, mint().with_value(value)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(1_000));
+        assert_eq!(ctx.balance(BRIDGE), value);
+        assert_eq!(ctx.balance(NATIVE_MINTER_ADDRESS), U256::ZERO);
+    }
+
+    #[test]
+    fn test_selector_constants() {
+        assert_eq!(MINT_SELECTOR[..], keccak256("mint(address,uint256)")[..4]);
+        assert_eq!(BURN_SELECTOR[..], keccak256("burn(address,uint256)")[..4]);
+        assert_eq!(
+            MINT_WITH_MESSAGE_SELECTOR[..],
+            keccak256("mintWithMessage(address,uint256,bytes32)")[..4]
+        );
+        assert_eq!(
+            MINT_WITH_MESSAGE_FROM_SELECTOR[..],
+            keccak256("mintWithMessageFrom(address,uint256,bytes32,uint32)")[..4]
+        );
+        assert_eq!(MINT_BATCH_SELECTOR[..], keccak256("mintBatch(address[],uint256[])")[..4]);
+    }
+
+    #[test]
+    fn test_require_origin_burn() {
+        let precompile =
+            NativeMinterPrecompile::new(BRIDGE).add_burner(BURNER).with_require_origin_burn(true);
+        assert!(precompile.require_origin_burn());
+        assert!(precompile.features().contains(Features::ORIGIN_BURN));
+        let not_from_origin =
+            Err(PrecompileError::other_static("NativeMinter: burn not from transaction origin"));
+
+        let mut ctx = TestContext::new();
+        ctx.set_balance(RECIPIENT, U256::from(1_000));
+        ctx.set_balance(RECIPIENT_2, U256::from(1_000));
+        ctx.tx.caller = RECIPIENT;
+        let burn = |caller, from| TestCall::new(caller, burn_calldata(from, U256::from(100)));
+
+        // The bridge may burn from the transaction origin
+        run_precompile(&precompile, &mut ctx, burn(BRIDGE, RECIPIENT)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT), U256::from(900));
+
+        // But not from any other account, however the burn is made
+        let result = run_precompile(&precompile, &mut ctx, burn(BRIDGE, RECIPIENT_2));
+        assert_eq!(result, not_from_origin);
+        let call = burn_1Call { from: RECIPIENT_2, amount: U256::from(100), nonce: U256::ZERO };
+        let result =
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, call.abi_encode()));
+        assert_eq!(result, not_from_origin);
+        let call = mintBurnNetCall { account: RECIPIENT_2, delta: I256::try_from(-100).unwrap() };
+        let result =
+            run_precompile(&precompile, &mut ctx, TestCall::new(BRIDGE, call.abi_encode()));
+        assert_eq!(result, not_from_origin);
+        assert_eq!(ctx.balance(RECIPIENT_2), U256::from(1_000));
+
+        // Other burners are unaffected
+        run_precompile(&precompile, &mut ctx, burn(BURNER, RECIPIENT_2)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT_2), U256::from(900));
+
+        // The bridge may burn from any account unless required to burn from the origin
+        let precompile = precompile.with_require_origin_burn(false);
+        run_precompile(&precompile, &mut ctx, burn(BRIDGE, RECIPIENT_2)).unwrap();
+        assert_eq!(ctx.balance(RECIPIENT_2), U256::from(800));
     }
 }
@@ -0,0 +1,30 @@
+//! The items most integrations need, for a single glob import.
+//!
+//! Everything here is also exported from the crate root. The prelude only grows, so a glob import
+//! of it keeps compiling as the crate's API expands.
+//!
+//! ```
+//! use reth_rkb::prelude::*;
+//!
+//! let bridge = Address::with_last_byte(0xb1);
+//! let config = RkbConfig {
+//!     authorized_bridges: vec![bridge],
+//!     supply_cap: Some(U256::from(1_000_000)),
+//!     ..Default::default()
+//! };
+//!
+//! let executor = RkbExecutorBuilder::from_config(config)?;
+//! let native_minter = executor.native_minter();
+//! assert_eq!(native_minter.authorized_bridge(), bridge);
+//! assert_eq!(native_minter.supply_cap(), Some(U256::from(1_000_000)));
+//! assert_eq!(native_minter.addresses().next(), Some(NATIVE_MINTER_ADDRESS));
+//! # Ok::<_, RkbConfigError>(())
+//! ```
+
+pub use crate::{
+    BurnMode, EventScheme, Features, GasModel, MintFee, NativeMinterConfig, NativeMinterError,
+    NativeMinterPrecompile, RateLimit, RkbConfig, RkbConfigError, RkbEvmFactory,
+    RkbExecutorBuilder, Role, ValueMode, BURN_SELECTOR, MINT_BATCH_SELECTOR, MINT_SELECTOR,
+    MINT_WITH_MESSAGE_FROM_SELECTOR, MINT_WITH_MESSAGE_SELECTOR, NATIVE_MINTER_ADDRESS,
+};
+pub use alloy_primitives::{Address, U256};
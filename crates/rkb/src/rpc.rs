@@ -0,0 +1,136 @@
+//! `rkb` RPC namespace exposing the live NativeMinter configuration.
+//!
+//! Operators can query the configuration directly instead of crafting `eth_call`s to
+//! [`NATIVE_MINTER_ADDRESS`].
+
+use crate::{NativeMinterPrecompile, Role, NATIVE_MINTER_ADDRESS};
+use alloy_primitives::Address;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// NativeMinter configuration returned by `rkb_config`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RkbConfigResponse {
+    /// Addresses allowed to call mint/burn, the authorized bridge first.
+    pub authorized_bridges: Vec<Address>,
+    /// Addresses granted the minter role in addition to the authorized bridge, including the
+    /// additional bridges.
+    pub minters: Vec<Address>,
+    /// Address of the NativeMinter precompile.
+    pub native_minter: Address,
+    /// Gas charged per mint, excluding any calldata-dependent component.
+    pub gas_cost: u64,
+    /// Gas charged per burn, excluding any calldata-dependent component.
+    pub burn_gas_cost: u64,
+    /// Version of the node's NativeMinter crate, to confirm a fleet runs the same build. Unlike
+    /// the precompile's `version()`, this may differ between nodes.
+    pub version: String,
+}
+
+impl From<&NativeMinterPrecompile> for RkbConfigResponse {
+    fn from(precompile: &NativeMinterPrecompile) -> Self {
+        let authorized_bridge = precompile.authorized_bridge();
+        let additional_bridges =
+            precompile.additional_bridges().iter().filter(|bridge| **bridge != authorized_bridge);
+        Self {
+            authorized_bridges: std::iter::once(authorized_bridge)
+                .chain(additional_bridges.copied())
+                .collect(),
+            minters: precompile.role_members(Role::Minter).iter().copied().collect(),
+            native_minter: NATIVE_MINTER_ADDRESS,
+            gas_cost: precompile.mint_gas_cost(),
+            burn_gas_cost: precompile.burn_gas_cost(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+/// RKB rpc interface.
+#[cfg_attr(not(test), rpc(server, namespace = "rkb"))]
+#[cfg_attr(test, rpc(server, client, namespace = "rkb"))]
+pub trait RkbApi {
+    /// Returns the NativeMinter configuration of this node.
+    #[method(name = "config")]
+    fn config(&self) -> RpcResult<RkbConfigResponse>;
+}
+
+/// Implementation of the `rkb` namespace.
+#[derive(Debug, Clone)]
+pub struct RkbRpc {
+    config: RkbConfigResponse,
+}
+
+impl RkbRpc {
+    /// Creates a new `rkb` namespace serving the given configuration.
+    pub const fn new(config: RkbConfigResponse) -> Self {
+        Self { config }
+    }
+}
+
+impl RkbApiServer for RkbRpc {
+    fn config(&self) -> RpcResult<RkbConfigResponse> {
+        Ok(self.config.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bridge_extra_data, RkbConfig, RkbExecutorBuilder};
+    use alloy_genesis::Genesis;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_rkb_config() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let rpc = RkbExecutorBuilder::new(bridge).rpc(&Genesis::default()).unwrap();
+
+        let config = RkbApiServer::config(&rpc).unwrap();
+        assert_eq!(
+            serde_json::to_value(config).unwrap(),
+            serde_json::json!({
+                "authorizedBridges": ["0x1234567890abcdef1234567890abcdef12345678"],
+                "minters": [],
+                "nativeMinter": "0x0000000000000000000000000000000000000420",
+                "gasCost": 6000,
+                "burnGasCost": 6800,
+                "version": env!("CARGO_PKG_VERSION"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_rkb_config_lists_bridges_and_minters() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let other_bridge = address!("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd");
+        let minter = address!("0x00000000000000000000000000000000000000b1");
+        let config = RkbConfig {
+            authorized_bridges: vec![bridge, other_bridge],
+            minter_address: Some(minter),
+            ..Default::default()
+        };
+        let rpc =
+            RkbExecutorBuilder::from_config(config).unwrap().rpc(&Genesis::default()).unwrap();
+
+        let config = RkbApiServer::config(&rpc).unwrap();
+        assert_eq!(config.authorized_bridges, vec![bridge, other_bridge]);
+        assert_eq!(config.minters, vec![minter, other_bridge]);
+    }
+
+    #[test]
+    fn test_rkb_config_reads_bridge_from_genesis() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let genesis = Genesis { extra_data: bridge_extra_data(bridge), ..Default::default() };
+        let rpc = RkbExecutorBuilder::testing().with_bridge_from_genesis().rpc(&genesis).unwrap();
+
+        let config = RkbApiServer::config(&rpc).unwrap();
+        assert_eq!(config.authorized_bridges, vec![bridge]);
+
+        // The endpoint isn't served if the genesis doesn't commit to a bridge
+        assert!(RkbExecutorBuilder::testing()
+            .with_bridge_from_genesis()
+            .rpc(&Genesis::default())
+            .is_err());
+    }
+}
@@ -0,0 +1,691 @@
+//! Storage layout of the NativeMinter precompile.
+//!
+//! All precompile-managed state lives in the storage of the account at
+//! [`NATIVE_MINTER_ADDRESS`], so it is part of the state root and rolled back together with the
+//! block that modified it.
+//!
+//! ## Layout
+//!
+//! | Slot | Name                | Type                                              | Unset (zero) value                  |
+//! |------|---------------------|---------------------------------------------------|-------------------------------------|
+//! | `0`  | `authorizedBridge`  | `address`                                         | falls back to the configured bridge |
+//! | `1`  | `processedMessages` | `mapping(bytes32 => bool)`                        | message not processed               |
+//! | `2`  | `paused`            | `uint256` bitmask, see [`PAUSE_ALL`]              | unpaused                            |
+//! | `3`  | `totalMinted`       | `uint256`                                         | nothing minted                      |
+//! | `4`  | `totalBurned`       | `uint256`                                         | nothing burned                      |
+//! | `5`  | `rateLimitStart`    | `uint256`                                         | window starting at timestamp `0`    |
+//! | `6`  | `rateLimitMinted`   | `uint256`                                         | nothing minted in the window        |
+//! | `7`  | `allowances`        | `mapping(address => mapping(address => uint256))` | no burn allowance                   |
+//! | `8`  | `nonces`            | `mapping(address => uint256)`                     | no permit used                      |
+//! | `9`  | `emergencyMints`    | `mapping(bytes32 => uint256)`                     | emergency mint not scheduled        |
+//! | `10` | `mintedTo`          | `mapping(address => uint256)`                     | nothing minted to the account       |
+//! | `11` | `gasCost`           | `uint256`                                         | configured gas costs                |
+//! | `12` | `pendingBridge`     | `address`                                         | no bridge rotation proposed         |
+//! | `13` | `recipientsBlock`   | `uint256`                                         | no recipient counted yet            |
+//! | `14` | `recipientsInBlock` | `uint256`                                         | no recipient counted yet            |
+//! | `15` | `lastMintBlock`     | `mapping(address => uint256)`                     | recipient not counted yet           |
+//! | `16` | `mintAllowances`    | `mapping(address => uint256)`                     | not a delegated minter              |
+//! | `17` | `breakerBlock`      | `uint256`                                         | nothing minted towards the breaker  |
+//! | `18` | `breakerMinted`     | `uint256`                                         | nothing minted towards the breaker  |
+//! | `19` | `mintCooldowns`     | `mapping(address => uint256)`                     | recipient not minted to yet         |
+//! | `20` | `burnNonces`        | `mapping(address => mapping(uint256 => uint256))` | no burn nonce used                  |
+//! | `21` | `approvalNonce`     | `uint256`                                         | no mint approval used               |
+//!
+//! Mapping entries are located as in Solidity, at `keccak256(key . slot)`, see [`mapping_slot`].
+//! The same layout is available programmatically from [`storage_layout`], and the slot of each
+//! mapping entry from the helper deriving it, such as [`allowance_slot`], [`nonce_slot`],
+//! [`minted_to_slot`] or [`burn_nonce_slot`], so tooling can read any value directly from state.
+//!
+//! The slots above are logical slots. A precompile configured with a storage salt stores each
+//! logical slot, including mapping entries, at `keccak256(salt . slot)` instead, see
+//! [`namespaced_slot`]. The default salt is zero, which stores every slot as laid out above.
+//!
+//! Every slot reads as zero until it is written, so the zero value of each slot is chosen to
+//! match the behavior of a precompile configured purely through its constructor. A fresh state
+//! therefore behaves identically to a node-config-only deployment.
+//!
+//! ## Transient layout
+//!
+//! | Slot | Name              | Type                       |
+//! |------|-------------------|----------------------------|
+//! | `0`  | `reentrancyGuard` | `bool`                     |
+//! | `1`  | `mintedInTx`      | `mapping(bytes32 => bool)` |
+//!
+//! ## Genesis requirement
+//!
+//! Storage written to an empty account (no code, zero nonce and balance) is wiped when the account
+//! is cleared under EIP-161. Chains using any storage-backed feature must therefore allocate a
+//! non-empty account at [`NATIVE_MINTER_ADDRESS`] at genesis, see [`genesis_alloc`].
+
+use crate::NATIVE_MINTER_ADDRESS;
+use alloy_evm::precompiles::PrecompileInput;
+use alloy_genesis::GenesisAccount;
+use alloy_primitives::{keccak256, Address, B256, U256};
+use revm::precompile::PrecompileError;
+use std::collections::BTreeMap;
+
+/// Slot holding the authorized bridge address when the bridge is sourced from state.
+pub const AUTHORIZED_BRIDGE_SLOT: U256 = U256::ZERO;
+
+/// Base slot of the `processedMessages` mapping, keyed by Hyperlane message ID.
+pub const PROCESSED_MESSAGES_SLOT: U256 = U256::from_limbs([1, 0, 0, 0]);
+
+/// Slot holding the pause flags, a bitmask of [`PAUSE_ALL`], [`PAUSE_MINT`] and [`PAUSE_BURN`].
+pub const PAUSED_SLOT: U256 = U256::from_limbs([2, 0, 0, 0]);
+
+/// Pause flag set by `pause()`, pausing both mints and burns.
+///
+/// This is the bit the slot held as a `bool` before operations could be paused individually.
+pub const PAUSE_ALL: U256 = U256::from_limbs([1, 0, 0, 0]);
+
+/// Pause flag set by `pauseMint()`, pausing mints only.
+pub const PAUSE_MINT: U256 = U256::from_limbs([2, 0, 0, 0]);
+
+/// Pause flag set by `pauseBurn()`, pausing burns only.
+pub const PAUSE_BURN: U256 = U256::from_limbs([4, 0, 0, 0]);
+
+/// Slot holding the total amount minted.
+pub const TOTAL_MINTED_SLOT: U256 = U256::from_limbs([3, 0, 0, 0]);
+
+/// Slot holding the total amount burned.
+pub const TOTAL_BURNED_SLOT: U256 = U256::from_limbs([4, 0, 0, 0]);
+
+/// Slot holding the block timestamp at which the current rate limit window started.
+pub const RATE_LIMIT_START_SLOT: U256 = U256::from_limbs([5, 0, 0, 0]);
+
+/// Slot holding the amount minted in the current rate limit window.
+pub const RATE_LIMIT_MINTED_SLOT: U256 = U256::from_limbs([6, 0, 0, 0]);
+
+/// Base slot of the `allowances` mapping, keyed by owner and then by spender.
+pub const ALLOWANCES_SLOT: U256 = U256::from_limbs([7, 0, 0, 0]);
+
+/// Base slot of the `nonces` mapping, keyed by permit owner.
+pub const NONCES_SLOT: U256 = U256::from_limbs([8, 0, 0, 0]);
+
+/// Base slot of the `emergencyMints` mapping, keyed by `keccak256(recipient . amount)` and
+/// holding the block timestamp the emergency mint was scheduled at.
+pub const EMERGENCY_MINTS_SLOT: U256 = U256::from_limbs([9, 0, 0, 0]);
+
+/// Base slot of the `mintedTo` mapping, keyed by recipient.
+pub const MINTED_TO_SLOT: U256 = U256::from_limbs([10, 0, 0, 0]);
+
+/// Slot holding the gas cost set by the governor.
+pub const GAS_COST_SLOT: U256 = U256::from_limbs([11, 0, 0, 0]);
+
+/// Slot holding the bridge proposed by the governor, until it accepts.
+pub const PENDING_BRIDGE_SLOT: U256 = U256::from_limbs([12, 0, 0, 0]);
+
+/// Slot holding one past the block number [`RECIPIENTS_IN_BLOCK_SLOT`] counts recipients for.
+pub const RECIPIENTS_BLOCK_SLOT: U256 = U256::from_limbs([13, 0, 0, 0]);
+
+/// Slot holding the number of distinct recipients minted to in the block recorded in
+/// [`RECIPIENTS_BLOCK_SLOT`].
+pub const RECIPIENTS_IN_BLOCK_SLOT: U256 = U256::from_limbs([14, 0, 0, 0]);
+
+/// Base slot of the `lastMintBlock` mapping, keyed by recipient and holding one past the block
+/// number the recipient was last counted in.
+pub const LAST_MINT_BLOCK_SLOT: U256 = U256::from_limbs([15, 0, 0, 0]);
+
+/// Base slot of the `mintAllowances` mapping, keyed by delegated minter.
+pub const MINT_ALLOWANCES_SLOT: U256 = U256::from_limbs([16, 0, 0, 0]);
+
+/// Slot holding one past the block number [`BREAKER_MINTED_SLOT`] accumulates mints for.
+pub const BREAKER_BLOCK_SLOT: U256 = U256::from_limbs([17, 0, 0, 0]);
+
+/// Slot holding the amount minted in the block recorded in [`BREAKER_BLOCK_SLOT`], which the
+/// circuit breaker keeps under its threshold.
+pub const BREAKER_MINTED_SLOT: U256 = U256::from_limbs([18, 0, 0, 0]);
+
+/// Base slot of the `mintCooldowns` mapping, keyed by recipient and holding one past the block
+/// number the recipient was last minted to in while a mint cooldown is configured.
+pub const MINT_COOLDOWNS_SLOT: U256 = U256::from_limbs([19, 0, 0, 0]);
+
+/// Base slot of the `burnNonces` mapping, keyed by account and word index and holding a bitmap
+/// of the burn nonces used, see [`burn_nonce_slot`].
+pub const BURN_NONCES_SLOT: U256 = U256::from_limbs([20, 0, 0, 0]);
+
+/// Slot holding the nonce of the next mint approval signed by the approver.
+pub const APPROVAL_NONCE_SLOT: U256 = U256::from_limbs([21, 0, 0, 0]);
+
+/// All fixed (non-mapping) slots of the persistent layout.
+pub const FIXED_SLOTS: [U256; 13] = [
+    AUTHORIZED_BRIDGE_SLOT,
+    PAUSED_SLOT,
+    TOTAL_MINTED_SLOT,
+    TOTAL_BURNED_SLOT,
+    RATE_LIMIT_START_SLOT,
+    RATE_LIMIT_MINTED_SLOT,
+    GAS_COST_SLOT,
+    PENDING_BRIDGE_SLOT,
+    RECIPIENTS_BLOCK_SLOT,
+    RECIPIENTS_IN_BLOCK_SLOT,
+    BREAKER_BLOCK_SLOT,
+    BREAKER_MINTED_SLOT,
+    APPROVAL_NONCE_SLOT,
+];
+
+/// Describes a slot of the persistent storage layout, for tools reading the precompile's state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageSlotDescriptor {
+    /// Slot index, or the base slot of a mapping.
+    pub slot: U256,
+    /// Name of the slot, as in the layout table.
+    pub name: &'static str,
+    /// Solidity type of the value stored in the slot.
+    pub ty: &'static str,
+    /// How the slot of a mapping entry is derived from its keys, `None` for fixed slots.
+    pub derivation: Option<&'static str>,
+}
+
+impl StorageSlotDescriptor {
+    /// Returns whether this is a fixed slot rather than the base slot of a mapping.
+    pub const fn is_fixed(&self) -> bool {
+        self.derivation.is_none()
+    }
+}
+
+const STORAGE_LAYOUT: &[StorageSlotDescriptor] = &[
+    StorageSlotDescriptor {
+        slot: AUTHORIZED_BRIDGE_SLOT,
+        name: "authorizedBridge",
+        ty: "address",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: PROCESSED_MESSAGES_SLOT,
+        name: "processedMessages",
+        ty: "mapping(bytes32 => bool)",
+        derivation: Some("keccak256(messageId . 1)"),
+    },
+    StorageSlotDescriptor { slot: PAUSED_SLOT, name: "paused", ty: "uint256", derivation: None },
+    StorageSlotDescriptor {
+        slot: TOTAL_MINTED_SLOT,
+        name: "totalMinted",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: TOTAL_BURNED_SLOT,
+        name: "totalBurned",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: RATE_LIMIT_START_SLOT,
+        name: "rateLimitStart",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: RATE_LIMIT_MINTED_SLOT,
+        name: "rateLimitMinted",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: ALLOWANCES_SLOT,
+        name: "allowances",
+        ty: "mapping(address => mapping(address => uint256))",
+        derivation: Some("keccak256(spender . keccak256(owner . 7))"),
+    },
+    StorageSlotDescriptor {
+        slot: NONCES_SLOT,
+        name: "nonces",
+        ty: "mapping(address => uint256)",
+        derivation: Some("keccak256(owner . 8)"),
+    },
+    StorageSlotDescriptor {
+        slot: EMERGENCY_MINTS_SLOT,
+        name: "emergencyMints",
+        ty: "mapping(bytes32 => uint256)",
+        derivation: Some("keccak256(keccak256(recipient . amount) . 9)"),
+    },
+    StorageSlotDescriptor {
+        slot: MINTED_TO_SLOT,
+        name: "mintedTo",
+        ty: "mapping(address => uint256)",
+        derivation: Some("keccak256(recipient . 10)"),
+    },
+    StorageSlotDescriptor { slot: GAS_COST_SLOT, name: "gasCost", ty: "uint256", derivation: None },
+    StorageSlotDescriptor {
+        slot: PENDING_BRIDGE_SLOT,
+        name: "pendingBridge",
+        ty: "address",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: RECIPIENTS_BLOCK_SLOT,
+        name: "recipientsBlock",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: RECIPIENTS_IN_BLOCK_SLOT,
+        name: "recipientsInBlock",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: LAST_MINT_BLOCK_SLOT,
+        name: "lastMintBlock",
+        ty: "mapping(address => uint256)",
+        derivation: Some("keccak256(recipient . 15)"),
+    },
+    StorageSlotDescriptor {
+        slot: MINT_ALLOWANCES_SLOT,
+        name: "mintAllowances",
+        ty: "mapping(address => uint256)",
+        derivation: Some("keccak256(minter . 16)"),
+    },
+    StorageSlotDescriptor {
+        slot: BREAKER_BLOCK_SLOT,
+        name: "breakerBlock",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: BREAKER_MINTED_SLOT,
+        name: "breakerMinted",
+        ty: "uint256",
+        derivation: None,
+    },
+    StorageSlotDescriptor {
+        slot: MINT_COOLDOWNS_SLOT,
+        name: "mintCooldowns",
+        ty: "mapping(address => uint256)",
+        derivation: Some("keccak256(recipient . 19)"),
+    },
+    StorageSlotDescriptor {
+        slot: BURN_NONCES_SLOT,
+        name: "burnNonces",
+        ty: "mapping(address => mapping(uint256 => uint256))",
+        derivation: Some("keccak256((nonce >> 8) . keccak256(from . 20))"),
+    },
+    StorageSlotDescriptor {
+        slot: APPROVAL_NONCE_SLOT,
+        name: "approvalNonce",
+        ty: "uint256",
+        derivation: None,
+    },
+];
+
+/// Returns the persistent storage layout of the precompile, ordered by slot.
+///
+/// Keys in the derivation formulas are left-padded to 32 bytes and `.` denotes concatenation, as
+/// in Solidity's storage layout.
+pub const fn storage_layout() -> &'static [StorageSlotDescriptor] {
+    STORAGE_LAYOUT
+}
+
+/// Transient slot set while a call into the precompile is executing.
+pub const REENTRANCY_GUARD_SLOT: U256 = U256::ZERO;
+
+/// Base transient slot of the `mintedInTx` mapping, keyed by `keccak256(recipient . amount)`.
+pub const MINTED_IN_TX_SLOT: U256 = U256::from_limbs([1, 0, 0, 0]);
+
+/// Returns the genesis `alloc` entry that seeds the authorized bridge into the precompile's
+/// storage.
+///
+/// The account is given a nonce of `1` so it is never considered empty: an empty account that is
+/// touched during execution is removed under EIP-161, which would wipe the seeded storage.
+///
+/// The bridge is seeded in the default storage namespace, for a precompile without a storage
+/// salt.
+pub fn genesis_alloc(authorized_bridge: Address) -> (Address, GenesisAccount) {
+    let storage = BTreeMap::from([(
+        B256::from(AUTHORIZED_BRIDGE_SLOT.to_be_bytes()),
+        authorized_bridge.into_word(),
+    )]);
+
+    (
+        NATIVE_MINTER_ADDRESS,
+        GenesisAccount::default().with_nonce(Some(1)).with_storage(Some(storage)),
+    )
+}
+
+/// Returns the physical slot storing the logical `slot` in the namespace of `salt`:
+/// `keccak256(salt . slot)`, or `slot` itself for the zero salt.
+///
+/// Precompiles sharing an account, or successive versions of a precompile with an incompatible
+/// layout, can use distinct salts to keep their storage disjoint.
+pub fn namespaced_slot(salt: B256, slot: U256) -> U256 {
+    if salt.is_zero() {
+        return slot;
+    }
+    mapping_slot(salt, slot)
+}
+
+/// Returns the slot of a mapping entry, following Solidity's layout: `keccak256(key . slot)`.
+pub fn mapping_slot(key: B256, slot: U256) -> U256 {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(key.as_slice());
+    buf[32..].copy_from_slice(&slot.to_be_bytes::<32>());
+    U256::from_be_bytes(keccak256(buf).0)
+}
+
+/// Returns the slot recording whether a Hyperlane message ID was processed.
+pub fn processed_message_slot(message_id: B256) -> U256 {
+    mapping_slot(message_id, PROCESSED_MESSAGES_SLOT)
+}
+
+/// Returns the slot holding the amount `spender` may burn from `owner`.
+pub fn allowance_slot(owner: Address, spender: Address) -> U256 {
+    mapping_slot(spender.into_word(), mapping_slot(owner.into_word(), ALLOWANCES_SLOT))
+}
+
+/// Returns the slot holding the next permit nonce of `owner`.
+pub fn nonce_slot(owner: Address) -> U256 {
+    mapping_slot(owner.into_word(), NONCES_SLOT)
+}
+
+/// Returns the slot holding the total amount minted to `recipient`.
+pub fn minted_to_slot(recipient: Address) -> U256 {
+    mapping_slot(recipient.into_word(), MINTED_TO_SLOT)
+}
+
+/// Returns the slot holding one past the block number `recipient` was last counted in towards the
+/// per-block recipient cap.
+pub fn last_mint_block_slot(recipient: Address) -> U256 {
+    mapping_slot(recipient.into_word(), LAST_MINT_BLOCK_SLOT)
+}
+
+/// Returns the slot holding one past the block number `recipient` was last minted to in, towards
+/// the mint cooldown.
+pub fn mint_cooldown_slot(recipient: Address) -> U256 {
+    mapping_slot(recipient.into_word(), MINT_COOLDOWNS_SLOT)
+}
+
+/// Returns the slot of the bitmap word recording whether `nonce` was used by a burn from `from`,
+/// and the mask of its bit in that word.
+///
+/// Each word records 256 consecutive nonces, so sequential nonces share a slot.
+pub fn burn_nonce_slot(from: Address, nonce: U256) -> (U256, U256) {
+    let word =
+        mapping_slot(B256::from(nonce >> 8), mapping_slot(from.into_word(), BURN_NONCES_SLOT));
+    (word, U256::from(1) << (nonce.as_limbs()[0] & 0xff) as usize)
+}
+
+/// Returns the slot holding the amount `minter` may still mint as a delegated minter.
+pub fn mint_allowance_slot(minter: Address) -> U256 {
+    mapping_slot(minter.into_word(), MINT_ALLOWANCES_SLOT)
+}
+
+/// Returns the slot holding the block timestamp an emergency mint of `amount` to `recipient` was
+/// scheduled at.
+pub fn emergency_mint_slot(recipient: Address, amount: U256) -> U256 {
+    mapping_slot(pair_key(recipient, amount), EMERGENCY_MINTS_SLOT)
+}
+
+/// Returns the transient slot recording whether `amount` was minted to `recipient` in the
+/// current transaction.
+pub fn minted_in_tx_slot(recipient: Address, amount: U256) -> U256 {
+    mapping_slot(pair_key(recipient, amount), MINTED_IN_TX_SLOT)
+}
+
+/// Returns the mapping key of a `(recipient, amount)` pair: `keccak256(recipient . amount)`.
+fn pair_key(recipient: Address, amount: U256) -> B256 {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(recipient.into_word().as_slice());
+    buf[32..].copy_from_slice(&amount.to_be_bytes::<32>());
+    keccak256(buf)
+}
+
+/// Reads a slot from the precompile's storage.
+pub(crate) fn sload(input: &mut PrecompileInput<'_>, slot: U256) -> Result<U256, PrecompileError> {
+    sload_at(input, NATIVE_MINTER_ADDRESS, slot)
+}
+
+/// Writes a slot in the precompile's storage.
+pub(crate) fn sstore(
+    input: &mut PrecompileInput<'_>,
+    slot: U256,
+    value: U256,
+) -> Result<(), PrecompileError> {
+    sstore_at(input, NATIVE_MINTER_ADDRESS, slot, value)
+}
+
+/// Reads a slot from the storage of `address`.
+pub(crate) fn sload_at(
+    input: &mut PrecompileInput<'_>,
+    address: Address,
+    slot: U256,
+) -> Result<U256, PrecompileError> {
+    let internals = input.internals_mut();
+
+    // The journal only serves storage of accounts that have been loaded.
+    internals
+        .load_account(address)
+        .map_err(|e| PrecompileError::other(format!("NativeMinter: load account failed: {e}")))?;
+
+    internals
+        .sload(address, slot)
+        .map(|value| value.data)
+        .map_err(|e| PrecompileError::other(format!("NativeMinter: storage read failed: {e}")))
+}
+
+/// Writes a slot in the storage of `address`.
+pub(crate) fn sstore_at(
+    input: &mut PrecompileInput<'_>,
+    address: Address,
+    slot: U256,
+    value: U256,
+) -> Result<(), PrecompileError> {
+    let internals = input.internals_mut();
+
+    internals
+        .load_account(address)
+        .map_err(|e| PrecompileError::other(format!("NativeMinter: load account failed: {e}")))?;
+
+    internals
+        .sstore(address, slot, value)
+        .map(|_| ())
+        .map_err(|e| PrecompileError::other(format!("NativeMinter: storage write failed: {e}")))
+}
+
+/// Adds `amount` to the counter in the given slot, returning the new value.
+pub(crate) fn sadd(
+    input: &mut PrecompileInput<'_>,
+    slot: U256,
+    amount: U256,
+) -> Result<U256, PrecompileError> {
+    let value = sload(input, slot)?
+        .checked_add(amount)
+        .ok_or_else(|| PrecompileError::other_static("NativeMinter: counter overflow"))?;
+    sstore(input, slot, value)?;
+    Ok(value)
+}
+
+/// Reads a slot from the precompile's transient storage.
+pub(crate) fn tload(input: &mut PrecompileInput<'_>, slot: U256) -> U256 {
+    input.internals_mut().tload(NATIVE_MINTER_ADDRESS, slot)
+}
+
+/// Writes a slot in the precompile's transient storage.
+pub(crate) fn tstore(input: &mut PrecompileInput<'_>, slot: U256, value: U256) {
+    input.internals_mut().tstore(NATIVE_MINTER_ADDRESS, slot, value)
+}
+
+/// Reads an address stored in the low 20 bytes of a slot.
+pub(crate) fn sload_address(
+    input: &mut PrecompileInput<'_>,
+    slot: U256,
+) -> Result<Address, PrecompileError> {
+    sload(input, slot).map(word_to_address)
+}
+
+/// Encodes an address as a storage value.
+pub fn address_to_word(address: Address) -> U256 {
+    U256::from_be_slice(address.as_slice())
+}
+
+/// Decodes an address from the low 20 bytes of a storage value.
+pub fn word_to_address(value: U256) -> Address {
+    Address::from_word(B256::from(value.to_be_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    #[test]
+    fn test_genesis_alloc() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let (address, account) = genesis_alloc(bridge);
+
+        assert_eq!(address, NATIVE_MINTER_ADDRESS);
+        assert_eq!(account.nonce, Some(1));
+        assert_eq!(
+            account.storage.unwrap().get(&B256::ZERO),
+            Some(&B256::left_padding_from(bridge.as_slice()))
+        );
+    }
+
+    #[test]
+    fn test_mapping_slot_matches_solidity() {
+        // keccak256(abi.encode(bytes32(0), uint256(0)))
+        assert_eq!(
+            mapping_slot(B256::ZERO, U256::ZERO),
+            U256::from_be_bytes(
+                b256!("0xad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5").0
+            )
+        );
+    }
+
+    #[test]
+    fn test_slot_helpers_known_values() {
+        let owner = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let spender = address!("0x2222222222222222222222222222222222222222");
+        let slot = |hash: B256| U256::from_be_bytes(hash.0);
+
+        assert_eq!(
+            allowance_slot(owner, spender),
+            slot(b256!("0xfb900cd963419aabf1fcd7427fca424bcfbd638b821015594d73f297e496129a"))
+        );
+        assert_eq!(
+            nonce_slot(owner),
+            slot(b256!("0x3f615205af8f183ba8efabe5fdb25bf58e52db3a905a6ede5e6c233a53f9f20a"))
+        );
+        assert_eq!(
+            minted_to_slot(owner),
+            slot(b256!("0x58e966394fd291100e68c68461ea48adb37f52b31cf24f844cdce87a7314ae91"))
+        );
+        assert_eq!(
+            burn_nonce_slot(owner, U256::from(300)),
+            (
+                slot(b256!("0x24cfcf290a1d2e5281769b6b68fa66e3abb34746707c1aa4bde615715976d898")),
+                U256::from(1) << 44
+            )
+        );
+    }
+
+    #[test]
+    fn test_storage_layout() {
+        let layout = storage_layout();
+        let names: Vec<_> = layout.iter().map(|slot| slot.name).collect();
+        assert_eq!(
+            names,
+            [
+                "authorizedBridge",
+                "processedMessages",
+                "paused",
+                "totalMinted",
+                "totalBurned",
+                "rateLimitStart",
+                "rateLimitMinted",
+                "allowances",
+                "nonces",
+                "emergencyMints",
+                "mintedTo",
+                "gasCost",
+                "pendingBridge",
+                "recipientsBlock",
+                "recipientsInBlock",
+                "lastMintBlock",
+                "mintAllowances",
+                "breakerBlock",
+                "breakerMinted",
+                "mintCooldowns",
+                "burnNonces",
+                "approvalNonce",
+            ]
+        );
+        assert!(layout.iter().enumerate().all(|(i, slot)| slot.slot == U256::from(i)));
+
+        // Mappings, including nested ones such as burnNonces, are derived from their own slot
+        for slot in layout {
+            assert_eq!(slot.ty.starts_with("mapping"), slot.derivation.is_some(), "{}", slot.name);
+            if let Some(derivation) = slot.derivation {
+                assert!(derivation.contains(&format!(" . {})", slot.slot)), "{}", slot.name);
+            }
+        }
+
+        let fixed: Vec<_> =
+            layout.iter().filter(|slot| slot.is_fixed()).map(|slot| slot.slot).collect();
+        assert_eq!(fixed, FIXED_SLOTS);
+    }
+
+    #[test]
+    fn test_slot_derivations_match_layout() {
+        let owner = address!("0x1234567890abcdef1234567890abcdef12345678");
+        let spender = address!("0x2222222222222222222222222222222222222222");
+        let message_id =
+            b256!("0x0101010101010101010101010101010101010101010101010101010101010101");
+        let word = |slot: u64| U256::from(slot).to_be_bytes::<32>();
+
+        // keccak256(spender . keccak256(owner . 7))
+        let inner = keccak256([owner.into_word().as_slice(), &word(7)].concat());
+        let expected = keccak256([spender.into_word().as_slice(), inner.as_slice()].concat());
+        assert_eq!(allowance_slot(owner, spender), U256::from_be_bytes(expected.0));
+
+        // keccak256(owner . 8)
+        let expected = keccak256([owner.into_word().as_slice(), &word(8)].concat());
+        assert_eq!(nonce_slot(owner), U256::from_be_bytes(expected.0));
+
+        // keccak256(recipient . 10)
+        let expected = keccak256([owner.into_word().as_slice(), &word(10)].concat());
+        assert_eq!(minted_to_slot(owner), U256::from_be_bytes(expected.0));
+
+        // keccak256(recipient . 15)
+        let expected = keccak256([owner.into_word().as_slice(), &word(15)].concat());
+        assert_eq!(last_mint_block_slot(owner), U256::from_be_bytes(expected.0));
+
+        // keccak256(minter . 16)
+        let expected = keccak256([owner.into_word().as_slice(), &word(16)].concat());
+        assert_eq!(mint_allowance_slot(owner), U256::from_be_bytes(expected.0));
+
+        // keccak256(recipient . 19)
+        let expected = keccak256([owner.into_word().as_slice(), &word(19)].concat());
+        assert_eq!(mint_cooldown_slot(owner), U256::from_be_bytes(expected.0));
+
+        // keccak256((nonce >> 8) . keccak256(from . 20)), bit nonce & 0xff
+        let inner = keccak256([owner.into_word().as_slice(), &word(20)].concat());
+        let expected = keccak256([&word(1)[..], inner.as_slice()].concat());
+        assert_eq!(
+            burn_nonce_slot(owner, U256::from(300)),
+            (U256::from_be_bytes(expected.0), U256::from(1) << 44)
+        );
+
+        // keccak256(messageId . 1)
+        let expected = keccak256([message_id.as_slice(), &word(1)].concat());
+        assert_eq!(processed_message_slot(message_id), U256::from_be_bytes(expected.0));
+    }
+
+    #[test]
+    fn test_address_word_roundtrip() {
+        let bridge = address!("0x1234567890abcdef1234567890abcdef12345678");
+        assert_eq!(word_to_address(address_to_word(bridge)), bridge);
+    }
+
+    #[test]
+    fn test_namespaced_slot() {
+        assert_eq!(namespaced_slot(B256::ZERO, TOTAL_MINTED_SLOT), TOTAL_MINTED_SLOT);
+
+        let salt = B256::with_last_byte(1);
+        assert_eq!(namespaced_slot(salt, TOTAL_MINTED_SLOT), mapping_slot(salt, TOTAL_MINTED_SLOT));
+        assert_ne!(
+            namespaced_slot(salt, TOTAL_MINTED_SLOT),
+            namespaced_slot(B256::with_last_byte(2), TOTAL_MINTED_SLOT)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Block-level summary of the native supply change caused by the NativeMinter.
+
+use crate::{
+    storage::{namespaced_slot, TOTAL_BURNED_SLOT, TOTAL_MINTED_SLOT},
+    NATIVE_MINTER_ADDRESS,
+};
+use alloy_primitives::{B256, U256};
+use revm::database::BundleState;
+
+/// Amounts minted and burned through the NativeMinter in a block.
+///
+/// This is derived from the state changes of the block, the `state` of its execution output, so
+/// it is available for any executor and only counts mints and burns that were committed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RkbBlockSummary {
+    /// Total amount minted in the block.
+    pub minted: U256,
+    /// Total amount burned in the block.
+    pub burned: U256,
+}
+
+impl RkbBlockSummary {
+    /// Computes the summary of a block from the state changes of its execution.
+    ///
+    /// The totals are the difference of the `totalMinted` and `totalBurned` slots before and after
+    /// the block, resolved in the namespace of the precompile's storage `salt`, see
+    /// [`NativeMinterPrecompile::storage_salt`](crate::NativeMinterPrecompile::storage_salt).
+    pub fn from_bundle_state(state: &BundleState, salt: B256) -> Self {
+        let Some(account) = state.account(&NATIVE_MINTER_ADDRESS) else {
+            return Self::default();
+        };
+
+        let delta = |slot| {
+            account.storage.get(&slot).map_or(U256::ZERO, |slot| {
+                slot.present_value.saturating_sub(slot.previous_or_original_value)
+            })
+        };
+
+        Self {
+            minted: delta(namespaced_slot(salt, TOTAL_MINTED_SLOT)),
+            burned: delta(namespaced_slot(salt, TOTAL_BURNED_SLOT)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        native_minter::{burn_0Call, mintCall},
+        NativeMinterPrecompile, RkbEvmFactory,
+    };
+    use alloy_evm::{Evm, EvmFactory};
+    use alloy_primitives::{address, b256, Address};
+    use alloy_sol_types::SolCall;
+    use reth_ethereum::evm::{primitives::EvmEnv, revm::context::TxEnv};
+    use revm::{
+        database::{states::bundle_state::BundleRetention, InMemoryDB, State},
+        state::AccountInfo,
+    };
+
+    const BRIDGE: Address = address!("0x1234567890abcdef1234567890abcdef12345678");
+    const RECIPIENT: Address = address!("0x00000000000000000000000000000000000000aa");
+
+    /// Executes a block of NativeMinter calls from the bridge on top of `db`, with the
+    /// precompile's storage namespaced by `salt`, returning its state changes.
+    fn execute_block(db: InMemoryDB, salt: B256, calls: Vec<Vec<u8>>) -> BundleState {
+        let state = State::builder().with_database(db).with_bundle_update().build();
+        let config = NativeMinterPrecompile::new(BRIDGE).with_storage_salt(salt).into_config();
+        let mut evm = RkbEvmFactory::with_config(config).create_evm(state, EvmEnv::default());
+        for (nonce, data) in calls.into_iter().enumerate() {
+            let result = evm
+                .transact_commit(TxEnv {
+                    caller: BRIDGE,
+                    nonce: nonce as u64,
+                    gas_limit: 100_000,
+                    data: data.into(),
+                    kind: NATIVE_MINTER_ADDRESS.into(),
+                    ..Default::default()
+                })
+                .unwrap();
+            assert!(result.is_success(), "{result:?}");
+        }
+
+        let mut state = evm.into_db();
+        state.merge_transitions(BundleRetention::Reverts);
+        state.take_bundle()
+    }
+
+    /// A block minting 1_500 and burning 500 in total.
+    fn block_calls() -> Vec<Vec<u8>> {
+        let mint = |amount| mintCall { recipient: RECIPIENT, amount: U256::from(amount) };
+        let burn = |amount| burn_0Call { from: RECIPIENT, amount: U256::from(amount) };
+        vec![
+            mint(1_000).abi_encode(),
+            burn(300).abi_encode(),
+            mint(500).abi_encode(),
+            burn(200).abi_encode(),
+        ]
+    }
+
+    /// The parent state, with the precompile's account allocated as at genesis so its storage is
+    /// never cleared.
+    fn parent_state() -> InMemoryDB {
+        let mut parent = InMemoryDB::default();
+        parent.insert_account_info(
+            NATIVE_MINTER_ADDRESS,
+            AccountInfo { nonce: 1, ..Default::default() },
+        );
+        parent
+    }
+
+    #[test]
+    fn test_block_summary_totals() {
+        let state = execute_block(parent_state(), B256::ZERO, block_calls());
+        assert_eq!(
+            RkbBlockSummary::from_bundle_state(&state, B256::ZERO),
+            RkbBlockSummary { minted: U256::from(1_500), burned: U256::from(500) }
+        );
+    }
+
+    #[test]
+    fn test_block_summary_salted_storage() {
+        let salt = b256!("0x00000000000000000000000000000000000000000000000000000000000000a1");
+        let state = execute_block(parent_state(), salt, block_calls());
+        assert_eq!(
+            RkbBlockSummary::from_bundle_state(&state, salt),
+            RkbBlockSummary { minted: U256::from(1_500), burned: U256::from(500) }
+        );
+
+        // The logical slots are untouched, so the unsalted summary sees no supply change
+        assert_eq!(
+            RkbBlockSummary::from_bundle_state(&state, B256::ZERO),
+            RkbBlockSummary::default()
+        );
+    }
+
+    #[test]
+    fn test_block_summary_without_native_minter_calls() {
+        assert_eq!(
+            RkbBlockSummary::from_bundle_state(&BundleState::default(), B256::ZERO),
+            RkbBlockSummary::default()
+        );
+    }
+}
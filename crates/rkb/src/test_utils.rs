@@ -0,0 +1,252 @@
+//! Utilities for driving the NativeMinter precompile in tests without a full EVM.
+//!
+//! [`EvmInternals`] is a concrete type wrapping a journal rather than a trait, so it can't be
+//! mocked directly. [`TestContext`] instead backs it with a journal over an in-memory database,
+//! which is as fast as a mock while behaving exactly like the EVM for `load_account`,
+//! `balance_incr`, `set_balance` and storage access. Its block, configuration and transaction
+//! environments are plain fields, so tests can set e.g. the block number or `tx.origin`.
+//! [`TestCall::input`] exposes the resulting [`PrecompileInput`] to test the precompile's
+//! operations directly, bypassing `call`.
+
+use crate::{NativeMinterPrecompile, NATIVE_MINTER_ADDRESS};
+use alloy_evm::{precompiles::PrecompileInput, EvmInternals};
+use alloy_primitives::{Address, Bytes, Log, U256};
+use revm::{
+    context::{BlockEnv, CfgEnv, Journal, TxEnv},
+    context_interface::JournalTr,
+    database::InMemoryDB,
+    precompile::PrecompileResult,
+    DatabaseCommit,
+};
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+use tracing::Level;
+
+/// Gas limit used by [`TestCall`] unless overridden.
+pub const TEST_GAS_LIMIT: u64 = 1_000_000;
+
+/// In-memory state the precompile is executed against.
+#[derive(Debug)]
+pub struct TestContext {
+    journal: Journal<InMemoryDB>,
+    /// Block environment exposed to the precompile.
+    pub block: BlockEnv,
+    /// Configuration environment exposed to the precompile, including the chain id.
+    pub cfg: CfgEnv,
+    /// Transaction environment exposed to the precompile; its caller is `tx.origin`.
+    pub tx: TxEnv,
+}
+
+impl TestContext {
+    /// Creates a new context backed by an empty in-memory database.
+    pub fn new() -> Self {
+        Self::with_db(InMemoryDB::default())
+    }
+
+    /// Creates a new context backed by the given database.
+    pub fn with_db(db: InMemoryDB) -> Self {
+        Self {
+            journal: Journal::new(db),
+            block: BlockEnv::default(),
+            cfg: CfgEnv::default(),
+            tx: TxEnv::default(),
+        }
+    }
+
+    /// Commits all changes made so far to the underlying database and returns it.
+    pub fn commit(&mut self) -> &mut InMemoryDB {
+        let state = self.journal.finalize();
+        let db = self.journal.db_mut();
+        db.commit(state);
+        db
+    }
+
+    /// Returns the logs emitted so far, clearing them.
+    pub fn take_logs(&mut self) -> Vec<Log> {
+        self.journal.take_logs()
+    }
+
+    /// Returns the [`EvmInternals`] view of this context, as seen by the precompile.
+    pub fn internals(&mut self) -> EvmInternals<'_> {
+        EvmInternals::new(&mut self.journal, &self.block, &self.cfg, &self.tx)
+    }
+
+    /// Returns the balance of the given account.
+    pub fn balance(&mut self, address: Address) -> U256 {
+        self.internals().load_account(address).expect("load account").data.info.balance
+    }
+
+    /// Sets the balance of the given account.
+    pub fn set_balance(&mut self, address: Address, balance: U256) {
+        self.internals().set_balance(address, balance).expect("set balance");
+    }
+
+    /// Returns the value of a slot in the precompile's storage.
+    pub fn storage(&mut self, slot: U256) -> U256 {
+        self.storage_at(NATIVE_MINTER_ADDRESS, slot)
+    }
+
+    /// Returns the value of a slot in the storage of the given account.
+    pub fn storage_at(&mut self, address: Address, slot: U256) -> U256 {
+        let mut internals = self.internals();
+        internals.load_account(address).expect("load account");
+        internals.sload(address, slot).expect("sload").data
+    }
+
+    /// Sets a slot in the precompile's storage.
+    pub fn set_storage(&mut self, slot: U256, value: U256) {
+        let mut internals = self.internals();
+        internals.load_account(NATIVE_MINTER_ADDRESS).expect("load account");
+        internals.sstore(NATIVE_MINTER_ADDRESS, slot, value).expect("sstore");
+    }
+
+    /// Returns the value of a slot in the precompile's transient storage.
+    pub fn transient_storage(&mut self, slot: U256) -> U256 {
+        self.internals().tload(NATIVE_MINTER_ADDRESS, slot)
+    }
+
+    /// Sets a slot in the precompile's transient storage.
+    pub fn set_transient_storage(&mut self, slot: U256, value: U256) {
+        self.internals().tstore(NATIVE_MINTER_ADDRESS, slot, value);
+    }
+}
+
+impl Default for TestContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parameters of a single call into the precompile.
+#[derive(Debug, Clone)]
+pub struct TestCall {
+    /// Caller of the precompile.
+    pub caller: Address,
+    /// Calldata, including the selector.
+    pub data: Bytes,
+    /// Gas available to the precompile.
+    pub gas: u64,
+    /// Value attached to the call.
+    pub value: U256,
+    /// Address whose context the call executes in.
+    pub target_address: Address,
+    /// Address whose code is executed.
+    pub bytecode_address: Address,
+    /// Whether the call is a STATICCALL.
+    pub is_static: bool,
+}
+
+impl TestCall {
+    /// Creates a direct call to the precompile from `caller`.
+    pub fn new(caller: Address, data: impl Into<Bytes>) -> Self {
+        Self {
+            caller,
+            data: data.into(),
+            gas: TEST_GAS_LIMIT,
+            value: U256::ZERO,
+            target_address: NATIVE_MINTER_ADDRESS,
+            bytecode_address: NATIVE_MINTER_ADDRESS,
+            is_static: false,
+        }
+    }
+
+    /// Sets the gas available to the precompile.
+    pub const fn with_gas(mut self, gas: u64) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Sets the value attached to the call.
+    pub const fn with_value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Turns the call into a DELEGATECALL made from `proxy`.
+    ///
+    /// The caller is preserved, as it is the caller of `proxy`.
+    pub const fn delegated_from(mut self, proxy: Address) -> Self {
+        self.target_address = proxy;
+        self
+    }
+
+    /// Turns the call into a CALLCODE made from `contract`.
+    pub const fn callcode_from(mut self, contract: Address) -> Self {
+        self.caller = contract;
+        self.target_address = contract;
+        self
+    }
+
+    /// Turns the call into a STATICCALL.
+    pub const fn static_call(mut self) -> Self {
+        self.is_static = true;
+        self
+    }
+
+    /// Returns the precompile input of this call, executing against `ctx`.
+    pub fn input<'a>(&'a self, ctx: &'a mut TestContext) -> PrecompileInput<'a> {
+        PrecompileInput {
+            data: &self.data,
+            gas: self.gas,
+            caller: self.caller,
+            value: self.value,
+            target_address: self.target_address,
+            bytecode_address: self.bytecode_address,
+            is_static: self.is_static,
+            internals: ctx.internals(),
+        }
+    }
+}
+
+/// Executes a single call against the given precompile and context.
+pub fn run_precompile(
+    precompile: &NativeMinterPrecompile,
+    ctx: &mut TestContext,
+    call: TestCall,
+) -> PrecompileResult {
+    precompile.call(call.input(ctx))
+}
+
+/// Writer capturing formatted log output, to be installed in a `tracing` subscriber.
+///
+/// [`capture`](Self::capture) installs one for the duration of a closure.
+#[derive(Debug, Clone, Default)]
+pub struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    /// Runs `f` with a subscriber recording events at `INFO` and above, returning the formatted
+    /// log output without ANSI colors.
+    pub fn capture(f: impl FnOnce()) -> String {
+        Self::capture_at(Level::INFO, f)
+    }
+
+    /// Runs `f` with a subscriber recording events at `level` and above, returning the formatted
+    /// log output without ANSI colors.
+    pub fn capture_at(level: Level, f: impl FnOnce()) -> String {
+        let logs = Self::default();
+        let subscriber =
+            tracing_subscriber::fmt().with_ansi(false).with_max_level(level).with_writer({
+                let logs = logs.clone();
+                move || logs.clone()
+            });
+        tracing::subscriber::with_default(subscriber.finish(), f);
+        logs.contents()
+    }
+
+    /// Returns the output captured so far.
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+}
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}